@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{OnceInfo, Summary};
+
+pub const BASELINE_FILE: &str = "tikey-baseline.json";
+
+/// Version of the baseline file, bumped whenever fingerprints change.
+const BASELINE_VERSION: u32 = 2;
+
+/// Known findings, which are hidden from later reports.
+///
+/// A finding is known by its fingerprint, made of its rule, its file and its statement with
+/// whitespace collapsed and case ignored. Its location is left out, so that lines added
+/// or removed around the statement keep it known.
+///
+/// Files are resolved, and saved relative to the directory of the baseline file, so that
+/// the input could be given by another path, or from another working directory.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Baseline {
+    version: u32,
+    findings: Vec<BaselineEntry>,
+    /// Directory the files are relative to, the files are absolute if none.
+    #[serde(skip)]
+    root: Option<PathBuf>,
+}
+
+/// A known finding, `count` is the number of times it was found.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    fingerprint: String,
+    uid: String,
+    file: Option<String>,
+    sql: String,
+    count: u64,
+}
+
+impl BaselineEntry {
+    pub fn fingerprint(&self) -> &String {
+        &self.fingerprint
+    }
+
+    pub fn uid(&self) -> &String {
+        &self.uid
+    }
+
+    pub fn file(&self) -> &Option<String> {
+        &self.file
+    }
+
+    /// The normalized statement of the finding.
+    pub fn sql(&self) -> &String {
+        &self.sql
+    }
+
+    pub fn count(&self) -> &u64 {
+        &self.count
+    }
+}
+
+impl Baseline {
+    /// A baseline knowing all the findings.
+    pub fn create(all_info: &[OnceInfo]) -> Self {
+        let mut findings: Vec<BaselineEntry> = vec![];
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let mut files = Files::new(None);
+        for info in all_info {
+            let file = files.relative(info.file().as_deref());
+            let sql = normalize_sql(info.sql());
+            for rec in info.records() {
+                let fingerprint = fingerprint(rec.uid(), file.as_deref(), &sql);
+                match index.get(&fingerprint) {
+                    Some(i) => findings[*i].count += 1,
+                    None => {
+                        index.insert(fingerprint.clone(), findings.len());
+                        findings.push(BaselineEntry {
+                            fingerprint,
+                            uid: rec.uid().clone(),
+                            file: file.clone(),
+                            sql: sql.clone(),
+                            count: 1,
+                        });
+                    }
+                }
+            }
+        }
+        Self {
+            version: BASELINE_VERSION,
+            findings,
+            root: None,
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("At baseline: {:?}", path))?;
+        let mut baseline: Baseline =
+            serde_json::from_str(&contents).with_context(|| format!("At baseline: {:?}", path))?;
+        if baseline.version != BASELINE_VERSION {
+            return Err(anyhow!(
+                "baseline of version {} is not supported, create it again",
+                baseline.version
+            ))
+            .with_context(|| format!("At baseline: {:?}", path));
+        }
+        baseline.root = Some(dir_of(path));
+        Ok(baseline)
+    }
+
+    /// Save the baseline, its files relative to the directory of `path`.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let mut files = Files::new(Some(dir_of(path)));
+        let findings: Vec<BaselineEntry> = self
+            .findings
+            .iter()
+            .map(|entry| {
+                let file = entry.file.as_ref().map(|file| match &self.root {
+                    Some(root) => root.join(file),
+                    None => PathBuf::from(file),
+                });
+                let file = files.relative(file.as_deref());
+                BaselineEntry {
+                    fingerprint: fingerprint(&entry.uid, file.as_deref(), &entry.sql),
+                    file,
+                    ..entry.clone()
+                }
+            })
+            .collect();
+        let saved = Baseline {
+            version: self.version,
+            findings,
+            root: None,
+        };
+        let contents = serde_json::to_string_pretty(&saved)?;
+        fs::write(path, contents + "\n").with_context(|| format!("At baseline: {:?}", path))
+    }
+
+    pub fn findings(&self) -> &Vec<BaselineEntry> {
+        &self.findings
+    }
+
+    /// Hide the known findings, the summary is counted again.
+    ///
+    /// Returns the new findings, and the known ones no longer found, with the count of
+    /// times they are missing.
+    pub fn apply(
+        &self,
+        summary: Summary,
+        all_info: Vec<OnceInfo>,
+    ) -> (Summary, Vec<OnceInfo>, Vec<BaselineEntry>) {
+        let mut summary = summary;
+        let mut remaining: HashMap<&String, u64> = self
+            .findings
+            .iter()
+            .map(|entry| (&entry.fingerprint, entry.count))
+            .collect();
+        let mut files = Files::new(self.root.clone());
+        let all_info: Vec<OnceInfo> = all_info
+            .into_iter()
+            .filter_map(|mut info| {
+                let file = files.relative(info.file().as_deref());
+                let sql = normalize_sql(info.sql());
+                info.records.retain(|rec| {
+                    let fingerprint = fingerprint(rec.uid(), file.as_deref(), &sql);
+                    match remaining.get_mut(&fingerprint) {
+                        Some(count) if *count > 0 => {
+                            *count -= 1;
+                            false
+                        }
+                        _ => true,
+                    }
+                });
+                if info.records.is_empty() && info.of_statement() {
+                    summary.hidden += 1;
+                }
+                (!info.records.is_empty()).then_some(info)
+            })
+            .collect();
+        let fixed = self
+            .findings
+            .iter()
+            .filter_map(|entry| match remaining[&entry.fingerprint] {
+                0 => None,
+                count => Some(BaselineEntry {
+                    count,
+                    ..entry.clone()
+                }),
+            })
+            .collect();
+        (summary.recount(&all_info), all_info, fixed)
+    }
+}
+
+/// The directory of the file, resolved.
+fn dir_of(path: &Path) -> PathBuf {
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    fs::canonicalize(&dir).unwrap_or(dir)
+}
+
+/// The files of findings as known by a baseline, each resolved once.
+struct Files {
+    root: Option<PathBuf>,
+    relatives: HashMap<PathBuf, String>,
+}
+
+impl Files {
+    fn new(root: Option<PathBuf>) -> Self {
+        Self {
+            root,
+            relatives: HashMap::new(),
+        }
+    }
+
+    /// The file resolved and relative to the root, going up by `..` if outside of it.
+    fn relative(&mut self, file: Option<&Path>) -> Option<String> {
+        let file = file?;
+        let root = self.root.as_deref();
+        let relative = self.relatives.entry(file.to_path_buf()).or_insert_with(|| {
+            let file = fs::canonicalize(file).unwrap_or_else(|_| file.to_path_buf());
+            let root = match root {
+                Some(root) => root,
+                None => return normalize_path(&file),
+            };
+            let common = file
+                .components()
+                .zip(root.components())
+                .take_while(|(f, r)| f == r)
+                .count();
+            // on another drive
+            if common == 0 {
+                return normalize_path(&file);
+            }
+            let mut relative = PathBuf::new();
+            for _ in root.components().skip(common) {
+                relative.push("..");
+            }
+            for c in file.components().skip(common) {
+                relative.push(c);
+            }
+            normalize_path(&relative)
+        });
+        Some(relative.clone())
+    }
+}
+
+/// The path with `/` separators, so that a baseline is shared across platforms.
+fn normalize_path(path: &Path) -> String {
+    let mut parts: Vec<String> = vec![];
+    for c in path.components() {
+        match c {
+            Component::CurDir => {}
+            // an empty part before the first separator, unless after a prefix like `C:`
+            Component::RootDir if parts.is_empty() => parts.push(String::new()),
+            Component::RootDir => {}
+            c => parts.push(c.as_os_str().to_string_lossy().to_string()),
+        }
+    }
+    parts.join("/")
+}
+
+/// The statement with whitespace collapsed and in lowercase.
+fn normalize_sql(sql: &str) -> String {
+    sql.split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// FNV-1a of the parts, which stays the same across builds unlike the std hasher.
+fn fingerprint(uid: &str, file: Option<&str>, sql: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for part in [uid, file.unwrap_or(""), sql] {
+        for b in part.bytes().chain([0]) {
+            hash ^= b as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
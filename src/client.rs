@@ -1,15 +1,27 @@
 use std::{
+    env, fmt,
     fs::File,
-    io::Write,
+    io::{self, BufWriter, IsTerminal, Write},
     path::{Path, PathBuf},
+    slice,
 };
 
-use anyhow::{Ok, Result};
-use clap::{Parser, Subcommand};
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use encoding_rs::Encoding;
 use walkdir::WalkDir;
 
+use crate::rules::{Rule, RuleParseError, Version};
+use crate::threshold::{FailOn, Thresholds};
 use crate::{
-    check_file, check_files, check_statements, format_records, format_summary, OnceInfo, Summary,
+    apply_config, apply_config_to, apply_version, check_file_with_encoding,
+    check_files_with_encoding, check_statements_with, default_jobs, encoding_for_label,
+    format_annotated, format_checkstyle, format_compact, format_delta, format_failure,
+    format_failure_compact, format_fixed, format_fixed_compact, format_html, format_json,
+    format_jsonl, format_jsonl_findings, format_junit, format_records, format_sarif,
+    format_summary, format_totals, stream_file, stream_files, upgrade_delta, Baseline,
+    BaselineEntry, Config, FileFailure, OnceInfo, RuleRegistry, Summary, UpgradeDelta,
+    BASELINE_FILE,
 };
 
 #[derive(Debug, Parser)]
@@ -30,11 +42,236 @@ pub struct TiKeyArgs {
     /// path of output report file
     #[arg(short, long)]
     out: Option<PathBuf>,
+
+    /// rule file, or directory of rule files, to load beside the built-in rules
+    #[arg(long)]
+    rules: Option<PathBuf>,
+
+    /// config file, `tikey.toml` found from the input upwards by default
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+
+    /// TiDB version to migrate to, e.g. `6.5.0`, only reporting what it does not support
+    ///
+    /// everything is reported when not given
+    #[arg(long)]
+    tidb_version: Option<Version>,
+
+    /// TiDB version migrating from, reporting which findings are resolved, remain or are new
+    /// when upgrading to `--tidb-version`
+    #[arg(long, requires = "tidb_version")]
+    upgrade_from: Option<Version>,
+
+    /// format of the report
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// lowest level of findings failing the check, with exit code 1
+    #[arg(long, value_enum, default_value_t = FailOn::Error)]
+    fail_on: FailOn,
+
+    /// count of errors allowed before failing the check
+    #[arg(long, default_value_t = 0)]
+    max_errors: u128,
+
+    /// count of warnings allowed before failing the check with `--fail-on warning`
+    #[arg(long, default_value_t = 0)]
+    max_warnings: u128,
+
+    /// count of findings allowed for a rule, like `m2=10`, failing the check whatever the level
+    #[arg(long = "max-rule", value_name = "UID=COUNT", value_parser = parse_max_rule)]
+    max_rules: Vec<(String, u128)>,
+
+    /// baseline file of known findings, only reporting new ones and the fixed known ones
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+
+    /// count of files checked at the same time, one for each CPU by default
+    #[arg(short, long, value_parser = parse_jobs)]
+    jobs: Option<usize>,
+
+    /// encoding of the input files, a MySQL charset like `gbk` or a label like `shift_jis`
+    ///
+    /// detected from the byte order mark or the content by default, a file starting with
+    /// bytes that are not valid UTF-8, or only ASCII, is decoded from its `SET NAMES` if any.
+    /// A file guessed to be UTF-8 fails at the first invalid byte. A given encoding is not
+    /// reported as a finding
+    #[arg(long, value_parser = parse_encoding)]
+    encoding: Option<&'static Encoding>,
+}
+
+fn parse_encoding(s: &str) -> Result<&'static Encoding, String> {
+    encoding_for_label(s).ok_or_else(|| format!("unknown encoding `{}`", s))
+}
+
+fn parse_jobs(s: &str) -> Result<usize, String> {
+    match s.trim().parse() {
+        Ok(0) => Err("expect at least one job".to_string()),
+        Ok(jobs) => Ok(jobs),
+        Err(e) => Err(format!("invalid count `{}`: {}", s, e)),
+    }
+}
+
+fn parse_max_rule(s: &str) -> Result<(String, u128), String> {
+    let (uid, max) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expect `UID=COUNT`, got `{}`", s))?;
+    let max = max
+        .trim()
+        .parse()
+        .map_err(|e| format!("invalid count `{}`: {}", max, e))?;
+    Ok((uid.trim().to_string(), max))
 }
 
 impl TiKeyArgs {
     pub fn new(target: Target, inp: String, out: Option<PathBuf>) -> Self {
-        Self { target, inp, out }
+        Self {
+            target,
+            inp,
+            out,
+            rules: None,
+            config: None,
+            tidb_version: None,
+            upgrade_from: None,
+            format: Format::Table,
+            fail_on: FailOn::Error,
+            max_errors: 0,
+            max_warnings: 0,
+            max_rules: vec![],
+            baseline: None,
+            jobs: None,
+            encoding: None,
+        }
+    }
+
+    pub fn with_rules(mut self, rules: PathBuf) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+
+    pub fn with_config(mut self, config: PathBuf) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn with_tidb_version(mut self, tidb_version: Version) -> Self {
+        self.tidb_version = Some(tidb_version);
+        self
+    }
+
+    /// Report the delta of upgrading from the version to the one of `with_tidb_version`.
+    pub fn with_upgrade_from(mut self, upgrade_from: Version) -> Self {
+        self.upgrade_from = Some(upgrade_from);
+        self
+    }
+
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn with_thresholds(
+        mut self,
+        fail_on: FailOn,
+        max_errors: u128,
+        max_warnings: u128,
+    ) -> Self {
+        self.fail_on = fail_on;
+        self.max_errors = max_errors;
+        self.max_warnings = max_warnings;
+        self
+    }
+
+    pub fn with_max_rule(mut self, uid: String, max: u128) -> Self {
+        self.max_rules.push((uid, max));
+        self
+    }
+
+    pub fn with_baseline(mut self, baseline: PathBuf) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    pub fn with_jobs(mut self, jobs: usize) -> Self {
+        self.jobs = Some(jobs);
+        self
+    }
+
+    /// Decode the input files from the encoding rather than the detected one.
+    pub fn with_encoding(mut self, encoding: &'static Encoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Whether the findings are written while checking, rather than reported once all are
+    /// found. A baseline or an upgrade delta needs all of them first, as do the formats
+    /// other than text lines and JSON Lines.
+    fn streams(&self) -> bool {
+        matches!(self.target, Target::File | Target::Dir)
+            && matches!(
+                self.format,
+                Format::Compact | Format::Annotated | Format::Jsonl
+            )
+            && self.baseline.is_none()
+            && self.upgrade_from.is_none()
+    }
+
+    /// Whether to color the report, only when shown on a terminal, see `NO_COLOR`.
+    fn color(&self) -> bool {
+        self.out.is_none() && io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none()
+    }
+
+    fn thresholds(&self) -> Thresholds {
+        let mut thresholds = Thresholds::new(self.fail_on)
+            .with_max_errors(self.max_errors)
+            .with_max_warnings(self.max_warnings);
+        for (uid, max) in &self.max_rules {
+            thresholds = thresholds.with_max_rule(uid.clone(), *max);
+        }
+        thresholds
+    }
+}
+
+/// How a run ended, each with its own exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitStatus {
+    /// no findings above the thresholds
+    Success,
+    /// findings above the thresholds
+    Findings,
+    /// the input, rules or config could not be read, some files failed to check or some
+    /// statements failed to parse
+    InvalidInput,
+    /// anything else went wrong
+    InternalError,
+}
+
+impl ExitStatus {
+    pub fn code(&self) -> i32 {
+        match self {
+            ExitStatus::Success => 0,
+            ExitStatus::Findings => 1,
+            ExitStatus::InvalidInput => 2,
+            ExitStatus::InternalError => 3,
+        }
+    }
+
+    /// The status of an error returned by `client`.
+    pub fn of_error(e: &anyhow::Error) -> Self {
+        match e.downcast_ref::<InvalidInput>() {
+            Some(_) => ExitStatus::InvalidInput,
+            None => ExitStatus::InternalError,
+        }
+    }
+}
+
+/// Marks an error caused by the input rather than by TiKey itself.
+#[derive(Debug)]
+pub struct InvalidInput;
+
+impl fmt::Display for InvalidInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid input")
     }
 }
 
@@ -43,61 +280,385 @@ pub enum Target {
     Statement,
     File,
     Dir,
+    /// Manage the baseline of known findings, for a file or a directory as input
+    #[command(subcommand)]
+    Baseline(BaselineCommand),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum BaselineCommand {
+    /// Save the findings of the input as the baseline, hidden from reports by `--baseline`
+    Create {
+        /// path of the baseline file
+        #[arg(long, default_value = BASELINE_FILE)]
+        file: PathBuf,
+    },
 }
 
-fn show_report(sum: Summary, all_info: Vec<OnceInfo>) {
-    println!("{}", format_summary(sum));
-    for info in all_info {
-        for table_rec in format_records(info) {
-            println!("{}", table_rec);
+/// Format of the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// text tables for humans
+    #[default]
+    Table,
+    /// a line for each finding, like `file:line:col: error[m4]: description`
+    Compact,
+    /// compiler style findings with the whole statement marked, not the token at fault,
+    /// colored on a terminal
+    Annotated,
+    /// a JSON object, see `format_json`
+    Json,
+    /// JSON Lines, one finding per line, see `format_jsonl`
+    Jsonl,
+    /// a SARIF 2.1.0 log for code scanning, see `format_sarif`
+    Sarif,
+    /// JUnit XML, a test suite per file, see `format_junit`
+    Junit,
+    /// Checkstyle XML, see `format_checkstyle`
+    Checkstyle,
+    /// a single HTML page working offline, see `format_html`
+    Html,
+}
+
+impl Format {
+    fn extension(&self) -> &'static str {
+        match self {
+            Format::Table | Format::Compact | Format::Annotated => "txt",
+            Format::Json => "json",
+            Format::Jsonl => "jsonl",
+            Format::Sarif => "sarif",
+            Format::Junit | Format::Checkstyle => "xml",
+            Format::Html => "html",
+        }
+    }
+}
+
+/// Everything found by a run, to be rendered.
+struct Report {
+    sum: Summary,
+    delta: Option<UpgradeDelta>,
+    files: Vec<PathBuf>,
+    all_info: Vec<OnceInfo>,
+    failures: Vec<FileFailure>,
+    fixed: Vec<BaselineEntry>,
+}
+
+/// Render the whole report, each part of it ends with a newline.
+fn render_report(
+    format: Format,
+    registry: &RuleRegistry,
+    report: Report,
+    color: bool,
+) -> Result<String> {
+    let Report {
+        sum,
+        delta,
+        files,
+        all_info,
+        failures,
+        fixed,
+    } = report;
+    let mut parts: Vec<String> = vec![];
+    match format {
+        Format::Table => {
+            parts.push(format_summary(sum));
+            if let Some(delta) = delta {
+                parts.push(format_delta(&delta));
+            }
+            for failure in failures {
+                parts.push(format_failure(failure));
+            }
+            for entry in &fixed {
+                parts.push(format_fixed(entry));
+            }
+            for info in all_info {
+                parts.extend(format_records(info));
+            }
+        }
+        // the totals go last, where they are seen first on a terminal
+        Format::Compact | Format::Annotated => {
+            for failure in failures {
+                parts.push(format_failure_compact(failure));
+            }
+            for entry in &fixed {
+                parts.push(format_fixed_compact(entry));
+            }
+            for info in all_info {
+                match format {
+                    Format::Compact => parts.extend(format_compact(info)),
+                    _ => parts.extend(format_annotated(info, color)),
+                }
+            }
+            if let Some(delta) = delta {
+                parts.push(format_delta(&delta));
+            }
+            parts.push(format_totals(&sum));
         }
+        Format::Json => {
+            let json = format_json(&sum, delta.as_ref(), &all_info, &failures, &fixed)?;
+            parts.push(json);
+        }
+        Format::Jsonl => {
+            let lines = format_jsonl(&sum, delta.as_ref(), &all_info, &failures, &fixed)?;
+            parts.extend(lines);
+        }
+        Format::Sarif => parts.push(format_sarif(registry, &all_info, &failures)?),
+        Format::Junit => parts.push(format_junit(&sum, &files, &all_info, &failures)),
+        Format::Checkstyle => parts.push(format_checkstyle(&files, &all_info, &failures)),
+        Format::Html => parts.push(format_html(&sum, &files, &all_info, &failures)),
     }
+    Ok(parts.into_iter().map(|p| p + "\n").collect())
 }
 
-fn save_report(path: Option<&Path>, sum: Summary, all_info: Vec<OnceInfo>) -> Result<()> {
-    let path = path.unwrap_or_else(|| Path::new("report.txt"));
+fn show_report(report: &str) {
+    print!("{}", report);
+}
+
+fn save_report(path: Option<&Path>, format: Format, report: &str) -> Result<()> {
+    let default_path = PathBuf::from(format!("report.{}", format.extension()));
+    let path = path.unwrap_or(default_path.as_path());
     if path.is_dir() {
         panic!("path is a file!");
     }
     let mut file = File::create(path)?;
-    let summary = format!("{}\n", format_summary(sum));
-    file.write(summary.as_bytes())?;
-    for info in all_info {
-        for table_rec in format_records(info) {
-            file.write(table_rec.as_bytes())?;
-            file.write(b"\n")?;
-        }
-    }
+    file.write_all(report.as_bytes())?;
     Ok(())
 }
 
-pub fn client(args: TiKeyArgs) -> Result<()> {
-    let (sum, all_info) = match args.target {
-        Target::Statement => check_statements(args.inp)?,
-        Target::File => check_file(args.inp)?,
+/// The summary, findings and failures of checking an input, with the files checked.
+type Checked = (Summary, Vec<OnceInfo>, Vec<FileFailure>, Vec<PathBuf>);
+
+fn check_file_input(
+    registry: &RuleRegistry,
+    inp: String,
+    encoding: Option<&'static Encoding>,
+) -> Result<Checked> {
+    let (sum, all_info) =
+        check_file_with_encoding(registry, &inp, encoding).context(InvalidInput)?;
+    Ok((sum, all_info, vec![], vec![PathBuf::from(inp)]))
+}
+
+/// Check all `.sql` files in the directory.
+fn check_dir_input(
+    registry: &RuleRegistry,
+    inp: String,
+    jobs: usize,
+    encoding: Option<&'static Encoding>,
+) -> Result<Checked> {
+    let all_files = sql_files(&inp)?;
+    let (sum, all_info, failures) =
+        check_files_with_encoding(registry, all_files.clone(), jobs, encoding)
+            .context(InvalidInput)?;
+    Ok((sum, all_info, failures, all_files))
+}
+
+/// All `.sql` files in the directory.
+fn sql_files(inp: &str) -> Result<Vec<PathBuf>> {
+    let mut all_files: Vec<PathBuf> = vec![];
+    for entry in WalkDir::new(inp) {
+        let entry = entry.context(InvalidInput)?;
+        all_files.push(entry.into_path());
+    }
+    let all_files = all_files;
+    #[allow(clippy::match_like_matches_macro)]
+    let all_files: Vec<PathBuf> = all_files
+        .into_iter()
+        .filter(|p| match p.extension() {
+            Some(s) => match s.to_str() {
+                Some("sql") => true,
+                _ => false,
+            },
+            None => false,
+        })
+        .collect();
+    Ok(all_files)
+}
+
+/// Check the file or the `.sql` files in the directory, writing each finding as soon as
+/// it is found, and the failures and totals once all are checked.
+///
+/// Only the counts of the findings are kept, so that the memory stays flat however many
+/// are found. The findings of a directory are written in the order of its files.
+fn stream_check(
+    args: &TiKeyArgs,
+    registry: &RuleRegistry,
+    config: Option<&Config>,
+    thresholds: &Thresholds,
+    jobs: usize,
+) -> Result<ExitStatus> {
+    let mut out: Box<dyn Write> = match &args.out {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(io::stdout().lock()),
+    };
+    let color = args.color();
+    // the findings left by the config, counted again
+    let mut counted = Summary::default();
+    let mut flagged: u128 = 0;
+    let mut on_info = |info: OnceInfo| -> Result<()> {
+        let info = match config {
+            Some(config) => match apply_config_to(config, info) {
+                Some(info) => info,
+                None => return Ok(()),
+            },
+            None => info,
+        };
+        if config.is_some() {
+            counted.count(&info);
+            flagged += info.of_statement() as u128;
+        }
+        let lines = match args.format {
+            Format::Compact => format_compact(info),
+            Format::Annotated => format_annotated(info, color),
+            _ => format_jsonl_findings(slice::from_ref(&info))?,
+        };
+        for line in lines {
+            writeln!(out, "{}", line)?;
+        }
+        Ok(())
+    };
+    let (sum, failures) = match &args.target {
         Target::Dir => {
-            let mut all_files: Vec<PathBuf> = vec![];
-            for entry in WalkDir::new(args.inp) {
-                let entry = entry?;
-                all_files.push(entry.into_path());
-            }
-            let all_files = all_files;
-            let all_files = all_files
-                .into_iter()
-                .filter(|p| match p.extension() {
-                    Some(s) => match s.to_str() {
-                        Some("sql") => true,
-                        _ => false,
-                    },
-                    None => false,
-                })
-                .collect();
-            check_files(all_files)?
+            let all_files = sql_files(&args.inp)?;
+            stream_files(registry, &all_files, jobs, args.encoding, &mut on_info)
+                .context(InvalidInput)?
+        }
+        _ => {
+            let sum = stream_file(registry, &args.inp, args.encoding, &mut on_info)
+                .context(InvalidInput)?;
+            (sum, vec![])
         }
     };
+    let sum = match config {
+        Some(_) => sum.with_counts(counted, flagged),
+        None => sum,
+    };
+    let exceeded = thresholds.exceeded(&sum);
+    let status = check_status(&failures, &sum, &exceeded);
+    // the failures are only known once all are checked, so they go after the findings
+    let mut lines: Vec<String> = vec![];
+    match args.format {
+        Format::Jsonl => lines.extend(format_jsonl(&sum, None, &[], &failures, &[])?),
+        _ => {
+            lines.extend(failures.into_iter().map(format_failure_compact));
+            lines.push(format_totals(&sum));
+        }
+    }
+    for line in lines {
+        writeln!(out, "{}", line)?;
+    }
+    out.flush()?;
+    for reason in exceeded {
+        eprintln!("check failed: {}", reason);
+    }
+    Ok(status)
+}
+
+/// The status of a check, of its failures first, then of the thresholds exceeded.
+fn check_status(failures: &[FileFailure], sum: &Summary, exceeded: &[String]) -> ExitStatus {
+    // a statement failing to parse is checked no further, like a file failing to read,
+    // so it fails the check whatever the thresholds unless suppressed or in the baseline
+    let unparsed = sum.by_rule().contains_key(&RuleParseError {}.uid());
+    if !failures.is_empty() || unparsed {
+        ExitStatus::InvalidInput
+    } else if !exceeded.is_empty() {
+        ExitStatus::Findings
+    } else {
+        ExitStatus::Success
+    }
+}
+
+/// Run a check and report it, returning whether the findings fail it.
+///
+/// An error caused by the input has the context `InvalidInput`, see `ExitStatus::of_error`.
+pub fn client(args: TiKeyArgs) -> Result<ExitStatus> {
+    let mut registry = RuleRegistry::builtin();
+    if let Some(path) = &args.rules {
+        registry.load_rules(path).context(InvalidInput)?;
+    }
+    let config = match &args.config {
+        Some(path) => Some(Config::load(path).context(InvalidInput)?),
+        None => match args.target {
+            Target::Statement => Config::find("."),
+            _ => Config::find(&args.inp),
+        }
+        .context(InvalidInput)?,
+    };
+    if let Some(config) = &config {
+        config.validate(&registry).context(InvalidInput)?;
+    }
+    let thresholds = args.thresholds();
+    thresholds.validate(&registry).context(InvalidInput)?;
+    // findings of both versions are needed to compare them
+    if let (Some(version), None) = (&args.tidb_version, &args.upgrade_from) {
+        registry.retain_version(version);
+    }
+    let baseline = match &args.baseline {
+        Some(path) => Some(Baseline::load(path).context(InvalidInput)?),
+        None => None,
+    };
+    let jobs = args.jobs.unwrap_or_else(default_jobs);
+    if args.streams() {
+        return stream_check(&args, &registry, config.as_ref(), &thresholds, jobs);
+    }
+    let color = args.color();
+    let (sum, all_info, failures, files) = match &args.target {
+        Target::Statement => {
+            let (sum, all_info) = check_statements_with(&registry, args.inp)?;
+            (sum, all_info, vec![], vec![])
+        }
+        Target::File => check_file_input(&registry, args.inp, args.encoding)?,
+        Target::Dir => check_dir_input(&registry, args.inp, jobs, args.encoding)?,
+        Target::Baseline(_) if Path::new(&args.inp).is_dir() => {
+            check_dir_input(&registry, args.inp, jobs, args.encoding)?
+        }
+        Target::Baseline(_) => check_file_input(&registry, args.inp, args.encoding)?,
+    };
+    let (sum, all_info) = match &config {
+        Some(config) => apply_config(config, sum, all_info),
+        None => (sum, all_info),
+    };
+    let (sum, delta, all_info) = match (&args.upgrade_from, &args.tidb_version) {
+        (Some(from), Some(to)) => {
+            let delta = upgrade_delta(from, to, &all_info);
+            let (sum, all_info) = apply_version(to, sum, all_info);
+            (sum, Some(delta), all_info)
+        }
+        _ => (sum, None, all_info),
+    };
+    if let Target::Baseline(BaselineCommand::Create { file }) = &args.target {
+        let baseline = Baseline::create(&all_info);
+        baseline.save(file)?;
+        eprintln!(
+            "baseline of {} findings saved to {:?}",
+            baseline.findings().len(),
+            file
+        );
+        return match failures.is_empty() {
+            true => Ok(ExitStatus::Success),
+            false => Ok(ExitStatus::InvalidInput),
+        };
+    }
+    let (sum, all_info, fixed) = match &baseline {
+        Some(baseline) => baseline.apply(sum, all_info),
+        None => (sum, all_info, vec![]),
+    };
+    let exceeded = thresholds.exceeded(&sum);
+    let status = check_status(&failures, &sum, &exceeded);
+    let report = Report {
+        sum,
+        delta,
+        files,
+        all_info,
+        failures,
+        fixed,
+    };
+    let report = render_report(args.format, &registry, report, color)?;
     match args.out {
-        Some(path) => save_report(Some(path.as_path()), sum, all_info)?,
-        None => show_report(sum, all_info),
+        Some(path) => save_report(Some(path.as_path()), args.format, &report)?,
+        None => show_report(&report),
     };
-    Ok(())
+    for reason in exceeded {
+        eprintln!("check failed: {}", reason);
+    }
+    Ok(status)
 }
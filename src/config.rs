@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+
+use crate::rules::{InfoLevel, RuleInfo};
+use crate::RuleRegistry;
+
+pub const CONFIG_FILE: &str = "tikey.toml";
+
+/// Settings of rules, read from a `tikey.toml` like
+///
+/// ```toml
+/// [rules.m2]
+/// enabled = false
+///
+/// [rules.s3]
+/// level = "ignore"
+///
+/// # procedures are rewritten by another team, count them cheaper
+/// [rules.h4]
+/// effort = 2
+///
+/// # relaxed rules for files under `legacy/`, later overrides win
+/// [[overrides]]
+/// paths = ["legacy"]
+/// rules.m1.level = "warning"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    rules: HashMap<String, RuleSetting>,
+    #[serde(default)]
+    overrides: Vec<Override>,
+    /// Directory holding the config file, override paths are relative to it.
+    #[serde(skip)]
+    root: PathBuf,
+    /// Path of each file relative to `root`, by the path it was checked as.
+    #[serde(skip)]
+    relatives: Mutex<HashMap<PathBuf, Vec<String>>>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+pub struct RuleSetting {
+    enabled: Option<bool>,
+    level: Option<Level>,
+    effort: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Error,
+    Warning,
+    Ignore,
+}
+
+/// Rule settings for the files matched by any of `paths`.
+///
+/// A path is a `/` separated pattern, matching a file or any of its parent directories,
+/// where `*` matches any part of a name and `**` matches any number of directories.
+#[derive(Debug, Deserialize)]
+pub struct Override {
+    paths: Vec<String>,
+    #[serde(default)]
+    rules: HashMap<String, RuleSetting>,
+}
+
+impl RuleSetting {
+    fn merge(&mut self, other: &RuleSetting) {
+        if other.enabled.is_some() {
+            self.enabled = other.enabled;
+        }
+        if other.level.is_some() {
+            self.level = other.level.clone();
+        }
+        if other.effort.is_some() {
+            self.effort = other.effort;
+        }
+    }
+}
+
+impl Config {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("At config: {:?}", path))?;
+        let mut config: Config =
+            toml::from_str(&contents).with_context(|| format!("At config: {:?}", path))?;
+        let root = match path.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        config.root = fs::canonicalize(&root).unwrap_or(root);
+        Ok(config)
+    }
+
+    /// Find `tikey.toml` in the directory of the input or any of its ancestors.
+    pub fn find<P: AsRef<Path>>(start: P) -> Result<Option<Self>> {
+        let start = start.as_ref();
+        let start = fs::canonicalize(start).unwrap_or_else(|_| start.to_path_buf());
+        let dir = if start.is_dir() {
+            Some(start.as_path())
+        } else {
+            start.parent()
+        };
+        for dir in dir.into_iter().flat_map(|d| d.ancestors()) {
+            let path = dir.join(CONFIG_FILE);
+            if path.is_file() {
+                return Self::load(path).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Make sure every rule named in the config is registered, to catch typos.
+    pub fn validate(&self, registry: &RuleRegistry) -> Result<()> {
+        let uids = registry.uids();
+        let named = self
+            .rules
+            .keys()
+            .chain(self.overrides.iter().flat_map(|o| o.rules.keys()));
+        for uid in named {
+            if !uids.contains(&uid) {
+                return Err(anyhow!("unknown rule `{}` in {}", uid, CONFIG_FILE));
+            }
+        }
+        Ok(())
+    }
+
+    /// The setting of a rule for the file, `None` if the statement is not from a file.
+    pub fn setting(&self, uid: &str, file: Option<&Path>) -> RuleSetting {
+        self.setting_of(uid, self.relative(file).as_deref())
+    }
+
+    /// The setting of a rule for the file relative to the root, see `relative`.
+    fn setting_of(&self, uid: &str, relative: Option<&[String]>) -> RuleSetting {
+        let mut setting = self.rules.get(uid).cloned().unwrap_or_default();
+        let relative = match relative {
+            Some(relative) => relative,
+            None => return setting,
+        };
+        for o in &self.overrides {
+            if !o.paths.iter().any(|p| path_matches(p, relative)) {
+                continue;
+            }
+            if let Some(s) = o.rules.get(uid) {
+                setting.merge(s);
+            }
+        }
+        setting
+    }
+
+    /// Apply the settings to the records of a statement, dropping disabled or ignored rules.
+    pub fn apply(&self, file: Option<&Path>, records: Vec<RuleInfo>) -> Vec<RuleInfo> {
+        let relative = self.relative(file);
+        records
+            .into_iter()
+            .filter_map(|mut rec| {
+                let setting = self.setting_of(rec.uid(), relative.as_deref());
+                if setting.enabled == Some(false) {
+                    return None;
+                }
+                match setting.level {
+                    Some(Level::Ignore) => return None,
+                    Some(Level::Error) => rec.set_info_level(InfoLevel::ERROR),
+                    Some(Level::Warning) => rec.set_info_level(InfoLevel::WARNING),
+                    None => {}
+                }
+                if let Some(effort) = setting.effort {
+                    rec.set_effort(effort);
+                }
+                Some(rec)
+            })
+            .collect()
+    }
+
+    /// The parts of the path of the file relative to the root, only needed by overrides.
+    ///
+    /// A file is resolved once, the findings of a file asking for it again and again.
+    fn relative(&self, file: Option<&Path>) -> Option<Vec<String>> {
+        let file = file.filter(|_| !self.overrides.is_empty())?;
+        let mut relatives = self.relatives.lock().unwrap_or_else(|e| e.into_inner());
+        let relative = relatives.entry(file.to_path_buf()).or_insert_with(|| {
+            let file = fs::canonicalize(file).unwrap_or_else(|_| file.to_path_buf());
+            let file = file.strip_prefix(&self.root).unwrap_or(&file);
+            file.components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect()
+        });
+        Some(relative.clone())
+    }
+}
+
+/// Whether the pattern matches the path, or a directory leading to it.
+fn path_matches(pattern: &str, path: &[String]) -> bool {
+    fn matches_from(pattern: &[&str], path: &[String]) -> bool {
+        match pattern.split_first() {
+            // the rest of path is inside the matched directory
+            None => true,
+            Some((first, rest)) if *first == "**" => {
+                (0..=path.len()).any(|skip| matches_from(rest, &path[skip..]))
+            }
+            Some((first, rest)) => match path.split_first() {
+                Some((name, path_rest)) => {
+                    name_matches(first, name) && matches_from(rest, path_rest)
+                }
+                None => false,
+            },
+        }
+    }
+    let pattern: Vec<&str> = pattern
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != ".")
+        .collect();
+    matches_from(&pattern, path)
+}
+
+/// Whether the name matches the pattern, `*` matching any characters.
+fn name_matches(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => match name.strip_prefix(prefix) {
+            Some(name) => (0..=name.len())
+                .filter(|i| name.is_char_boundary(*i))
+                .any(|i| name_matches(rest, &name[i..])),
+            None => false,
+        },
+    }
+}
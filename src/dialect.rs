@@ -1,5 +1,10 @@
-use std::sync::atomic::AtomicBool;
-use std::sync::atomic::Ordering::SeqCst;
+use std::borrow::Cow;
+use std::cell::Cell;
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use serde::Serialize;
 
 /// This is a hack for some statements sqlparser failed to parser.
 /// A bad pratice, will be removed if sqlparser support them.
@@ -21,26 +26,1105 @@ use sqlparser::tokenizer::Word;
 
 const DEFAULT_DELIMITER: Token = Token::SemiColon;
 
+const DELIMITER_DIRECTIVE: &str = "delimiter";
+
+const SUPPRESSION_PREFIX: &str = "tikey:";
+
+const INSERT_KEYWORD: &str = "insert";
+
+/// Position of a char in the original input, both `line` and `column` start from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct Location {
+    pub line: u64,
+    pub column: u64,
+}
+
+/// Range of a statement in the original input, `end` is the position of its last char.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct Span {
+    pub start: Location,
+    pub end: Location,
+}
+
+/// Source text of a single statement, without leading comments and the ending delimiter.
 #[derive(Debug)]
-pub struct MysqlBeyondDialect {
-    pub is_recalled: AtomicBool,
+pub struct RawStatement<'a> {
+    pub text: &'a str,
+    pub span: Span,
+    /// The text is an expression inside a routine body, e.g. condition of `IF`,
+    /// which should be checked as `SELECT <text>`.
+    pub expression: bool,
+    /// Rules suppressed by `tikey:` comments for this statement.
+    pub suppression: Suppression,
+    /// The text is a bulk data insert, ending its first row at this byte offset,
+    /// see `bulk_insert_end`.
+    pub bulk: Option<usize>,
+}
+
+/// Rules suppressed by comments, e.g. `-- tikey:disable-next-statement h6` before a statement,
+/// or `/* tikey:disable m4 */ ... /* tikey:enable m4 */` around statements.
+/// A directive without any uid applies to all rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Suppression {
+    all: bool,
+    uids: HashSet<String>,
+    /// rules enabled again after all rules were disabled
+    except: HashSet<String>,
+}
+
+impl Suppression {
+    pub fn covers(&self, uid: &str) -> bool {
+        (self.all && !self.except.contains(uid)) || self.uids.contains(uid)
+    }
+
+    fn disable(&mut self, uids: &[String]) {
+        if uids.is_empty() {
+            *self = Self {
+                all: true,
+                ..Self::default()
+            };
+        }
+        for uid in uids {
+            if self.all {
+                self.except.remove(uid);
+            } else {
+                self.uids.insert(uid.clone());
+            }
+        }
+    }
+
+    fn enable(&mut self, uids: &[String]) {
+        if uids.is_empty() {
+            *self = Self::default();
+        }
+        for uid in uids {
+            if self.all {
+                self.except.insert(uid.clone());
+            } else {
+                self.uids.remove(uid);
+            }
+        }
+    }
+}
+
+/// Follow suppression comments through the input, in the order they appear.
+#[derive(Clone, Default)]
+struct SuppressionTracker {
+    region: Suppression,
+    /// uids of `disable-next-statement` directives since the last statement
+    next_statement: Vec<Vec<String>>,
+}
+
+impl SuppressionTracker {
+    fn within(region: Suppression) -> Self {
+        Self {
+            region,
+            next_statement: vec![],
+        }
+    }
+
+    fn read_comment(&mut self, comment: &str) {
+        let comment = comment.trim_matches(|c: char| {
+            c == '-' || c == '#' || c == '/' || c == '*' || c.is_whitespace()
+        });
+        let len = SUPPRESSION_PREFIX.len();
+        if !comment.is_char_boundary(len)
+            || !comment[..len].eq_ignore_ascii_case(SUPPRESSION_PREFIX)
+        {
+            return;
+        }
+        let mut words = comment[len..]
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|w| !w.is_empty());
+        let directive = words.next().unwrap_or("").to_ascii_lowercase();
+        let uids: Vec<String> = words.map(|w| w.to_lowercase()).collect();
+        match directive.as_str() {
+            "disable-next-statement" => self.next_statement.push(uids),
+            "disable" => self.region.disable(&uids),
+            "enable" => self.region.enable(&uids),
+            _ => {}
+        }
+    }
+
+    /// Suppression of a statement starting now, which consumes `disable-next-statement`.
+    fn start_statement(&mut self) -> Suppression {
+        let mut suppression = self.region.clone();
+        for uids in self.next_statement.drain(..) {
+            suppression.disable(&uids);
+        }
+        suppression
+    }
+}
+
+impl<'a> RawStatement<'a> {
+    /// The text handed to sqlparser, always ended by `;`.
+    ///
+    /// Only the first row of a bulk data insert is handed, the others are never parsed.
+    pub fn to_sql(&self) -> String {
+        let text = match self.bulk {
+            Some(end) => &self.text[..end],
+            None => self.text,
+        };
+        let text = sqlparser_comments(text);
+        if self.expression {
+            format!("SELECT {};", text)
+        } else {
+            format!("{};", text)
+        }
+    }
+}
+
+/// The text with comments written the way sqlparser reads them: a `#` comment becomes a
+/// `--` comment, and a `--` not followed by a whitespace is two minus signs as in MySQL.
+fn sqlparser_comments(text: &str) -> Cow<'_, str> {
+    if !text.contains('#') && !text.contains("--") {
+        return Cow::Borrowed(text);
+    }
+    let mut scanner = Scanner::at(text, Location::default());
+    let mut sql = String::with_capacity(text.len());
+    let mut copied = 0;
+    while let Some(ch) = scanner.peek() {
+        let rest = scanner.rest();
+        if rest.starts_with('#') {
+            sql.push_str(&text[copied..scanner.offset]);
+            sql.push_str("--");
+            scanner.next();
+            copied = scanner.offset;
+            scanner.skip_line();
+        } else if scanner.at_line_comment() {
+            scanner.skip_line();
+        } else if rest.starts_with("--") {
+            sql.push_str(&text[copied..scanner.offset]);
+            sql.push_str("- -");
+            scanner.advance_to(scanner.offset + 2);
+            copied = scanner.offset;
+        } else if rest.starts_with("/*") {
+            scanner.next();
+            scanner.skip_block_comment();
+        } else {
+            scanner.next();
+            if let '\'' | '"' | '`' = ch {
+                scanner.skip_quoted(ch);
+            }
+        }
+    }
+    sql.push_str(&text[copied..]);
+    Cow::Owned(sql)
+}
+
+/// Statements inside the body of `CREATE PROCEDURE/FUNCTION/TRIGGER/EVENT`.
+#[derive(Debug)]
+pub struct Routine<'a> {
+    /// Type and name of the routine, such as `PROCEDURE getname`.
+    pub name: String,
+    pub body: Vec<RawStatement<'a>>,
+}
+
+/// Return the argument of a `DELIMITER` client command, if the text starts with one.
+fn delimiter_argument(text: &str) -> Option<&str> {
+    let len = DELIMITER_DIRECTIVE.len();
+    if !text.is_char_boundary(len) || !text[..len].eq_ignore_ascii_case(DELIMITER_DIRECTIVE) {
+        return None;
+    }
+    let line = text[len..].lines().next()?;
+    if !line.starts_with(|c: char| c.is_whitespace()) {
+        return None;
+    }
+    Some(line.split_whitespace().next().unwrap_or(""))
+}
+
+/// If the text is an `INSERT ... VALUES` whose rows are made of literals only, return
+/// the byte offset ending its first row.
+///
+/// Such rows are most of a dump and match no rule, so that parsing the first one is
+/// enough to check the table and the columns. Anything else in a row, such as a function
+/// call, a charset introducer or a comment, makes it a usual statement.
+fn bulk_insert_end(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let len = INSERT_KEYWORD.len();
+    if !text.get(..len)?.eq_ignore_ascii_case(INSERT_KEYWORD)
+        || bytes.get(len).is_some_and(|b| is_word_byte(*b))
+    {
+        return None;
+    }
+    // the table, the columns and modifiers like `IGNORE`, up to `VALUES`
+    let mut i = len;
+    loop {
+        i = skip_spaces(bytes, i);
+        match *bytes.get(i)? {
+            b'`' => i = skip_string(bytes, i)?,
+            b'.' => i += 1,
+            b'(' => loop {
+                i = skip_spaces(bytes, i + 1);
+                match *bytes.get(i)? {
+                    b'`' => i = skip_string(bytes, i)?,
+                    b if is_word_byte(b) => i = skip_word(bytes, i),
+                    _ => return None,
+                }
+                i = skip_spaces(bytes, i);
+                match *bytes.get(i)? {
+                    b')' => {
+                        i += 1;
+                        break;
+                    }
+                    b',' | b'.' => {}
+                    _ => return None,
+                }
+            },
+            b if is_word_byte(b) => {
+                let end = skip_word(bytes, i);
+                let word = &text[i..end];
+                i = end;
+                if word.eq_ignore_ascii_case("values") || word.eq_ignore_ascii_case("value") {
+                    break;
+                }
+                if ["select", "set", "table", "with"]
+                    .iter()
+                    .any(|k| word.eq_ignore_ascii_case(k))
+                {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+    }
+    // rows of literals, up to the end of the text
+    let mut first_end = None;
+    loop {
+        i = skip_spaces(bytes, i);
+        if *bytes.get(i)? != b'(' {
+            return None;
+        }
+        loop {
+            i = skip_spaces(bytes, i + 1);
+            i = skip_literal(text, i)?;
+            i = skip_spaces(bytes, i);
+            match *bytes.get(i)? {
+                b')' => break,
+                b',' => {}
+                _ => return None,
+            }
+        }
+        i += 1;
+        first_end.get_or_insert(i);
+        i = skip_spaces(bytes, i);
+        match bytes.get(i) {
+            Some(b',') => i += 1,
+            Some(_) => return None,
+            None => return first_end,
+        }
+    }
+}
+
+/// Skip a number, a string, a hexadecimal or bit string, `NULL`, `TRUE` or `FALSE`.
+fn skip_literal(text: &str, start: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    match *bytes.get(start)? {
+        b'\'' | b'"' => skip_string(bytes, start),
+        b'+' | b'-' | b'.' | b'0'..=b'9' => {
+            let sign = usize::from(matches!(bytes[start], b'+' | b'-'));
+            let mut end = start + sign;
+            while let Some(&b) = bytes.get(end) {
+                let exponent = matches!(b, b'+' | b'-') && matches!(bytes[end - 1], b'e' | b'E');
+                if !(b.is_ascii_alphanumeric() || b == b'.' || exponent) {
+                    break;
+                }
+                end += 1;
+            }
+            let number = &text[start + sign..end];
+            let radix = number
+                .get(..2)
+                .filter(|p| p.eq_ignore_ascii_case("0x") || p.eq_ignore_ascii_case("0b"));
+            let valid = match radix {
+                Some(_) => number.len() > 2 && number[2..].bytes().all(|b| b.is_ascii_hexdigit()),
+                None => {
+                    number.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+                        && number.parse::<f64>().is_ok()
+                }
+            };
+            valid.then_some(end)
+        }
+        b if is_word_byte(b) => {
+            let end = skip_word(bytes, start);
+            let word = &text[start..end];
+            if ["x", "b"].iter().any(|k| word.eq_ignore_ascii_case(k))
+                && bytes.get(end) == Some(&b'\'')
+            {
+                return skip_string(bytes, end);
+            }
+            ["null", "true", "false"]
+                .iter()
+                .any(|k| word.eq_ignore_ascii_case(k))
+                .then_some(end)
+        }
+        _ => None,
+    }
+}
+
+/// Skip a string or identifier quoted by the byte at `start`, with escapes and doubled quotes.
+fn skip_string(bytes: &[u8], start: usize) -> Option<usize> {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    loop {
+        match *bytes.get(i)? {
+            b'\\' if quote != b'`' => i += 2,
+            b if b == quote && bytes.get(i + 1) == Some(&quote) => i += 2,
+            b if b == quote => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+}
+
+fn skip_spaces(bytes: &[u8], mut i: usize) -> usize {
+    while bytes.get(i).is_some_and(u8::is_ascii_whitespace) {
+        i += 1;
+    }
+    i
+}
+
+fn skip_word(bytes: &[u8], mut i: usize) -> usize {
+    while bytes.get(i).is_some_and(|b| is_word_byte(*b)) {
+        i += 1;
+    }
+    i
+}
+
+/// A byte of a word, non-ASCII bytes being parts of non-ASCII identifiers.
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || b >= 0x80
+}
+
+struct Scanner<'a> {
+    contents: &'a str,
+    offset: usize,
+    location: Location,
+    last: (usize, Location),
+    /// byte offset and text of comments skipped so far
+    comments: Vec<(usize, &'a str)>,
+    /// a quote or a block comment left open at the end of the input
+    open: Option<Open>,
 }
 
-pub struct FixedStatement(pub Statement);
+/// A quote or a block comment left open at the end of a part of the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Open {
+    Quote(char),
+    /// a block comment, whose text starts at the byte offset
+    BlockComment(usize),
+}
 
-impl ToString for FixedStatement {
-    fn to_string(&self) -> String {
-        match &self.0 {
-            Statement::Comment {
-                object_type: _,
-                object_name: _,
-                comment,
-            } => comment.clone().unwrap_or("".to_string()),
-            _ => self.0.to_string(),
+impl<'a> Scanner<'a> {
+    /// Scan a part of the input, which starts at `location` of the whole input.
+    fn at(contents: &'a str, location: Location) -> Self {
+        Self {
+            contents,
+            offset: 0,
+            location,
+            last: (0, location),
+            comments: vec![],
+            open: None,
+        }
+    }
+
+    /// Scan the input from byte offset `offset`, which is at `location` of the whole input.
+    fn resume(contents: &'a str, offset: usize, location: Location) -> Self {
+        Self {
+            offset,
+            last: (offset, location),
+            ..Self::at(contents, location)
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.contents[self.offset..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Consume a char, returning its byte offset and location.
+    fn next(&mut self) -> Option<(usize, Location, char)> {
+        let ch = self.peek()?;
+        let current = (self.offset, self.location, ch);
+        self.last = (self.offset, self.location);
+        self.offset += ch.len_utf8();
+        if ch == '\n' {
+            self.location.line += 1;
+            self.location.column = 1;
+        } else {
+            self.location.column += 1;
+        }
+        Some(current)
+    }
+
+    /// Consume chars until reaching byte offset `end`.
+    fn advance_to(&mut self, end: usize) {
+        while self.offset < end && self.next().is_some() {}
+    }
+
+    fn skip_line(&mut self) {
+        let start = self.offset;
+        while let Some((_, _, ch)) = self.next() {
+            if ch == '\n' {
+                break;
+            }
+        }
+        self.comments
+            .push((start, &self.contents[start..self.offset]));
+    }
+
+    fn skip_block_comment(&mut self) {
+        self.finish_block_comment(self.offset);
+    }
+
+    /// Consume the rest of a block comment whose text starts at byte offset `start`.
+    fn finish_block_comment(&mut self, start: usize) {
+        let mut prev = ' ';
+        self.open = Some(Open::BlockComment(start));
+        while let Some((_, _, ch)) = self.next() {
+            if prev == '*' && ch == '/' {
+                self.open = None;
+                break;
+            }
+            prev = ch;
+        }
+        self.comments
+            .push((start, &self.contents[start..self.offset]));
+    }
+
+    /// Hand the comments skipped since the last call to the tracker.
+    fn track_comments(&mut self, tracker: &mut SuppressionTracker) {
+        for (_, comment) in self.comments.drain(..) {
+            tracker.read_comment(comment);
+        }
+    }
+
+    /// Consume a quoted string or identifier, returning the position of the closing quote.
+    fn skip_quoted(&mut self, quote: char) -> (usize, Location) {
+        while let Some((offset, location, ch)) = self.next() {
+            if ch == '\\' && quote != '`' {
+                self.next();
+            } else if ch == quote {
+                return (offset, location);
+            }
+        }
+        self.open = Some(Open::Quote(quote));
+        self.last
+    }
+
+    /// Whether a comment to the end of the line starts here, either `#` or `--` followed
+    /// by a whitespace or the end of the input, as MySQL requires for the latter.
+    fn at_line_comment(&self) -> bool {
+        let rest = self.rest();
+        rest.starts_with('#')
+            || rest
+                .strip_prefix("--")
+                .is_some_and(|r| r.chars().next().is_none_or(char::is_whitespace))
+    }
+
+    /// Skip whitespaces and comments, returning false if nothing is left.
+    fn skip_blank(&mut self) -> bool {
+        loop {
+            match self.peek() {
+                Some(ch) if ch.is_whitespace() => {
+                    self.next();
+                }
+                Some(_) if self.at_line_comment() => self.skip_line(),
+                Some('/') if self.rest().starts_with("/*") => {
+                    self.next();
+                    self.skip_block_comment();
+                }
+                Some(_) => return true,
+                None => return false,
+            }
+        }
+    }
+}
+
+/// What splitting a part of the input leaves to the next part.
+struct SplitState {
+    delimiter: String,
+    tracker: SuppressionTracker,
+    /// byte offset in the part where splitting resumes, and its location
+    offset: usize,
+    location: Location,
+    open: Option<Open>,
+    /// first and last chars of the statement split so far
+    start: Option<(usize, Location)>,
+    last: (usize, Location),
+    suppression: Suppression,
+}
+
+impl Default for SplitState {
+    fn default() -> Self {
+        let location = Location { line: 1, column: 1 };
+        Self {
+            delimiter: ";".to_string(),
+            tracker: SuppressionTracker::default(),
+            offset: 0,
+            location,
+            open: None,
+            start: None,
+            last: (0, location),
+            suppression: Suppression::default(),
         }
     }
 }
 
+impl SplitState {
+    /// Byte offset of the first byte of the part still needed to resume splitting.
+    fn kept_from(&self) -> usize {
+        match (self.start, self.open) {
+            (Some((start, _)), _) => start,
+            (None, Some(Open::BlockComment(start))) => start,
+            _ => self.offset,
+        }
+    }
+
+    /// Shift the byte offsets, after the first `count` bytes of the part are dropped.
+    fn drop_bytes(&mut self, count: usize) {
+        self.offset -= count;
+        if let Some((start, _)) = &mut self.start {
+            *start -= count;
+        }
+        self.last.0 = self.last.0.saturating_sub(count);
+        if let Some(Open::BlockComment(start)) = &mut self.open {
+            *start -= count;
+        }
+    }
+}
+
+/// Split a part of the input into statements, remembering where every statement is.
+///
+/// Statements are ended by `;`, or the delimiter set by the `DELIMITER` client command.
+/// Quotes and comments are respected, so that a delimiter inside them will not end the statement.
+///
+/// A `DELIMITER` command changing the delimiter is kept as a statement for `RuleDelimiter`.
+///
+/// Splitting resumes where the state was left, the part being the one split last with
+/// more lines appended. Only the statements ended in the appended lines are returned, the
+/// unfinished one as well `at_end` of the input.
+fn split_part<'a>(
+    contents: &'a str,
+    at_end: bool,
+    state: &mut SplitState,
+) -> Vec<RawStatement<'a>> {
+    let mut scanner = Scanner::resume(contents, state.offset, state.location);
+    let tracker = &mut state.tracker;
+    let delimiter = &mut state.delimiter;
+    let start = &mut state.start;
+    let last = &mut state.last;
+    let suppression = &mut state.suppression;
+    let mut statements = vec![];
+
+    let mut push = |start: &mut Option<(usize, Location)>,
+                    last: (usize, Location),
+                    suppression: &Suppression| {
+        if let Some((start_offset, start_location)) = start.take() {
+            let end = last.0 + contents[last.0..].chars().next().map_or(0, char::len_utf8);
+            statements.push(RawStatement {
+                text: &contents[start_offset..end],
+                span: Span {
+                    start: start_location,
+                    end: last.1,
+                },
+                expression: false,
+                suppression: suppression.clone(),
+                bulk: bulk_insert_end(&contents[start_offset..end]),
+            });
+        }
+    };
+
+    match state.open.take() {
+        // nothing appended, e.g. at the end of the input
+        open if scanner.peek().is_none() => scanner.open = open,
+        Some(Open::Quote(quote)) => *last = scanner.skip_quoted(quote),
+        Some(Open::BlockComment(comment)) => scanner.finish_block_comment(comment),
+        None => {}
+    }
+    loop {
+        if start.is_none() {
+            if !scanner.skip_blank() {
+                break;
+            }
+            if let Some(argument) = delimiter_argument(scanner.rest()) {
+                let line = scanner.rest().lines().next().unwrap_or("");
+                let directive = (scanner.offset, scanner.location);
+                scanner.advance_to(scanner.offset + line.trim_end().len());
+                if !argument.is_empty() {
+                    if argument != ";" {
+                        scanner.track_comments(tracker);
+                        let suppression = tracker.start_statement();
+                        push(&mut Some(directive), scanner.last, &suppression);
+                    }
+                    *delimiter = argument.to_string();
+                }
+                continue;
+            }
+        }
+        if scanner.rest().starts_with(delimiter.as_str()) {
+            scanner.advance_to(scanner.offset + delimiter.len());
+            push(start, *last, suppression);
+            // directives only come from comments between statements
+            scanner.comments.clear();
+            continue;
+        }
+        if scanner.at_line_comment() {
+            scanner.skip_line();
+            continue;
+        }
+        let (offset, location, ch) = match scanner.next() {
+            Some(t) => t,
+            None => break,
+        };
+        match ch {
+            _ if ch.is_whitespace() => continue,
+            '/' if scanner.peek() == Some('*') => {
+                scanner.skip_block_comment();
+                continue;
+            }
+            _ => {}
+        }
+        if start.is_none() {
+            *start = Some((offset, location));
+            scanner.track_comments(tracker);
+            *suppression = tracker.start_statement();
+        }
+        *last = match ch {
+            '\'' | '"' | '`' => scanner.skip_quoted(ch),
+            _ => (offset, location),
+        };
+    }
+    if start.is_none() {
+        // the comment is read once it is closed by a later part
+        if let Some(Open::BlockComment(_)) = scanner.open {
+            scanner.comments.pop();
+        }
+        scanner.track_comments(tracker);
+    }
+    if at_end {
+        push(start, *last, suppression);
+    }
+    state.open = scanner.open;
+    state.offset = scanner.offset;
+    state.location = scanner.location;
+    statements
+}
+
+/// Default of the size a statement may grow to, the default `max_allowed_packet` of MySQL.
+const MAX_STATEMENT_SIZE: usize = 64 * 1024 * 1024;
+
+/// Read statements one line at a time, for inputs too large to be held in memory.
+///
+/// Every line is split where the previous one was left, and the statements ended in it are
+/// handed over at once. Only the statement being read is held, and the statements are split
+/// the same as the whole input would be.
+///
+/// A statement growing beyond the maximum size, e.g. from a quote never closed, is handed
+/// over as it is. The rest of the input is then split as if the statement had ended.
+pub struct StatementReader<R> {
+    reader: R,
+    buffer: String,
+    state: SplitState,
+    max_size: usize,
+}
+
+impl<R: BufRead> StatementReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: String::new(),
+            state: SplitState::default(),
+            max_size: MAX_STATEMENT_SIZE,
+        }
+    }
+
+    /// Hand every statement to `f` in the order of the input, stopping at the first error.
+    pub fn for_each<F, E>(mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&RawStatement) -> Result<(), E>,
+        E: From<io::Error>,
+    {
+        loop {
+            let at_end = self.reader.read_line(&mut self.buffer)? == 0;
+            for raw in &split_part(&self.buffer, at_end, &mut self.state) {
+                f(raw)?;
+            }
+            if at_end {
+                return Ok(());
+            }
+            if self.buffer.len() - self.state.kept_from() > self.max_size {
+                for raw in &split_part(&self.buffer, true, &mut self.state) {
+                    f(raw)?;
+                }
+                self.state.open = None;
+            }
+            let kept_from = self.state.kept_from();
+            self.buffer.drain(..kept_from);
+            self.state.drop_bytes(kept_from);
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum LexemeKind {
+    Word,
+    Quoted,
+    Punct(char),
+}
+
+/// A rough token of the input, only used to find out the structure of routine bodies.
+#[derive(Debug)]
+struct Lexeme {
+    kind: LexemeKind,
+    start: usize,
+    end: usize,
+    start_location: Location,
+    end_location: Location,
+}
+
+/// Split the text into lexemes, also returning the comments with their byte offsets.
+fn lex(text: &str, location: Location) -> (Vec<Lexeme>, Vec<(usize, &str)>) {
+    let mut scanner = Scanner::at(text, location);
+    let mut lexemes = vec![];
+    while scanner.skip_blank() {
+        let (start, start_location, ch) = match scanner.next() {
+            Some(t) => t,
+            None => break,
+        };
+        let kind = match ch {
+            '\'' | '"' | '`' => {
+                scanner.skip_quoted(ch);
+                if ch == '`' {
+                    LexemeKind::Word
+                } else {
+                    LexemeKind::Quoted
+                }
+            }
+            _ if is_word_char(ch) => {
+                while scanner.peek().is_some_and(is_word_char) {
+                    scanner.next();
+                }
+                LexemeKind::Word
+            }
+            _ => LexemeKind::Punct(ch),
+        };
+        lexemes.push(Lexeme {
+            kind,
+            start,
+            end: scanner.offset,
+            start_location,
+            end_location: scanner.last.1,
+        });
+    }
+    (lexemes, scanner.comments)
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_' || ch == '$' || ch == '@'
+}
+
+/// Walk through lexemes of a routine, with the original text to compare words.
+struct RoutineWalker<'a, 'b> {
+    text: &'a str,
+    lexemes: &'b [Lexeme],
+    comments: &'b [(usize, &'a str)],
+    tracker: SuppressionTracker,
+    /// byte offset where the last pushed statement ends
+    statement_end: usize,
+    index: usize,
+    /// depth of `BEGIN ... END` blocks
+    depth: usize,
+    body: Vec<RawStatement<'a>>,
+}
+
+impl<'a, 'b> RoutineWalker<'a, 'b> {
+    fn word(&self, index: usize) -> Option<&'a str> {
+        let lexeme = self.lexemes.get(index)?;
+        match lexeme.kind {
+            LexemeKind::Word => Some(&self.text[lexeme.start..lexeme.end]),
+            _ => None,
+        }
+    }
+
+    fn is_word(&self, index: usize, word: &str) -> bool {
+        self.word(index)
+            .is_some_and(|w| w.eq_ignore_ascii_case(word))
+    }
+
+    fn is_punct(&self, index: usize, punct: char) -> bool {
+        self.lexemes
+            .get(index)
+            .is_some_and(|l| l.kind == LexemeKind::Punct(punct))
+    }
+
+    /// Find the first of `words` from `index` outside parentheses, or the end of statement.
+    fn find(&self, index: usize, words: &[&str]) -> usize {
+        let mut depth = 0;
+        let mut i = index;
+        while i < self.lexemes.len() {
+            if self.is_punct(i, '(') {
+                depth += 1;
+            } else if self.is_punct(i, ')') {
+                depth -= 1;
+            } else if depth <= 0
+                && (self.is_punct(i, ';') || words.iter().any(|w| self.is_word(i, w)))
+            {
+                break;
+            }
+            i += 1;
+        }
+        i
+    }
+
+    /// Skip a parenthesized list starting from `index`, if there is one.
+    fn skip_parentheses(&self, index: usize) -> usize {
+        if !self.is_punct(index, '(') {
+            return index;
+        }
+        let mut depth = 0;
+        for i in index..self.lexemes.len() {
+            if self.is_punct(i, '(') {
+                depth += 1;
+            } else if self.is_punct(i, ')') {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+        }
+        self.lexemes.len()
+    }
+
+    fn push(&mut self, start: usize, end: usize, expression: bool) {
+        if start >= end || end > self.lexemes.len() {
+            return;
+        }
+        let (first, last) = (&self.lexemes[start], &self.lexemes[end - 1]);
+        let read = self
+            .comments
+            .iter()
+            .take_while(|(offset, _)| *offset < first.start)
+            .count();
+        for (offset, comment) in &self.comments[..read] {
+            if *offset >= self.statement_end {
+                self.tracker.read_comment(comment);
+            }
+        }
+        self.comments = &self.comments[read..];
+        self.statement_end = last.end;
+        self.body.push(RawStatement {
+            text: &self.text[first.start..last.end],
+            span: Span {
+                start: first.start_location,
+                end: last.end_location,
+            },
+            expression,
+            suppression: self.tracker.start_statement(),
+            bulk: None,
+        });
+    }
+
+    /// Skip characteristics and `RETURNS` of procedures and functions, returning where body starts.
+    fn skip_characteristics(&self, index: usize) -> usize {
+        let mut i = index;
+        loop {
+            let word = match self.word(i) {
+                Some(w) => w.to_ascii_uppercase(),
+                None => return i,
+            };
+            i = match word.as_str() {
+                "RETURNS" => {
+                    let mut i = self.skip_parentheses(i + 2);
+                    loop {
+                        let modifier = self.word(i).map(|w| w.to_ascii_uppercase());
+                        i = match modifier.as_deref() {
+                            Some("UNSIGNED" | "SIGNED" | "ZEROFILL" | "BINARY") => i + 1,
+                            Some("CHARSET" | "COLLATE") => i + 2,
+                            Some("CHARACTER") => i + 3,
+                            _ => break i,
+                        };
+                    }
+                }
+                "COMMENT" | "LANGUAGE" => i + 2,
+                "DETERMINISTIC" => i + 1,
+                "NOT" | "CONTAINS" | "NO" => i + 2,
+                "READS" | "MODIFIES" | "SQL" => i + 3,
+                _ => return i,
+            };
+        }
+    }
+
+    /// Flatten statements of the body from `index`, including those inside control flows.
+    fn walk(&mut self, index: usize) {
+        self.index = index;
+        let len = self.lexemes.len();
+        while self.index < len {
+            let i = self.index;
+            if self.is_punct(i, ';') {
+                self.index += 1;
+                continue;
+            }
+            // label of block or loop
+            if self.word(i).is_some() && self.is_punct(i + 1, ':') {
+                self.index += 2;
+                continue;
+            }
+            let word = self.word(i).map(|w| w.to_ascii_uppercase());
+            match word.as_deref() {
+                Some("BEGIN") => {
+                    self.depth += 1;
+                    self.index += 1;
+                }
+                Some("END") => {
+                    self.depth = self.depth.saturating_sub(1);
+                    self.index = self.find(i, &[]);
+                }
+                Some("ELSE" | "LOOP" | "REPEAT" | "DO") => self.index += 1,
+                Some("IF" | "ELSEIF" | "WHEN") => self.walk_condition(i, &["THEN"]),
+                Some("WHILE") => self.walk_condition(i, &["DO"]),
+                Some("UNTIL") => self.walk_condition(i, &["END"]),
+                Some("CASE") => {
+                    let end = self.find(i + 1, &["WHEN"]);
+                    self.push(i + 1, end, true);
+                    self.index = end;
+                }
+                Some("RETURN") => {
+                    let end = self.find(i + 1, &[]);
+                    self.push(i + 1, end, true);
+                    self.index = end;
+                }
+                Some("DECLARE") => self.walk_declare(i),
+                Some("LEAVE" | "ITERATE" | "OPEN" | "CLOSE" | "FETCH") => {
+                    self.index = self.find(i, &[]);
+                }
+                _ => {
+                    let mut end = self.find(i, &[]);
+                    // the last statement before `END` could be without `;`
+                    if end == len && self.depth > 0 && self.is_word(end - 1, "END") {
+                        end -= 1;
+                        self.depth -= 1;
+                    }
+                    self.push(i, end, false);
+                    self.index = end.max(i + 1);
+                }
+            }
+        }
+    }
+
+    fn walk_condition(&mut self, index: usize, until: &[&str]) {
+        let end = self.find(index + 1, until);
+        self.push(index + 1, end, true);
+        self.index = if self.is_punct(end, ';') || self.is_word(end, "END") {
+            end
+        } else {
+            end + 1
+        };
+    }
+
+    fn walk_declare(&mut self, index: usize) {
+        let end = self.find(index, &["CURSOR", "HANDLER"]);
+        if self.is_word(end, "CURSOR") && self.is_word(end + 1, "FOR") {
+            let cursor_end = self.find(end + 2, &[]);
+            self.push(end + 2, cursor_end, false);
+            self.index = cursor_end;
+        } else if self.is_word(end, "HANDLER") && self.is_word(end + 1, "FOR") {
+            // skip conditions, then the handler statement follows
+            let mut i = end + 2;
+            loop {
+                i += if self.is_word(i, "SQLSTATE") {
+                    if self.is_word(i + 1, "VALUE") {
+                        3
+                    } else {
+                        2
+                    }
+                } else if self.is_word(i, "NOT") {
+                    2
+                } else {
+                    1
+                };
+                if !self.is_punct(i, ',') {
+                    break;
+                }
+                i += 1;
+            }
+            self.index = i;
+        } else {
+            self.index = self.find(end, &[]);
+        }
+    }
+}
+
+/// Find the body of `CREATE PROCEDURE/FUNCTION/TRIGGER/EVENT`, split into single statements.
+///
+/// Control flows like `IF ... END IF` are flattened, with their conditions kept as expressions.
+pub fn split_routine<'a>(raw: &RawStatement<'a>) -> Option<Routine<'a>> {
+    let (lexemes, comments) = lex(raw.text, raw.span.start);
+    let mut walker = RoutineWalker {
+        text: raw.text,
+        lexemes: &lexemes,
+        comments: &comments,
+        tracker: SuppressionTracker::within(raw.suppression.clone()),
+        statement_end: 0,
+        index: 0,
+        depth: 0,
+        body: vec![],
+    };
+    let kind = (0..lexemes.len())
+        .take_while(|i| !walker.is_punct(*i, '('))
+        .find(|i| {
+            ["PROCEDURE", "FUNCTION", "TRIGGER", "EVENT"]
+                .iter()
+                .any(|w| walker.is_word(*i, w))
+        })?;
+    let mut name = kind + 1;
+    if walker.is_word(name, "IF") {
+        name += 3;
+    }
+    let mut body = name + 1;
+    while walker.is_punct(body, '.') {
+        body += 2;
+    }
+    let routine_name = format!(
+        "{} {}",
+        walker.word(kind)?,
+        &raw.text[lexemes.get(name)?.start..lexemes.get(body - 1)?.end]
+    );
+
+    let body = match walker.word(kind)?.to_ascii_uppercase().as_str() {
+        "PROCEDURE" | "FUNCTION" => walker.skip_characteristics(walker.skip_parentheses(body)),
+        "TRIGGER" => {
+            let row = (body..lexemes.len()).find(|i| {
+                walker.is_word(*i, "FOR")
+                    && walker.is_word(i + 1, "EACH")
+                    && walker.is_word(i + 2, "ROW")
+            })? + 3;
+            if walker.is_word(row, "FOLLOWS") || walker.is_word(row, "PRECEDES") {
+                row + 2
+            } else {
+                row
+            }
+        }
+        _ => walker.find(body, &["DO"]) + 1,
+    };
+    walker.walk(body);
+    Some(Routine {
+        name: routine_name,
+        body: walker.body,
+    })
+}
+
+/// The dialect recalls sqlparser for statements it does not handle by itself, while
+/// `is_recalled` tells the recalled parsing to skip the dialect.
+///
+/// The flag belongs to the parsing in progress, so it is a `Cell`: the dialect is not
+/// `Sync`, and threads checking at the same time each build their own.
+#[derive(Debug, Default)]
+pub struct MysqlBeyondDialect {
+    is_recalled: Cell<bool>,
+}
+
 pub enum AddupStatement {
     Delimiter,
     CreateFunction,
@@ -51,6 +1135,8 @@ pub enum AddupStatement {
     XA,
     Unknown,
     EndEarly,
+    ParseError,
+    Encoding,
 }
 
 impl From<String> for AddupStatement {
@@ -65,27 +1151,148 @@ impl From<String> for AddupStatement {
             "XA" => Self::XA,
             "Unknown" => Self::Unknown,
             "EndEarly" => Self::EndEarly,
+            "ParseError" => Self::ParseError,
+            "Encoding" => Self::Encoding,
             _ => unreachable!(),
         }
     }
 }
 
-impl ToString for AddupStatement {
-    fn to_string(&self) -> String {
-        match self {
-            AddupStatement::Delimiter => "Delimiter".to_string(),
-            AddupStatement::CreateFunction => "CreateFunction".to_string(),
-            AddupStatement::CreateProcedure => "CreateProcedure".to_string(),
-            AddupStatement::CreateTrigger => "CreateTrigger".to_string(),
-            AddupStatement::CreateEvent => "CreateEvent".to_string(),
-            AddupStatement::CreateFullText => "CreateFullText".to_string(),
-            AddupStatement::XA => "XA".to_string(),
-            AddupStatement::Unknown => "Unknown".to_string(),
-            AddupStatement::EndEarly => "EndEarly".to_string(),
+impl fmt::Display for AddupStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            AddupStatement::Delimiter => "Delimiter",
+            AddupStatement::CreateFunction => "CreateFunction",
+            AddupStatement::CreateProcedure => "CreateProcedure",
+            AddupStatement::CreateTrigger => "CreateTrigger",
+            AddupStatement::CreateEvent => "CreateEvent",
+            AddupStatement::CreateFullText => "CreateFullText",
+            AddupStatement::XA => "XA",
+            AddupStatement::Unknown => "Unknown",
+            AddupStatement::EndEarly => "EndEarly",
+            AddupStatement::ParseError => "ParseError",
+            AddupStatement::Encoding => "Encoding",
+        };
+        f.write_str(s)
+    }
+}
+
+impl AddupStatement {
+    /// Whether the statement is the one built by `addup_statement` with this type.
+    pub fn matches(&self, statement: &Statement) -> bool {
+        match statement {
+            Statement::Comment { object_name, .. } => {
+                object_name.0.len() == 1 && object_name.0[0].value == self.to_string()
+            }
+            _ => false,
         }
     }
 }
 
+/// Build a statement sqlparser cannot express, fallback to Comment(not really a Comment).
+pub fn addup_statement(typ: AddupStatement, comment: Option<String>) -> Statement {
+    Statement::Comment {
+        object_type: CommentObject::Table,
+        object_name: ObjectName(vec![Ident {
+            value: typ.to_string(),
+            quote_style: None,
+        }]),
+        comment,
+    }
+}
+
+/// Whether the statement is a routine, whose body could be split by `split_routine`.
+pub fn is_routine(statement: &Statement) -> bool {
+    let routines = [
+        AddupStatement::CreateProcedure,
+        AddupStatement::CreateFunction,
+        AddupStatement::CreateTrigger,
+        AddupStatement::CreateEvent,
+    ];
+    routines.iter().any(|r| r.matches(statement))
+}
+
+/// The kind of a statement, named after its variant in sqlparser such as `CreateTable`.
+///
+/// Statements sqlparser cannot express are named after their `AddupStatement`, and the
+/// legal ones the dialect skips are `Other`.
+pub fn statement_kind(statement: &Statement) -> String {
+    let addups = [
+        AddupStatement::Delimiter,
+        AddupStatement::CreateFunction,
+        AddupStatement::CreateProcedure,
+        AddupStatement::CreateTrigger,
+        AddupStatement::CreateEvent,
+        AddupStatement::CreateFullText,
+        AddupStatement::XA,
+        AddupStatement::Unknown,
+        AddupStatement::EndEarly,
+        AddupStatement::ParseError,
+        AddupStatement::Encoding,
+    ];
+    if let Some(addup) = addups.iter().find(|a| a.matches(statement)) {
+        return addup.to_string();
+    }
+    let kind = match statement {
+        Statement::ShowVariable { variable } if variable.is_empty() => "Other",
+        Statement::ShowVariable { .. } => "ShowVariable",
+        Statement::Comment { .. } => "Comment",
+        Statement::Analyze { .. } => "Analyze",
+        Statement::Truncate { .. } => "Truncate",
+        Statement::Msck { .. } => "Msck",
+        Statement::Query(..) => "Query",
+        Statement::Insert { .. } => "Insert",
+        Statement::Directory { .. } => "Directory",
+        Statement::Copy { .. } => "Copy",
+        Statement::Close { .. } => "Close",
+        Statement::Update { .. } => "Update",
+        Statement::Delete { .. } => "Delete",
+        Statement::CreateView { .. } => "CreateView",
+        Statement::CreateTable { .. } => "CreateTable",
+        Statement::CreateVirtualTable { .. } => "CreateVirtualTable",
+        Statement::CreateIndex { .. } => "CreateIndex",
+        Statement::CreateRole { .. } => "CreateRole",
+        Statement::AlterTable { .. } => "AlterTable",
+        Statement::Drop { .. } => "Drop",
+        Statement::Declare { .. } => "Declare",
+        Statement::Fetch { .. } => "Fetch",
+        Statement::Discard { .. } => "Discard",
+        Statement::SetRole { .. } => "SetRole",
+        Statement::SetVariable { .. } => "SetVariable",
+        Statement::SetNames { .. } => "SetNames",
+        Statement::SetNamesDefault { .. } => "SetNamesDefault",
+        Statement::ShowFunctions { .. } => "ShowFunctions",
+        Statement::ShowVariables { .. } => "ShowVariables",
+        Statement::ShowCreate { .. } => "ShowCreate",
+        Statement::ShowColumns { .. } => "ShowColumns",
+        Statement::ShowTables { .. } => "ShowTables",
+        Statement::ShowCollation { .. } => "ShowCollation",
+        Statement::Use { .. } => "Use",
+        Statement::StartTransaction { .. } => "StartTransaction",
+        Statement::SetTransaction { .. } => "SetTransaction",
+        Statement::Commit { .. } => "Commit",
+        Statement::Rollback { .. } => "Rollback",
+        Statement::CreateSchema { .. } => "CreateSchema",
+        Statement::CreateDatabase { .. } => "CreateDatabase",
+        Statement::CreateFunction { .. } => "CreateFunction",
+        Statement::Assert { .. } => "Assert",
+        Statement::Grant { .. } => "Grant",
+        Statement::Revoke { .. } => "Revoke",
+        Statement::Deallocate { .. } => "Deallocate",
+        Statement::Execute { .. } => "Execute",
+        Statement::Prepare { .. } => "Prepare",
+        Statement::Kill { .. } => "Kill",
+        Statement::ExplainTable { .. } => "ExplainTable",
+        Statement::Explain { .. } => "Explain",
+        Statement::Savepoint { .. } => "Savepoint",
+        Statement::Merge { .. } => "Merge",
+        Statement::Cache { .. } => "Cache",
+        Statement::UNCache { .. } => "UNCache",
+        Statement::CreateSequence { .. } => "CreateSequence",
+    };
+    kind.to_string()
+}
+
 impl MysqlBeyondDialect {
     fn parse_legal(&self, parser: &mut Parser) -> Option<Result<Statement, ParserError>> {
         loop {
@@ -105,41 +1312,24 @@ impl MysqlBeyondDialect {
         Some(Ok(Statement::ShowVariable { variable: vec![] }))
     }
 
+    /// The `DELIMITER` client command is split as a statement by itself.
     fn parse_delimiter_statement(
         &self,
         parser: &mut Parser,
     ) -> Option<Result<Statement, ParserError>> {
-        // TODO: warning cannot detect
-        let mut meet_delimiter = false;
-        loop {
-            let token = parser.next_token();
-            match token {
-                DEFAULT_DELIMITER => {
-                    if meet_delimiter {
-                        parser.prev_token();
-                        break;
-                    }
-                }
-                Token::EOF => {
-                    return Some(Err(ParserError::ParserError("meet EOF".to_owned())));
-                }
-                Token::Word(w) => {
-                    if w.keyword == Keyword::DELIMITER {
-                        meet_delimiter = true;
-                    }
-                }
-                _ => continue,
-            }
-        }
-        // fallback to Comment(not really a Comment)
-        Some(Ok(Statement::Comment {
-            object_type: CommentObject::Table,
-            object_name: ObjectName(vec![Ident {
-                value: AddupStatement::Delimiter.to_string(),
-                quote_style: None,
-            }]),
-            comment: None,
-        }))
+        parse_to_end(parser);
+        Some(Ok(addup_statement(AddupStatement::Delimiter, None)))
+    }
+
+    /// Routines are split as a statement by itself, with inner statements of body checked
+    /// later by `split_routine`.
+    fn parse_routine(
+        &self,
+        parser: &mut Parser,
+        typ: AddupStatement,
+    ) -> Option<Result<Statement, ParserError>> {
+        let sql = parse_to_end(parser);
+        Some(Ok(addup_statement(typ, Some(sql))))
     }
 
     fn parse_illegal(
@@ -163,38 +1353,98 @@ impl MysqlBeyondDialect {
                 _ => continue,
             }
         }
-        // fallback to Comment(not really a Comment)
-        Some(Ok(Statement::Comment {
-            object_type: CommentObject::Table,
-            object_name: ObjectName(vec![Ident {
-                value: typ.to_string(),
-                quote_style: None,
-            }]),
-            comment: Some(sql),
-        }))
+        Some(Ok(addup_statement(typ, Some(sql))))
+    }
+}
+
+fn parse_to_end(parser: &mut Parser) -> String {
+    let mut sql = "".to_string();
+    loop {
+        let token = parser.next_token();
+        if token == Token::EOF {
+            break;
+        }
+        sql += &token.to_string();
+        sql += " ";
+    }
+    sql
+}
+
+/// Match `CREATE [DEFINER = user] PROCEDURE/FUNCTION/TRIGGER/EVENT`.
+fn parse_create_routine(parser: &mut Parser) -> Option<AddupStatement> {
+    if !parser.parse_keyword(Keyword::CREATE) {
+        return None;
+    }
+    let mut consumed = 1;
+    if let Token::Word(w) = parser.peek_token() {
+        if w.quote_style.is_none() && w.value.eq_ignore_ascii_case("definer") {
+            // user could be `name`@`host`, 'name'@'host' or CURRENT_USER
+            while consumed < 8 {
+                match parser.peek_token() {
+                    Token::Word(w)
+                        if matches!(
+                            w.keyword,
+                            Keyword::PROCEDURE
+                                | Keyword::FUNCTION
+                                | Keyword::TRIGGER
+                                | Keyword::EVENT
+                        ) =>
+                    {
+                        break
+                    }
+                    Token::EOF => break,
+                    _ => {
+                        parser.next_token();
+                        consumed += 1;
+                    }
+                }
+            }
+        }
     }
+    consumed += 1;
+    let typ = match parser.next_token() {
+        Token::Word(w) => match w.keyword {
+            Keyword::PROCEDURE => Some(AddupStatement::CreateProcedure),
+            Keyword::FUNCTION => Some(AddupStatement::CreateFunction),
+            Keyword::TRIGGER => Some(AddupStatement::CreateTrigger),
+            Keyword::EVENT => Some(AddupStatement::CreateEvent),
+            _ => None,
+        },
+        _ => None,
+    };
+    if typ.is_none() {
+        for _ in 0..consumed {
+            parser.prev_token();
+        }
+    }
+    typ
 }
 
+/// Words are matched case-insensitively, as the input keeps its original casing.
 fn parse_tokens(parser: &mut Parser, tokens: &[Token]) -> bool {
-    for (i, wanted_token) in tokens.into_iter().enumerate() {
-        match wanted_token {
+    for (i, wanted_token) in tokens.iter().enumerate() {
+        let matched = match wanted_token {
             Token::Word(w) if w.keyword != Keyword::NoKeyword => {
                 if parser.parse_keyword(w.keyword) {
                     continue;
-                } else {
-                    return false;
                 }
+                false
             }
-            _ => {
-                let token = parser.next_token();
-                if token != *wanted_token {
-                    for _ in 0..i + 1 {
-                        parser.prev_token()
-                    }
-                    return false;
-                }
-                continue;
+            Token::Word(w) => match parser.next_token() {
+                Token::Word(t) => t.quote_style.is_none() && t.value.eq_ignore_ascii_case(&w.value),
+                _ => false,
+            },
+            _ => parser.next_token() == *wanted_token,
+        };
+        if !matched {
+            let consumed = match wanted_token {
+                Token::Word(w) if w.keyword != Keyword::NoKeyword => i,
+                _ => i + 1,
+            };
+            for _ in 0..consumed {
+                parser.prev_token()
             }
+            return false;
         }
     }
     true
@@ -220,36 +1470,29 @@ fn key_to_token(keyword: Keyword) -> Token {
 
 impl Dialect for MysqlBeyondDialect {
     fn parse_statement(&self, parser: &mut Parser) -> Option<Result<Statement, ParserError>> {
-        if self.is_recalled.load(SeqCst) {
+        if self.is_recalled.get() {
             return None;
         }
         if parser.parse_keyword(Keyword::DELIMITER) {
             return self.parse_delimiter_statement(parser);
-        } else if parser.parse_keywords(&[Keyword::CREATE, Keyword::PROCEDURE]) {
-            return self.parse_illegal(parser, AddupStatement::CreateProcedure);
-        } else if parser.parse_keywords(&[Keyword::CREATE, Keyword::FUNCTION]) {
-            return self.parse_illegal(parser, AddupStatement::CreateFunction);
-        } else if parser.parse_keywords(&[Keyword::CREATE, Keyword::TRIGGER]) {
-            return self.parse_illegal(parser, AddupStatement::CreateTrigger);
-        } else if parser.parse_keywords(&[Keyword::CREATE, Keyword::EVENT]) {
-            return self.parse_illegal(parser, AddupStatement::CreateEvent);
-        } else if parser.parse_keywords(&[Keyword::DROP, Keyword::DATABASE]) {
-            return self.parse_legal(parser);
-        } else if parse_tokens(
-            parser,
-            &[
-                str_to_token("lock".to_string()),
-                key_to_token(Keyword::TABLES),
-            ],
-        ) {
-            return self.parse_legal(parser);
-        } else if parse_tokens(
-            parser,
-            &[
-                str_to_token("unlock".to_string()),
-                key_to_token(Keyword::TABLES),
-            ],
-        ) {
+        } else if let Some(typ) = parse_create_routine(parser) {
+            return self.parse_routine(parser, typ);
+        } else if parser.parse_keywords(&[Keyword::DROP, Keyword::DATABASE])
+            || parse_tokens(
+                parser,
+                &[
+                    str_to_token("lock".to_string()),
+                    key_to_token(Keyword::TABLES),
+                ],
+            )
+            || parse_tokens(
+                parser,
+                &[
+                    str_to_token("unlock".to_string()),
+                    key_to_token(Keyword::TABLES),
+                ],
+            )
+        {
             return self.parse_legal(parser);
         } else if parse_tokens(
             parser,
@@ -263,22 +1506,21 @@ impl Dialect for MysqlBeyondDialect {
         } else if parse_tokens(parser, &[str_to_token("xa".to_string())]) {
             return self.parse_illegal(parser, AddupStatement::XA);
         }
-        self.is_recalled.store(true, SeqCst);
+        self.is_recalled.set(true);
         let original_ans = parser.parse_statement();
-        self.is_recalled.store(false, SeqCst);
+        self.is_recalled.set(false);
 
         match original_ans {
             Ok(s) => match parser.peek_token() {
                 Token::SemiColon => Some(Ok(s)),
-                _ => return self.parse_illegal(parser, AddupStatement::EndEarly),
+                _ => self.parse_illegal(parser, AddupStatement::EndEarly),
             },
             Err(_) => self.parse_illegal(parser, AddupStatement::Unknown),
         }
     }
 
     fn is_identifier_start(&self, ch: char) -> bool {
-        ('a'..='z').contains(&ch)
-            || ('A'..='Z').contains(&ch)
+        ch.is_ascii_alphabetic()
             || ch == '_'
             || ch == '$'
             || ch == '@'
@@ -290,6 +1532,55 @@ impl Dialect for MysqlBeyondDialect {
     }
 
     fn is_identifier_part(&self, ch: char) -> bool {
-        self.is_identifier_start(ch) || ('0'..='9').contains(&ch)
+        self.is_identifier_start(ch) || ch.is_ascii_digit()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io;
+
+    use super::{StatementReader, MAX_STATEMENT_SIZE};
+
+    /// Text, first line and whether `h6` is suppressed, of every statement read.
+    fn read(input: &str, max_size: usize) -> Vec<(String, u64, bool)> {
+        let reader = StatementReader {
+            max_size,
+            ..StatementReader::new(input.as_bytes())
+        };
+        let mut statements = vec![];
+        reader
+            .for_each(|raw| -> Result<(), io::Error> {
+                let text = raw.text.to_string();
+                statements.push((text, raw.span.start.line, raw.suppression.covers("h6")));
+                Ok(())
+            })
+            .unwrap();
+        statements
+    }
+
+    #[test]
+    fn test_read_across_lines() {
+        let input = "/* tikey:disable\nh6 */ SAVEPOINT a;\nSELECT 'b;\nc;' FROM t1; SAVEPOINT d;
+/* tikey:enable */\nSAVEPOINT e";
+        let expect = vec![
+            ("SAVEPOINT a".to_string(), 2, true),
+            ("SELECT 'b;\nc;' FROM t1".to_string(), 3, true),
+            ("SAVEPOINT d".to_string(), 4, true),
+            ("SAVEPOINT e".to_string(), 6, false),
+        ];
+        assert_eq!(read(input, MAX_STATEMENT_SIZE), expect);
+    }
+
+    #[test]
+    fn test_read_oversize_statement() {
+        // the quote is never closed, the statement is cut rather than holding the rest
+        let input = "SELECT 'a;\nb;\nSAVEPOINT c;\n";
+        let expect = vec![
+            ("SELECT 'a;\n".to_string(), 1, false),
+            ("b".to_string(), 2, false),
+            ("SAVEPOINT c".to_string(), 3, false),
+        ];
+        assert_eq!(read(input, 8), expect);
     }
 }
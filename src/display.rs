@@ -1,9 +1,31 @@
+use std::collections::BTreeMap;
+use std::fmt;
 use std::path::PathBuf;
 
 use comfy_table::{ContentArrangement, Table};
 
-use crate::{rules::RuleInfo, OnceInfo, Summary};
+use crate::{
+    baseline::BaselineEntry,
+    count_info,
+    rules::{InfoLevel, RuleFuture, RuleInfo},
+    Effort, FileFailure, Location, OnceInfo, Span, Summary, UpgradeDelta,
+};
 
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} - {}", self.start, self.end)
+    }
+}
+
+/// Format the totals and the statements without findings, clean or hidden by suppressions
+/// and the baseline, followed by the findings counted by rule, rule future, statement kind
+/// and file, and the estimated effort by schema and file, if there is any finding.
 pub fn format_summary(sum: Summary) -> String {
     let mut table = Table::new();
     table
@@ -14,6 +36,7 @@ pub fn format_summary(sum: Summary) -> String {
             "SQL Count",
             "Errors",
             "Warnings",
+            "Suppressed",
             "Time Cost",
         ])
         .add_row(vec![
@@ -21,27 +44,190 @@ pub fn format_summary(sum: Summary) -> String {
             sum.sql_count().to_string(),
             sum.errors().to_string(),
             sum.warnings().to_string(),
+            sum.suppressed().to_string(),
             format!("{:?}", sum.time_cost()),
         ]);
+    let mut clean = Table::new();
+    clean
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+        .set_width(70)
+        .set_header(vec![
+            "Clean Statements",
+            "Clean Percent",
+            "Hidden Statements",
+            "Bulk Inserts",
+        ])
+        .add_row(vec![
+            sum.clean().to_string(),
+            format!("{:.2}%", sum.clean_percent()),
+            sum.hidden().to_string(),
+            sum.bulk_inserts().to_string(),
+        ]);
+    let mut tables = vec![table.to_string(), clean.to_string()];
+    let counts = [
+        ("Rule", to_rows(sum.by_rule(), |uid| uid.clone())),
+        (
+            "Future",
+            to_rows(sum.by_future(), |future| future.to_string()),
+        ),
+        (
+            "Statement Kind",
+            to_rows(sum.by_kind(), |kind| kind.clone()),
+        ),
+        (
+            "File",
+            to_rows(sum.by_file(), |file| file.to_string_lossy().to_string()),
+        ),
+    ];
+    for (header, rows) in counts {
+        if rows.is_empty() {
+            continue;
+        }
+        let mut table = Table::new();
+        table
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_width(70)
+            .set_header(vec![header, "Findings"])
+            .add_rows(rows);
+        tables.push(table.to_string());
+    }
+    if *sum.effort().total() == 0 {
+        return tables.join("\n");
+    }
+    // the overall effort closes the table by schema, shown even if no schema is known
+    let mut by_schema = effort_rows(sum.effort_by_schema(), |schema| schema.clone());
+    by_schema.push(effort_row("All".to_string(), sum.effort()));
+    let efforts = [
+        ("Schema", by_schema),
+        (
+            "File",
+            effort_rows(sum.effort_by_file(), |file| {
+                file.to_string_lossy().to_string()
+            }),
+        ),
+    ];
+    for (header, rows) in efforts {
+        if rows.is_empty() {
+            continue;
+        }
+        let mut table = Table::new();
+        table
+            .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+            .set_width(70)
+            .set_header(vec![header, "Will Support", "No Plan", "Effort"])
+            .add_rows(rows);
+        tables.push(table.to_string());
+    }
+    tables.join("\n")
+}
+
+/// Format the totals as a single line closing compact or annotated output, like
+/// `2 errors, 1 warning, 0 suppressed in 5 statements of 1 file`.
+pub fn format_totals(sum: &Summary) -> String {
+    let count = |n: &u128, one: &str, many: &str| match n {
+        1 => format!("1 {}", one),
+        n => format!("{} {}", n, many),
+    };
+    format!(
+        "{}, {}, {} suppressed in {} of {}",
+        count(sum.errors(), "error", "errors"),
+        count(sum.warnings(), "warning", "warnings"),
+        sum.suppressed(),
+        count(sum.sql_count(), "statement", "statements"),
+        count(sum.file_count(), "file", "files"),
+    )
+}
+
+fn to_rows<K>(counts: &BTreeMap<K, u128>, name: fn(&K) -> String) -> Vec<Vec<String>> {
+    counts
+        .iter()
+        .map(|(key, count)| vec![name(key), count.to_string()])
+        .collect()
+}
+
+fn effort_rows<K>(efforts: &BTreeMap<K, Effort>, name: fn(&K) -> String) -> Vec<Vec<String>> {
+    efforts
+        .iter()
+        .map(|(key, effort)| effort_row(name(key), effort))
+        .collect()
+}
+
+fn effort_row(name: String, effort: &Effort) -> Vec<String> {
+    vec![
+        name,
+        effort.of_future(&RuleFuture::WillSupport).to_string(),
+        effort.of_future(&RuleFuture::NoPlan).to_string(),
+        effort.total().to_string(),
+    ]
+}
+
+pub fn format_delta(delta: &UpgradeDelta) -> String {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+        .set_width(70)
+        .set_header(vec![
+            format!("{} -> {}", delta.from(), delta.to()),
+            "Errors".to_string(),
+            "Warnings".to_string(),
+            "Rules".to_string(),
+        ]);
+    let parts = [
+        ("Resolved", delta.resolved()),
+        ("Remaining", delta.remaining()),
+        ("New", delta.introduced()),
+    ];
+    for (name, all_info) in parts {
+        let (errors, warnings) = count_info(all_info);
+        table.add_row(vec![
+            name.to_string(),
+            errors.to_string(),
+            warnings.to_string(),
+            format_rule_counts(all_info),
+        ]);
+    }
     table.to_string()
 }
 
+/// Count of findings for each rule, like `h6 (1), m1 (2)`.
+fn format_rule_counts(all_info: &[OnceInfo]) -> String {
+    let mut counts: BTreeMap<&String, usize> = BTreeMap::new();
+    for rec in all_info.iter().flat_map(|info| &info.records) {
+        *counts.entry(rec.uid()).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .map(|(uid, count)| format!("{} ({})", uid, count))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
 pub fn format_records(info: OnceInfo) -> Vec<String> {
     info.records
         .into_iter()
-        .map(|rec| format_record(info.sql.clone(), rec, info.file.clone()))
+        .map(|rec| {
+            format_record(
+                info.sql.clone(),
+                rec,
+                info.file.clone(),
+                info.span,
+                info.routine.clone(),
+            )
+        })
         .collect()
 }
 
-fn format_record(sql: String, rec: RuleInfo, file: Option<PathBuf>) -> String {
+fn format_record(
+    sql: String,
+    rec: RuleInfo,
+    file: Option<PathBuf>,
+    span: Span,
+    routine: Option<String>,
+) -> String {
     let mut table = Table::new();
 
     let (db_version_range_from, db_version_range_to) = &rec.db_version_range();
-    let db_version_description = format!(
-        "{} - {}",
-        db_version_range_from.to_string(),
-        db_version_range_to.to_string()
-    );
+    let db_version_description = format!("{} - {}", db_version_range_from, db_version_range_to);
 
     let url = rec.url().clone().unwrap_or("".to_string());
     let file = file
@@ -57,45 +243,316 @@ fn format_record(sql: String, rec: RuleInfo, file: Option<PathBuf>) -> String {
         .add_row(vec!["Description", &rec.description().to_string()])
         .add_row(vec!["URL", &url])
         .add_row(vec!["SQL", &sql])
-        .add_row(vec!["File", &file]);
+        .add_row(vec!["File", &file])
+        .add_row(vec!["Location", &span.to_string()]);
+    if let Some(routine) = routine {
+        table.add_row(vec!["Routine", &routine]);
+    }
+    table.to_string()
+}
+
+/// Format each record as a single line, like `file:line:col: error[m4]: description`.
+pub fn format_compact(info: OnceInfo) -> Vec<String> {
+    let file = display_file(&info.file);
+    info.records
+        .into_iter()
+        .map(|rec| {
+            format!(
+                "{}:{}: {}[{}]: {}",
+                file,
+                info.span.start,
+                rec.info_level(),
+                rec.uid(),
+                rec.description()
+            )
+        })
+        .collect()
+}
+
+/// Lines of a statement shown at most in an annotated record, the middle ones are elided.
+const ANNOTATED_LINES: usize = 6;
+
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+const RESET: &str = "\x1b[0m";
+
+/// Format each record like a compiler diagnostic, with the statement marked below.
+///
+/// The statement is printed from its original text at its location, a statement of a single
+/// line is underlined by carets while a longer one is bracketed in the gutter. The whole
+/// statement is marked, as the rules judge statements parsed without the location of
+/// their parts, so the token at fault is not known.
+pub fn format_annotated(info: OnceInfo, color: bool) -> Vec<String> {
+    let paint = |style: &str, text: &str| {
+        if color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    };
+    let file = display_file(&info.file);
+    let span = info.span;
+    let start_line = span.start.line as usize;
+    // the first line starts at the column of the statement, the chars before are blanked
+    let padding = " ".repeat(span.start.column as usize - 1);
+    let lines: Vec<String> = info
+        .sql
+        .lines()
+        .enumerate()
+        .map(|(i, l)| match i {
+            0 => format!("{}{}", padding, l),
+            _ => l.to_string(),
+        })
+        // a tab is kept as one column for the marks to line up
+        .map(|l| l.replace('\t', " "))
+        .collect();
+    let last_line = start_line + lines.len().max(1) - 1;
+    let width = last_line.to_string().len();
+    let gutter = |number: Option<usize>| {
+        let number = number.map(|n| n.to_string()).unwrap_or_default();
+        paint(BLUE, &format!("{:>width$} |", number, width = width))
+    };
+
+    let mut excerpt: Vec<String> = vec![gutter(None)];
+    if lines.len() <= 1 {
+        let line = lines.first().cloned().unwrap_or_default();
+        let from = span.start.column as usize - 1;
+        let to = (span.end.column as usize).max(from + 1);
+        excerpt.push(format!("{} {}", gutter(Some(start_line)), line));
+        excerpt.push(format!(
+            "{} {}{}",
+            gutter(None),
+            " ".repeat(from),
+            paint(BOLD, &"^".repeat(to - from))
+        ));
+    } else {
+        for (i, line) in lines.iter().enumerate() {
+            let elided = lines.len() > ANNOTATED_LINES
+                && i >= ANNOTATED_LINES / 2
+                && i < lines.len() - (ANNOTATED_LINES - ANNOTATED_LINES / 2 - 1);
+            if elided {
+                if i == ANNOTATED_LINES / 2 {
+                    excerpt.push(paint(BLUE, "..."));
+                }
+                continue;
+            }
+            let bracket = if i == 0 { "/" } else { "|" };
+            excerpt.push(format!(
+                "{} {} {}",
+                gutter(Some(start_line + i)),
+                paint(BOLD, bracket),
+                line
+            ));
+        }
+        let underline = format!("|{}^", "_".repeat(span.end.column as usize));
+        excerpt.push(format!("{} {}", gutter(None), paint(BOLD, &underline)));
+    }
+
+    info.records
+        .into_iter()
+        .map(|rec| {
+            let level_style = match rec.info_level() {
+                InfoLevel::ERROR => RED,
+                InfoLevel::WARNING => YELLOW,
+            };
+            let mut out = vec![
+                format!(
+                    "{}{}",
+                    paint(level_style, &format!("{}[{}]", rec.info_level(), rec.uid())),
+                    paint(BOLD, &format!(": {}", rec.description()))
+                ),
+                format!(
+                    "{:>width$}{} {}:{}",
+                    "",
+                    paint(BLUE, "-->"),
+                    file,
+                    span.start,
+                    width = width
+                ),
+            ];
+            out.extend(excerpt.iter().cloned());
+            let mut notes = vec![];
+            if let Some(routine) = &info.routine {
+                notes.push(format!("note: in {}", routine));
+            }
+            if let Some(url) = rec.url() {
+                notes.push(format!("help: see {}", url));
+            }
+            if !notes.is_empty() {
+                out.push(gutter(None));
+            }
+            for note in notes {
+                out.push(format!(
+                    "{:>width$} {} {}",
+                    "",
+                    paint(BLUE, "="),
+                    note,
+                    width = width
+                ));
+            }
+            out.join("\n") + "\n"
+        })
+        .collect()
+}
+
+pub fn format_fixed(entry: &BaselineEntry) -> String {
+    let mut table = Table::new();
+    table
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+        .set_width(70)
+        .add_row(vec!["Fixed code", entry.uid()])
+        .add_row(vec!["Count", &entry.count().to_string()])
+        .add_row(vec!["SQL", entry.sql()])
+        .add_row(vec!["File", entry.file().as_deref().unwrap_or("")]);
+    table.to_string()
+}
+
+/// Format the fixed finding of the baseline as a single line, like `file: fixed[m4]: sql`.
+pub fn format_fixed_compact(entry: &BaselineEntry) -> String {
+    let times = match entry.count() {
+        1 => String::new(),
+        n => format!(" ({} times)", n),
+    };
+    format!(
+        "{}: fixed[{}]{}: {}",
+        entry.file().as_deref().unwrap_or("<statement>"),
+        entry.uid(),
+        times,
+        entry.sql()
+    )
+}
+
+/// Format the failure as a single line, like `file: failed to check: reason`.
+pub fn format_failure_compact(failure: FileFailure) -> String {
+    format!(
+        "{}: failed to check: {}",
+        failure.file.to_string_lossy(),
+        failure.reason
+    )
+}
+
+fn display_file(file: &Option<PathBuf>) -> String {
+    match file {
+        Some(p) => p.to_string_lossy().to_string(),
+        None => "<statement>".to_string(),
+    }
+}
+
+pub fn format_failure(failure: FileFailure) -> String {
+    let mut table = Table::new();
+    let file = failure.file.to_string_lossy().to_string();
+    table
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+        .set_width(70)
+        .add_row(vec!["Failed file", &file])
+        .add_row(vec!["Reason", failure.reason()]);
     table.to_string()
 }
 
 #[cfg(test)]
 mod test {
-    use std::time::Duration;
+    use std::{
+        path::{PathBuf, MAIN_SEPARATOR},
+        time::Duration,
+    };
 
     use crate::{
-        format_records, format_summary,
-        rules::{DBVersion, InfoLevel, RuleFuture, RuleInfo},
-        OnceInfo, Summary,
+        format_annotated, format_compact, format_records, format_summary, format_totals,
+        rules::{DBVersion, InfoLevel, RuleFuture, RuleInfo, Version},
+        Location, OnceInfo, Span, Summary,
     };
 
     #[test]
     fn test_format_summary() {
         let summary = Summary {
             file_count: 1,
-            sql_count: 2,
+            sql_count: 3,
             errors: 3,
             warnings: 4,
+            suppressed: 5,
+            clean: 1,
+            hidden: 1,
+            clean_percent: 33.33,
             time_cost: Duration::from_millis(114514),
+            ..Summary::default()
         };
         let table = format_summary(summary);
         let expect = "\
-        +------------+-----------+--------+----------+-----------+\n\
-        | File Count | SQL Count | Errors | Warnings | Time Cost |\n\
-        +========================================================+\n\
-        | 1          | 2         | 3      | 4        | 114.514s  |\n\
-        +------------+-----------+--------+----------+-----------+";
+        +--------+------------+---------+-----------+------------+-----------+\n\
+        | File   | SQL Count  | Errors  | Warnings  | Suppressed | Time Cost |\n\
+        | Count  |            |         |           |            |           |\n\
+        +====================================================================+\n\
+        | 1      | 3          | 3       | 4         | 5          | 114.514s  |\n\
+        +--------+------------+---------+-----------+------------+-----------+\n\
+        +--------------------+-----------------+-------------+---------------+\n\
+        | Clean Statements   | Clean Percent   | Hidden      | Bulk Inserts  |\n\
+        |                    |                 | Statements  |               |\n\
+        +====================================================================+\n\
+        | 1                  | 33.33%          | 1           | 0             |\n\
+        +--------------------+-----------------+-------------+---------------+";
         assert_eq!(table, expect);
     }
 
+    #[test]
+    fn test_format_summary_counts() {
+        let summary = Summary {
+            file_count: 1,
+            sql_count: 4,
+            errors: 3,
+            ..Summary::default()
+        };
+        let all_info = vec![
+            savepoint_info("SAVEPOINT a;", (1, 1), (1, 12)),
+            savepoint_info("SAVEPOINT b;", (2, 1), (2, 12)),
+        ];
+        let summary = summary.recount(&all_info);
+        assert_eq!(*summary.clean(), 2);
+        assert_eq!(*summary.clean_percent(), 50.0);
+        let file: PathBuf = ["sql", "a.sql"].iter().collect();
+        assert_eq!(summary.by_file().get(&file), Some(&2));
+        let tables = format_summary(summary);
+        let (_, counts) = tables.split_once("| Rule").unwrap();
+        // up to the top border of the table by file
+        let (counts, _) = counts.split_once("| File").unwrap();
+        let (counts, _) = counts.trim_end().rsplit_once('\n').unwrap();
+        let expect = "                           | Findings                          |\n\
+        +====================================================================+\n\
+        | h6                             | 2                                 |\n\
+        +--------------------------------+-----------------------------------+\n\
+        +-----------------------------------------+--------------------------+\n\
+        | Future                                  | Findings                 |\n\
+        +====================================================================+\n\
+        | have a plan to support                  | 2                        |\n\
+        +-----------------------------------------+--------------------------+\n\
+        +-------------------------------------+------------------------------+\n\
+        | Statement Kind                      | Findings                     |\n\
+        +====================================================================+\n\
+        | Savepoint                           | 2                            |\n\
+        +-------------------------------------+------------------------------+";
+        assert_eq!(counts, expect);
+        let (_, efforts) = tables.split_once("| Schema").unwrap();
+        let (efforts, _) = efforts.split_once("| File").unwrap();
+        let (efforts, _) = efforts.trim_end().rsplit_once('\n').unwrap();
+        let expect = "        | Will Support        | No Plan       | Effort       |\n\
+        +====================================================================+\n\
+        | shop          | 4                   | 0             | 4            |\n\
+        |---------------+---------------------+---------------+--------------|\n\
+        | All           | 4                   | 0             | 4            |\n\
+        +---------------+---------------------+---------------+--------------+";
+        assert_eq!(efforts, expect);
+    }
+
     #[test]
     fn test_format_rule_info() {
         let sql = "select mysql from TiDB".to_string();
         let uid = "P5".to_string();
         let info_level = InfoLevel::ERROR;
-        let db_version_range = (DBVersion::Earliest, DBVersion::Version("1.0.0".to_string()));
+        let db_version_range = (
+            DBVersion::Earliest,
+            DBVersion::Version(Version::new(1, 0, 0)),
+        );
         let future = RuleFuture::NoPlan;
         let description = "oh, yeah!".to_string();
         let url = None;
@@ -106,29 +563,131 @@ mod test {
             sql,
             records: vec![rec],
             file: Some([r"Shimo", "kitazawa", "Koji", "Tadokoro"].iter().collect()),
+            span: Span {
+                start: Location {
+                    line: 8,
+                    column: 10,
+                },
+                end: Location {
+                    line: 11,
+                    column: 4,
+                },
+            },
+            routine: None,
+            kind: "Query".to_string(),
+            schema: None,
         };
         let mut tables = format_records(info);
         assert_eq!(tables.len(), 1);
         let table = tables.pop().unwrap();
 
         let expect = "\
-        +--------------+------------------------------+\n\
-        | Error code   | P5                           |\n\
-        |--------------+------------------------------|\n\
-        | Level        | error                        |\n\
-        |--------------+------------------------------|\n\
-        | TiDB version | earliest - 1.0.0             |\n\
-        |--------------+------------------------------|\n\
-        | Future plan  | no plan to support           |\n\
-        |--------------+------------------------------|\n\
-        | Description  | oh, yeah!                    |\n\
-        |--------------+------------------------------|\n\
-        | URL          |                              |\n\
-        |--------------+------------------------------|\n\
-        | SQL          | select mysql from TiDB       |\n\
-        |--------------+------------------------------|\n\
-        | File         | Shimo\\kitazawa\\Koji\\Tadokoro |\n\
-        +--------------+------------------------------+";
+        +--------------------------+-----------------------------------------+\n\
+        | Error code               | P5                                      |\n\
+        |--------------------------+-----------------------------------------|\n\
+        | Level                    | error                                   |\n\
+        |--------------------------+-----------------------------------------|\n\
+        | TiDB version             | earliest - 1.0.0                        |\n\
+        |--------------------------+-----------------------------------------|\n\
+        | Future plan              | no plan to support                      |\n\
+        |--------------------------+-----------------------------------------|\n\
+        | Description              | oh, yeah!                               |\n\
+        |--------------------------+-----------------------------------------|\n\
+        | URL                      |                                         |\n\
+        |--------------------------+-----------------------------------------|\n\
+        | SQL                      | select mysql from TiDB                  |\n\
+        |--------------------------+-----------------------------------------|\n\
+        | File                     | Shimo/kitazawa/Koji/Tadokoro            |\n\
+        |--------------------------+-----------------------------------------|\n\
+        | Location                 | 8:10 - 11:4                             |\n\
+        +--------------------------+-----------------------------------------+"
+            .replace(
+                "Shimo/kitazawa/Koji/Tadokoro",
+                &format!("Shimo{0}kitazawa{0}Koji{0}Tadokoro", MAIN_SEPARATOR),
+            );
         assert_eq!(table, expect);
     }
+
+    fn savepoint_info(sql: &str, start: (u64, u64), end: (u64, u64)) -> OnceInfo {
+        let rec = RuleInfo::new(
+            "h6".to_string(),
+            InfoLevel::ERROR,
+            (DBVersion::Earliest, DBVersion::Latest),
+            RuleFuture::WillSupport,
+            "TiDB not supported savepoint".to_string(),
+            Some("https://github.com/pingcap/tidb/issues/6840".to_string()),
+        )
+        .with_effort(2);
+        OnceInfo {
+            sql: sql.to_string(),
+            records: vec![rec],
+            file: Some(["sql", "a.sql"].iter().collect()),
+            span: Span {
+                start: Location {
+                    line: start.0,
+                    column: start.1,
+                },
+                end: Location {
+                    line: end.0,
+                    column: end.1,
+                },
+            },
+            routine: Some("PROCEDURE p".to_string()),
+            kind: "Savepoint".to_string(),
+            schema: Some("shop".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_format_totals() {
+        let summary = Summary {
+            file_count: 1,
+            sql_count: 5,
+            errors: 2,
+            warnings: 1,
+            ..Summary::default()
+        };
+        assert_eq!(
+            format_totals(&summary),
+            "2 errors, 1 warning, 0 suppressed in 5 statements of 1 file"
+        );
+    }
+
+    #[test]
+    fn test_format_compact() {
+        let info = savepoint_info("SAVEPOINT s1", (3, 5), (3, 16));
+        let lines = format_compact(info);
+        let expect = format!(
+            "sql{}a.sql:3:5: error[h6]: TiDB not supported savepoint",
+            MAIN_SEPARATOR
+        );
+        assert_eq!(lines, vec![expect]);
+    }
+
+    #[test]
+    fn test_format_annotated() {
+        let info = savepoint_info("SAVEPOINT s1", (3, 5), (3, 16));
+        let mut lines = format_annotated(info, false);
+        assert_eq!(lines.len(), 1);
+        let expect = "\
+        error[h6]: TiDB not supported savepoint\n \
+        --> sql/a.sql:3:5\n  \
+          |\n\
+        3 |     SAVEPOINT s1\n  \
+          |     ^^^^^^^^^^^^\n  \
+          |\n  \
+          = note: in PROCEDURE p\n  \
+          = help: see https://github.com/pingcap/tidb/issues/6840\n"
+            .replace("sql/a.sql", &format!("sql{}a.sql", MAIN_SEPARATOR));
+        assert_eq!(lines.pop().unwrap(), expect);
+
+        let info = savepoint_info("CREATE TABLE t1 (\n\ta INT\n)", (9, 1), (11, 1));
+        let lines = format_annotated(info, false);
+        let expect = " \
+         9 | / CREATE TABLE t1 (\n\
+        10 | |  a INT\n\
+        11 | | )\n   \
+           | |_^\n";
+        assert!(lines[0].contains(expect), "{}", lines[0]);
+    }
 }
@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+use std::ops::Add;
+
+use serde::Serialize;
+
+use crate::rules::{RuleFuture, RuleInfo};
+
+/// Estimated effort to migrate some findings, the sum of the effort weights of their rules.
+///
+/// The effort is split by rule future, so that findings TiDB will support later can be
+/// tracked apart from the ones to rewrite.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Effort {
+    total: u128,
+    by_future: BTreeMap<RuleFuture, u128>,
+}
+
+impl Effort {
+    pub fn total(&self) -> &u128 {
+        &self.total
+    }
+
+    pub fn by_future(&self) -> &BTreeMap<RuleFuture, u128> {
+        &self.by_future
+    }
+
+    /// The effort of the rule future, zero if there is no finding of it.
+    pub fn of_future(&self, future: &RuleFuture) -> u128 {
+        self.by_future.get(future).copied().unwrap_or_default()
+    }
+
+    pub(crate) fn count(&mut self, rec: &RuleInfo) {
+        let effort = *rec.effort() as u128;
+        self.total += effort;
+        *self.by_future.entry(rec.future().clone()).or_default() += effort;
+    }
+}
+
+impl Add for Effort {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self.total += other.total;
+        for (future, effort) in other.by_future {
+            *self.by_future.entry(future).or_default() += effort;
+        }
+        self
+    }
+}
@@ -0,0 +1,279 @@
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::mem;
+use std::str;
+
+use chardetng::EncodingDetector;
+use encoding_rs::{
+    Encoding, BIG5, EUC_JP, EUC_KR, GB18030, GBK, IBM866, ISO_8859_13, ISO_8859_2, ISO_8859_7,
+    ISO_8859_8, KOI8_R, KOI8_U, MACINTOSH, SHIFT_JIS, UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1250,
+    WINDOWS_1251, WINDOWS_1252, WINDOWS_1254, WINDOWS_1256, WINDOWS_1257, WINDOWS_874,
+};
+use encoding_rs_io::{DecodeReaderBytes, DecodeReaderBytesBuilder};
+
+/// Count of bytes at the start of an input read ahead to detect its encoding.
+const HEAD_SIZE: usize = 64 * 1024;
+
+/// Statements declaring the charset of the statements after them, as written by `mysqldump`.
+const SET_CHARSET_KEYWORDS: [&str; 3] = ["set names", "set character set", "set charset"];
+
+/// How the encoding of an input is decided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodingSource {
+    /// given by the caller, whatever the input
+    Given,
+    /// the byte order mark at the start of the input
+    Bom,
+    /// a `SET NAMES` or `SET CHARACTER SET` statement near the start of an input that is
+    /// not valid UTF-8, or only ASCII so far
+    SetNames,
+    /// guessed from the bytes near the start of the input
+    Guess,
+}
+
+impl fmt::Display for EncodingSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            EncodingSource::Given => "given",
+            EncodingSource::Bom => "detected by byte order mark",
+            EncodingSource::SetNames => "detected by SET NAMES",
+            EncodingSource::Guess => "guessed from content",
+        };
+        f.write_str(s)
+    }
+}
+
+/// The encoding an input is decoded from before checking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DetectedEncoding {
+    encoding: &'static Encoding,
+    source: EncodingSource,
+}
+
+impl DetectedEncoding {
+    pub fn encoding(&self) -> &'static Encoding {
+        self.encoding
+    }
+
+    pub fn source(&self) -> &EncodingSource {
+        &self.source
+    }
+
+    /// Whether the input is in UTF-8 already, with or without a byte order mark.
+    pub fn is_utf8(&self) -> bool {
+        self.encoding == UTF_8
+    }
+}
+
+impl fmt::Display for DetectedEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "encoding {} {}", self.encoding.name(), self.source)
+    }
+}
+
+/// The encoding of a MySQL charset name like `gbk`, or of a WHATWG label like `shift_jis`.
+pub fn encoding_for_label(label: &str) -> Option<&'static Encoding> {
+    let label = label.trim().to_ascii_lowercase();
+    mysql_charset(&label).or_else(|| Encoding::for_label(label.as_bytes()))
+}
+
+/// The encoding of a MySQL charset, `None` for `binary` and charsets without an encoding.
+fn mysql_charset(name: &str) -> Option<&'static Encoding> {
+    let encoding = match name {
+        "utf8" | "utf8mb3" | "utf8mb4" => UTF_8,
+        // latin1 of MySQL is cp1252, and ascii is a part of it
+        "ascii" | "latin1" => WINDOWS_1252,
+        "latin2" => ISO_8859_2,
+        "latin5" => WINDOWS_1254,
+        "latin7" => ISO_8859_13,
+        "greek" => ISO_8859_7,
+        "hebrew" => ISO_8859_8,
+        "cp1250" => WINDOWS_1250,
+        "cp1251" => WINDOWS_1251,
+        "cp1256" => WINDOWS_1256,
+        "cp1257" => WINDOWS_1257,
+        "cp866" => IBM866,
+        "koi8r" => KOI8_R,
+        "koi8u" => KOI8_U,
+        "macroman" => MACINTOSH,
+        "tis620" => WINDOWS_874,
+        "gb2312" | "gbk" => GBK,
+        "gb18030" => GB18030,
+        "big5" => BIG5,
+        "sjis" | "cp932" => SHIFT_JIS,
+        "ujis" | "eucjpms" => EUC_JP,
+        "euckr" => EUC_KR,
+        "ucs2" | "utf16" => UTF_16BE,
+        "utf16le" => UTF_16LE,
+        _ => return None,
+    };
+    Some(encoding)
+}
+
+/// Detect the encoding from the head of an input, `at_end` if nothing follows the head.
+///
+/// A byte order mark comes first, then a head of valid UTF-8 is UTF-8 whatever it declares,
+/// as a `SET NAMES` only tells how the server should read the statements. Otherwise the
+/// first `SET NAMES` of a known charset is trusted, and any other head is guessed by its bytes.
+///
+/// A head of ASCII only tells nothing of the bytes after it, like the long header of a
+/// `mysqldump` output, so its `SET NAMES` is trusted unless nothing follows.
+pub fn detect_encoding(head: &[u8], at_end: bool) -> DetectedEncoding {
+    let detected = |encoding, source| DetectedEncoding { encoding, source };
+    if let Some((encoding, _)) = Encoding::for_bom(head) {
+        return detected(encoding, EncodingSource::Bom);
+    }
+    if head.is_ascii() && !at_end {
+        if let Some(encoding) = set_names(head) {
+            return detected(encoding, EncodingSource::SetNames);
+        }
+    }
+    match str::from_utf8(head) {
+        Ok(_) => return detected(UTF_8, EncodingSource::Guess),
+        // a char cut at the end of the head
+        Err(e) if e.error_len().is_none() && !at_end => {
+            return detected(UTF_8, EncodingSource::Guess)
+        }
+        Err(_) => {}
+    }
+    if let Some(encoding) = set_names(head) {
+        return detected(encoding, EncodingSource::SetNames);
+    }
+    let mut detector = EncodingDetector::new();
+    detector.feed(head, at_end);
+    detected(detector.guess(None, false), EncodingSource::Guess)
+}
+
+/// The encoding of the first charset statement of a known charset in the head.
+fn set_names(head: &[u8]) -> Option<&'static Encoding> {
+    let lower = head.to_ascii_lowercase();
+    let mut found: Vec<(usize, &'static Encoding)> = vec![];
+    for keyword in SET_CHARSET_KEYWORDS {
+        let keyword = keyword.as_bytes();
+        let mut from = 0;
+        while let Some(at) = find(&lower[from..], keyword).map(|at| at + from) {
+            from = at + keyword.len();
+            // a keyword ending another word, like `offset names`
+            if at > 0 && is_name_byte(lower[at - 1]) {
+                continue;
+            }
+            if let Some(encoding) = charset_name(&lower[from..]).and_then(mysql_charset) {
+                found.push((at, encoding));
+                break;
+            }
+        }
+    }
+    found.into_iter().min_by_key(|(at, _)| *at).map(|(_, e)| e)
+}
+
+/// The charset name at the start of the bytes, after spaces and maybe in quotes.
+fn charset_name(bytes: &[u8]) -> Option<&str> {
+    let start = bytes.iter().position(|b| !b.is_ascii_whitespace())?;
+    if start == 0 {
+        return None;
+    }
+    let mut name = &bytes[start..];
+    if let Some(b'\'' | b'"' | b'`') = name.first() {
+        name = &name[1..];
+    }
+    let end = name.iter().position(|b| !is_name_byte(*b))?;
+    str::from_utf8(&name[..end]).ok().filter(|n| !n.is_empty())
+}
+
+fn is_name_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Detect the encoding of the input, unless it is given, and decode the input into UTF-8.
+///
+/// Bytes that are invalid in the encoding are replaced by `U+FFFD` rather than failing,
+/// but for an input guessed to be UTF-8 by its head: an invalid sequence after the head
+/// tells the guess is wrong, and fails the read with `InvalidData`.
+pub fn decode_reader<R: Read>(
+    mut reader: R,
+    encoding: Option<&'static Encoding>,
+) -> io::Result<(DetectedEncoding, impl BufRead)> {
+    let mut head: Vec<u8> = Vec::with_capacity(HEAD_SIZE);
+    reader
+        .by_ref()
+        .take(HEAD_SIZE as u64)
+        .read_to_end(&mut head)?;
+    let detected = match encoding {
+        Some(encoding) => DetectedEncoding {
+            encoding,
+            source: EncodingSource::Given,
+        },
+        None => detect_encoding(&head, head.len() < HEAD_SIZE),
+    };
+    let reader = Cursor::new(head).chain(reader);
+    let decoded = match detected.source {
+        EncodingSource::Guess if detected.is_utf8() => Decoded::Utf8(CheckUtf8 {
+            reader,
+            pending: vec![],
+            offset: 0,
+        }),
+        _ => Decoded::Other(
+            DecodeReaderBytesBuilder::new()
+                .encoding(Some(detected.encoding))
+                .build(reader),
+        ),
+    };
+    Ok((detected, BufReader::new(decoded)))
+}
+
+/// An input decoded into UTF-8, either checked to be UTF-8 already or transcoded.
+enum Decoded<R: Read> {
+    Utf8(CheckUtf8<R>),
+    Other(DecodeReaderBytes<R, Vec<u8>>),
+}
+
+impl<R: Read> Read for Decoded<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Decoded::Utf8(reader) => reader.read(buf),
+            Decoded::Other(reader) => reader.read(buf),
+        }
+    }
+}
+
+/// Pass the bytes of an input through, failing at the first sequence invalid in UTF-8.
+struct CheckUtf8<R> {
+    reader: R,
+    /// the start of a char cut at the end of the last read
+    pending: Vec<u8>,
+    /// count of bytes passed through before the last read
+    offset: u64,
+}
+
+impl<R: Read> Read for CheckUtf8<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.reader.read(buf)?;
+        let mut bytes = mem::take(&mut self.pending);
+        bytes.extend_from_slice(&buf[..count]);
+        let start = self.offset - (bytes.len() - count) as u64;
+        let invalid = match str::from_utf8(&bytes) {
+            Ok(_) => None,
+            Err(e) if e.error_len().is_none() && count > 0 => {
+                self.pending = bytes[e.valid_up_to()..].to_vec();
+                None
+            }
+            Err(e) => Some(start + e.valid_up_to() as u64),
+        };
+        if let Some(at) = invalid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "invalid UTF-8 at byte {}, guessed to be UTF-8 by the head, \
+                     see `--encoding`",
+                    at
+                ),
+            ));
+        }
+        self.offset += count as u64;
+        Ok(count)
+    }
+}
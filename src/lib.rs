@@ -1,33 +1,70 @@
+mod baseline;
 pub mod client;
+mod config;
 mod dialect;
 mod display;
+mod effort;
+mod encoding;
 mod registry;
+mod report;
 pub mod rules;
+mod threshold;
+mod visitor;
 
+use std::collections::BTreeMap;
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::BufRead;
+use std::mem;
 use std::ops::Add;
+use std::panic;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
 use std::time::{Duration, Instant};
 
 use anyhow::{anyhow, Context, Result};
-use rules::InfoLevel;
-use serde_json::Value;
+use encoding_rs::Encoding;
+use rules::{InfoLevel, RuleFuture, Version};
+use serde::Serialize;
+use sqlparser::ast::Statement;
 use sqlparser::parser::Parser;
 
-use crate::dialect::FixedStatement;
 use crate::dialect::MysqlBeyondDialect;
-use crate::registry::REGISTRY;
+use crate::dialect::{
+    addup_statement, is_routine, split_routine, statement_kind, AddupStatement, RawStatement,
+    StatementReader,
+};
+use crate::registry::registry;
 use crate::rules::RuleInfo;
 
-pub use display::{format_records, format_summary};
+pub use baseline::{Baseline, BaselineEntry, BASELINE_FILE};
+pub use config::Config;
+pub use dialect::{Location, Span};
+pub use display::{
+    format_annotated, format_compact, format_delta, format_failure, format_failure_compact,
+    format_fixed, format_fixed_compact, format_records, format_summary, format_totals,
+};
+pub use effort::Effort;
+pub use encoding::{
+    decode_reader, detect_encoding, encoding_for_label, DetectedEncoding, EncodingSource,
+};
+pub use registry::{load_rules, RuleRegistry};
+pub use report::{
+    format_checkstyle, format_html, format_json, format_jsonl, format_jsonl_findings, format_junit,
+    format_sarif, SCHEMA_VERSION,
+};
+pub use threshold::{FailOn, Thresholds};
 
 #[derive(Debug, Clone)]
 pub struct OnceInfo {
     sql: String,
     records: Vec<RuleInfo>,
     file: Option<PathBuf>,
+    span: Span,
+    routine: Option<String>,
+    kind: String,
+    schema: Option<String>,
 }
 
 impl OnceInfo {
@@ -42,14 +79,106 @@ impl OnceInfo {
     pub fn file(&self) -> &Option<PathBuf> {
         &self.file
     }
+
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// The routine whose body contains the statement, such as `PROCEDURE getname`.
+    pub fn routine(&self) -> &Option<String> {
+        &self.routine
+    }
+
+    /// The kind of the statement, such as `CreateTable`, see `statement_kind`.
+    pub fn kind(&self) -> &String {
+        &self.kind
+    }
+
+    /// The schema in use by the statement, as set by the last `USE` before it.
+    pub fn schema(&self) -> &Option<String> {
+        &self.schema
+    }
+
+    /// Whether the findings are of a statement, rather than of the encoding of a file.
+    fn of_statement(&self) -> bool {
+        self.kind != AddupStatement::Encoding.to_string()
+    }
 }
 
+/// Findings split by how they change when upgrading TiDB from one version to another.
+///
+/// Each `OnceInfo` only holds the records of its part, findings applying to neither
+/// version are left out.
 #[derive(Debug)]
+pub struct UpgradeDelta {
+    from: Version,
+    to: Version,
+    resolved: Vec<OnceInfo>,
+    remaining: Vec<OnceInfo>,
+    introduced: Vec<OnceInfo>,
+}
+
+impl UpgradeDelta {
+    pub fn from(&self) -> &Version {
+        &self.from
+    }
+
+    pub fn to(&self) -> &Version {
+        &self.to
+    }
+
+    /// Findings of the old version that the new version supports.
+    pub fn resolved(&self) -> &Vec<OnceInfo> {
+        &self.resolved
+    }
+
+    /// Findings of both versions.
+    pub fn remaining(&self) -> &Vec<OnceInfo> {
+        &self.remaining
+    }
+
+    /// Findings of the new version only.
+    pub fn introduced(&self) -> &Vec<OnceInfo> {
+        &self.introduced
+    }
+}
+
+/// A file could not be checked at all, e.g. it is unreadable.
+#[derive(Debug, Serialize)]
+pub struct FileFailure {
+    file: PathBuf,
+    reason: String,
+}
+
+impl FileFailure {
+    pub fn file(&self) -> &PathBuf {
+        &self.file
+    }
+
+    pub fn reason(&self) -> &String {
+        &self.reason
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
 pub struct Summary {
     file_count: u128,
     sql_count: u128,
     errors: u128,
     warnings: u128,
+    suppressed: u128,
+    bulk_inserts: u128,
+    clean: u128,
+    hidden: u128,
+    clean_percent: f64,
+    by_rule: BTreeMap<String, u128>,
+    by_future: BTreeMap<RuleFuture, u128>,
+    by_kind: BTreeMap<String, u128>,
+    by_file: BTreeMap<PathBuf, u128>,
+    effort: Effort,
+    effort_by_file: BTreeMap<PathBuf, Effort>,
+    effort_by_schema: BTreeMap<String, Effort>,
+    #[serde(rename = "time_cost_ms", serialize_with = "report::serialize_millis")]
     time_cost: Duration,
 }
 
@@ -70,9 +199,150 @@ impl Summary {
         &self.warnings
     }
 
+    /// Count of findings suppressed by `tikey:` comments, not counted in errors or warnings.
+    pub fn suppressed(&self) -> &u128 {
+        &self.suppressed
+    }
+
+    /// Count of bulk data inserts, whose rows past the first one were skipped rather than
+    /// parsed, see `RawStatement::bulk`.
+    pub fn bulk_inserts(&self) -> &u128 {
+        &self.bulk_inserts
+    }
+
+    /// Count of statements checked without any finding, neither reported nor hidden.
+    pub fn clean(&self) -> &u128 {
+        &self.clean
+    }
+
+    /// Count of statements whose findings were all suppressed by comments or in the
+    /// baseline, so that none is reported. They are not counted as clean.
+    pub fn hidden(&self) -> &u128 {
+        &self.hidden
+    }
+
+    /// Percentage of statements checked without any finding, rounded to two decimals.
+    pub fn clean_percent(&self) -> &f64 {
+        &self.clean_percent
+    }
+
+    /// Count of findings of each rule, by uid.
+    pub fn by_rule(&self) -> &BTreeMap<String, u128> {
+        &self.by_rule
+    }
+
+    /// Count of findings of each rule future.
+    pub fn by_future(&self) -> &BTreeMap<RuleFuture, u128> {
+        &self.by_future
+    }
+
+    /// Count of findings of each statement kind, see `OnceInfo::kind`.
+    pub fn by_kind(&self) -> &BTreeMap<String, u128> {
+        &self.by_kind
+    }
+
+    /// Count of findings of each file, statements not read from a file are left out.
+    pub fn by_file(&self) -> &BTreeMap<PathBuf, u128> {
+        &self.by_file
+    }
+
+    /// Estimated effort to migrate all the findings.
+    pub fn effort(&self) -> &Effort {
+        &self.effort
+    }
+
+    /// Estimated effort of each file, statements not read from a file are left out.
+    pub fn effort_by_file(&self) -> &BTreeMap<PathBuf, Effort> {
+        &self.effort_by_file
+    }
+
+    /// Estimated effort of each schema, statements before any `USE` are left out.
+    pub fn effort_by_schema(&self) -> &BTreeMap<String, Effort> {
+        &self.effort_by_schema
+    }
+
+    /// Wall time of the check, rather than the sum over files checked at the same time.
     pub fn time_cost(&self) -> &Duration {
         &self.time_cost
     }
+
+    /// Count the findings again, e.g. after some of them are filtered out.
+    pub(crate) fn recount(self, all_info: &Vec<OnceInfo>) -> Self {
+        let mut counted = Summary::default();
+        for info in all_info {
+            counted.count(info);
+        }
+        let flagged = all_info.iter().filter(|info| info.of_statement()).count();
+        self.with_counts(counted, flagged as u128)
+    }
+
+    /// Take the counts of findings from `counted`, made of `flagged` statements, while
+    /// keeping the counts of files and statements.
+    pub(crate) fn with_counts(self, counted: Summary, flagged: u128) -> Self {
+        let mut summary = Self {
+            file_count: self.file_count,
+            sql_count: self.sql_count,
+            suppressed: self.suppressed,
+            bulk_inserts: self.bulk_inserts,
+            hidden: self.hidden,
+            time_cost: self.time_cost,
+            ..counted
+        };
+        summary.count_clean(flagged);
+        summary
+    }
+
+    /// Count the findings of a statement.
+    fn count(&mut self, info: &OnceInfo) {
+        for rec in &info.records {
+            match rec.info_level() {
+                InfoLevel::ERROR => self.errors += 1,
+                InfoLevel::WARNING => self.warnings += 1,
+            }
+            *self.by_rule.entry(rec.uid().clone()).or_default() += 1;
+            *self.by_future.entry(rec.future().clone()).or_default() += 1;
+            *self.by_kind.entry(info.kind.clone()).or_default() += 1;
+            self.effort.count(rec);
+            if let Some(file) = &info.file {
+                *self.by_file.entry(file.clone()).or_default() += 1;
+                self.effort_by_file
+                    .entry(file.clone())
+                    .or_default()
+                    .count(rec);
+            }
+            if let Some(schema) = &info.schema {
+                self.effort_by_schema
+                    .entry(schema.clone())
+                    .or_default()
+                    .count(rec);
+            }
+        }
+    }
+
+    /// Count the clean statements, knowing how many statements have reported findings.
+    fn count_clean(&mut self, flagged: u128) {
+        self.clean = self.sql_count.saturating_sub(flagged + self.hidden);
+        self.clean_percent = percent(self.clean, self.sql_count);
+    }
+}
+
+/// The part in percent of the whole rounded to two decimals, a whole of zero is all clean.
+fn percent(part: u128, whole: u128) -> f64 {
+    if whole == 0 {
+        return 100.0;
+    }
+    (part as f64 * 10000.0 / whole as f64).round() / 100.0
+}
+
+fn merge<K: Ord, V: Add<Output = V> + Default>(
+    mut counts: BTreeMap<K, V>,
+    other: BTreeMap<K, V>,
+) -> BTreeMap<K, V> {
+    for (key, count) in other {
+        let entry = counts.entry(key).or_default();
+        *entry = mem::take(entry) + count;
+    }
+    counts
 }
 
 impl Add for Summary {
@@ -84,112 +354,465 @@ impl Add for Summary {
             sql_count: self.sql_count + other.sql_count,
             errors: self.errors + other.errors,
             warnings: self.warnings + other.warnings,
+            suppressed: self.suppressed + other.suppressed,
+            bulk_inserts: self.bulk_inserts + other.bulk_inserts,
+            clean: self.clean + other.clean,
+            hidden: self.hidden + other.hidden,
+            clean_percent: percent(self.clean + other.clean, self.sql_count + other.sql_count),
+            by_rule: merge(self.by_rule, other.by_rule),
+            by_future: merge(self.by_future, other.by_future),
+            by_kind: merge(self.by_kind, other.by_kind),
+            by_file: merge(self.by_file, other.by_file),
+            effort: self.effort + other.effort,
+            effort_by_file: merge(self.effort_by_file, other.effort_by_file),
+            effort_by_schema: merge(self.effort_by_schema, other.effort_by_schema),
             time_cost: self.time_cost + other.time_cost,
         }
     }
 }
 
-fn read_value(value: &Value, check_result: &mut Vec<RuleInfo>) -> Result<()> {
-    if !value.is_array() && !value.is_object() {
-        let key_equal_result = REGISTRY.check_string_elem(value);
-        check_result.extend(key_equal_result.into_iter());
-        return Ok(());
-    }
-    if value.is_array() {
-        for inner in value.as_array().ok_or_else(|| anyhow!("impossable"))? {
-            read_value(inner, check_result)?;
+/// Check a single statement, as well as statements inside its body if it is a routine.
+///
+/// Statements checked and findings suppressed by comments are counted into the summary,
+/// and `schema` follows the `USE` statements.
+fn check_raw_statement(
+    registry: &RuleRegistry,
+    dialect: &MysqlBeyondDialect,
+    raw: &RawStatement,
+    routine: Option<&String>,
+    schema: &mut Option<String>,
+    summary: &mut Summary,
+    all_info: &mut Vec<OnceInfo>,
+) -> Result<()> {
+    let sql = raw.to_sql();
+    // a statement failed to parse is reported by itself, without aborting the others
+    let (ast, parse_error) = match Parser::parse_sql(dialect, &sql) {
+        Ok(ast) => (ast, None),
+        Err(e) => {
+            let error = e.to_string();
+            let statement = addup_statement(AddupStatement::ParseError, Some(error.clone()));
+            (vec![statement], Some(error))
         }
-        return Ok(());
-    }
-    for val in value.as_object().ok_or_else(|| anyhow!("impossable"))? {
-        let (key, v) = val;
-        let key_equal_result = REGISTRY.check_key_equal(key, v);
-        check_result.extend(key_equal_result.into_iter());
-
-        read_value(&v, check_result)?;
-    }
-    return Ok(());
-}
-
-pub fn check_statements(contents: String) -> Result<(Summary, Vec<OnceInfo>)> {
-    let contents = contents.to_lowercase();
-    let start_clock = Instant::now();
-    let dialect = MysqlBeyondDialect {
-        is_recalled: AtomicBool::new(false),
     };
-    let ast = Parser::parse_sql(&dialect, &contents)
-        .with_context(|| format!("Parsing SQL statements: {}", contents))?;
+    summary.sql_count += ast.len() as u128;
+    if raw.bulk.is_some() {
+        summary.bulk_inserts += 1;
+    }
 
-    let mut all_info: Vec<OnceInfo> = vec![];
     for statement in &ast {
-        let value = serde_json::to_value(statement.clone())
-            .with_context(|| format!("Serde SQL statement: {}", statement.to_string()))?;
-        println!("{:?}", value);
-        let mut check_result: Vec<RuleInfo> = vec![];
-        read_value(&value, &mut check_result)?;
+        if let Statement::Use { db_name } = statement {
+            *schema = Some(db_name.value.clone());
+        }
+        let (suppressed, mut check_result): (Vec<RuleInfo>, Vec<RuleInfo>) = registry
+            .check_statement(statement)?
+            .into_iter()
+            .partition(|rec| raw.suppression.covers(rec.uid()));
+        summary.suppressed += suppressed.len() as u128;
+        if check_result.is_empty() && !suppressed.is_empty() {
+            summary.hidden += 1;
+        }
+        // tell why the statement failed to parse
+        if let Some(error) = &parse_error {
+            for rec in &mut check_result {
+                let description = format!("{}: {}", rec.description(), error);
+                rec.set_description(description);
+            }
+        }
         if !check_result.is_empty() {
             all_info.push(OnceInfo {
-                sql: FixedStatement(statement.clone()).to_string(),
+                sql: raw.text.to_string(),
                 records: check_result,
                 file: None,
+                span: raw.span,
+                routine: routine.cloned(),
+                kind: statement_kind(statement),
+                schema: schema.clone(),
             })
         }
+        if !is_routine(statement) {
+            continue;
+        }
+        if let Some(inner) = split_routine(raw) {
+            for inner_raw in &inner.body {
+                let name = Some(&inner.name);
+                check_raw_statement(
+                    registry, dialect, inner_raw, name, schema, summary, all_info,
+                )?;
+            }
+        }
     }
-    let (error_count, warning_count) = count_info(&all_info);
-    let summary = Summary {
-        file_count: 0,
-        sql_count: ast.len() as u128,
-        errors: error_count,
-        warnings: warning_count,
-        time_cost: Instant::now() - start_clock,
-    };
+    Ok(())
+}
+
+pub fn check_statements(contents: String) -> Result<(Summary, Vec<OnceInfo>)> {
+    check_statements_with(registry(), contents)
+}
+
+/// Check statements against the rules of the given registry rather than the global one.
+pub fn check_statements_with(
+    registry: &RuleRegistry,
+    contents: String,
+) -> Result<(Summary, Vec<OnceInfo>)> {
+    let mut all_info: Vec<OnceInfo> = vec![];
+    let summary = check_reader_with(registry, contents.as_bytes(), None, |info| {
+        all_info.push(info);
+        Ok(())
+    })?;
     Ok((summary, all_info))
 }
 
+pub fn check_reader<R, F>(reader: R, file: Option<&Path>, on_info: F) -> Result<Summary>
+where
+    R: BufRead,
+    F: FnMut(OnceInfo) -> Result<()>,
+{
+    check_reader_with(registry(), reader, file, on_info)
+}
+
+/// Check statements read one at a time, handing the findings of each statement to
+/// `on_info` as soon as it is checked.
+///
+/// Only the statements being checked are held in memory, so that the input could be
+/// larger than the memory, e.g. a dump of a whole database. Findings are of `file` if given.
+pub fn check_reader_with<R, F>(
+    registry: &RuleRegistry,
+    reader: R,
+    file: Option<&Path>,
+    mut on_info: F,
+) -> Result<Summary>
+where
+    R: BufRead,
+    F: FnMut(OnceInfo) -> Result<()>,
+{
+    let start_clock = Instant::now();
+    let dialect = MysqlBeyondDialect::default();
+
+    let mut summary = Summary::default();
+    let mut schema: Option<String> = None;
+    let mut flagged: u128 = 0;
+    let mut found: Vec<OnceInfo> = vec![];
+    StatementReader::new(reader).for_each(|raw| -> Result<()> {
+        check_raw_statement(
+            registry,
+            &dialect,
+            raw,
+            None,
+            &mut schema,
+            &mut summary,
+            &mut found,
+        )?;
+        for mut info in found.drain(..) {
+            info.file = file.map(Path::to_path_buf);
+            summary.count(&info);
+            flagged += 1;
+            on_info(info)?;
+        }
+        Ok(())
+    })?;
+    summary.count_clean(flagged);
+    summary.time_cost = Instant::now() - start_clock;
+    Ok(summary)
+}
+
 pub fn check_file<P: AsRef<Path>>(path: P) -> Result<(Summary, Vec<OnceInfo>)> {
+    check_file_with(registry(), path)
+}
+
+/// Check a file against the rules of the given registry rather than the global one.
+pub fn check_file_with<P: AsRef<Path>>(
+    registry: &RuleRegistry,
+    path: P,
+) -> Result<(Summary, Vec<OnceInfo>)> {
+    check_file_with_encoding(registry, path, None)
+}
+
+/// Check a file decoded from the given encoding, or from the one detected if not given.
+///
+/// A file not in UTF-8 is reported by the finding of its encoding, before the findings
+/// of its statements.
+pub fn check_file_with_encoding<P: AsRef<Path>>(
+    registry: &RuleRegistry,
+    path: P,
+    encoding: Option<&'static Encoding>,
+) -> Result<(Summary, Vec<OnceInfo>)> {
+    let mut all_info: Vec<OnceInfo> = vec![];
+    let summary = stream_file(registry, path, encoding, |info| {
+        all_info.push(info);
+        Ok(())
+    })?;
+    Ok((summary, all_info))
+}
+
+/// Check a file like `check_file_with_encoding`, handing the findings of each statement
+/// to `on_info` as soon as it is checked rather than gathering them.
+pub fn stream_file<P, F>(
+    registry: &RuleRegistry,
+    path: P,
+    encoding: Option<&'static Encoding>,
+    mut on_info: F,
+) -> Result<Summary>
+where
+    P: AsRef<Path>,
+    F: FnMut(OnceInfo) -> Result<()>,
+{
     let path = path.as_ref();
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
-    let mut contents = String::new();
-    buf_reader
-        .read_to_string(&mut contents)
+    let file = File::open(path).with_context(|| format!("At file: {:?}", path))?;
+    let (detected, reader) =
+        decode_reader(file, encoding).with_context(|| format!("At file: {:?}", path))?;
+    let mut encoding_info: Option<OnceInfo> = None;
+    // an encoding given by the caller is known to be right, there is nothing to tell
+    if !detected.is_utf8() && *detected.source() != EncodingSource::Given {
+        let statement = addup_statement(AddupStatement::Encoding, Some(detected.to_string()));
+        let records = registry.check_statement(&statement)?;
+        if !records.is_empty() {
+            let start = Location { line: 1, column: 1 };
+            let info = OnceInfo {
+                sql: detected.to_string(),
+                records,
+                file: Some(path.to_path_buf()),
+                span: Span { start, end: start },
+                routine: None,
+                kind: statement_kind(&statement),
+                schema: None,
+            };
+            on_info(info.clone())?;
+            encoding_info = Some(info);
+        }
+    }
+    let mut summary = check_reader_with(registry, reader, Some(path), &mut on_info)
         .with_context(|| format!("At file: {:?}", path))?;
-    let (mut summary, info_statement) =
-        check_statements(contents).with_context(|| format!("At file: {:?}", path))?;
-    summary.file_count = 1;
-    let info_statement = info_statement
-        .into_iter()
-        .map(|info| {
-            let mut revised_info = info;
-            revised_info.file = Some(path.to_path_buf());
-            revised_info
-        })
-        .collect();
-    Ok((summary, info_statement))
+    if let Some(info) = &encoding_info {
+        summary.count(info);
+    }
+    let summary = Summary {
+        file_count: 1,
+        ..summary
+    };
+    Ok(summary)
+}
+
+/// Check all files, a file failed to check is returned beside the others' results.
+pub fn check_files<P: AsRef<Path> + Sync>(
+    paths: Vec<P>,
+) -> Result<(Summary, Vec<OnceInfo>, Vec<FileFailure>)> {
+    check_files_with(registry(), paths)
+}
+
+/// Check all files against the rules of the given registry rather than the global one.
+pub fn check_files_with<P: AsRef<Path> + Sync>(
+    registry: &RuleRegistry,
+    paths: Vec<P>,
+) -> Result<(Summary, Vec<OnceInfo>, Vec<FileFailure>)> {
+    check_files_with_jobs(registry, paths, default_jobs())
+}
+
+/// Count of jobs checking files by default, one for each available CPU.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
 }
 
-pub fn check_files<P: AsRef<Path>>(paths: Vec<P>) -> Result<(Summary, Vec<OnceInfo>)> {
+/// Check all files with up to `jobs` threads, each taking the next file left once it is done.
+///
+/// Results are gathered in the order of `paths` whatever the jobs, and the time cost of
+/// the summary is the wall time of the whole check.
+pub fn check_files_with_jobs<P: AsRef<Path> + Sync>(
+    registry: &RuleRegistry,
+    paths: Vec<P>,
+    jobs: usize,
+) -> Result<(Summary, Vec<OnceInfo>, Vec<FileFailure>)> {
+    check_files_with_encoding(registry, paths, jobs, None)
+}
+
+/// Check all files with up to `jobs` threads, each file decoded from the given encoding,
+/// or from the one detected in it if not given.
+pub fn check_files_with_encoding<P: AsRef<Path> + Sync>(
+    registry: &RuleRegistry,
+    paths: Vec<P>,
+    jobs: usize,
+    encoding: Option<&'static Encoding>,
+) -> Result<(Summary, Vec<OnceInfo>, Vec<FileFailure>)> {
+    let mut all_info: Vec<OnceInfo> = vec![];
+    let (summary, failures) = stream_files(registry, &paths, jobs, encoding, |info| {
+        all_info.push(info);
+        Ok(())
+    })?;
+    Ok((summary, all_info, failures))
+}
+
+/// Count of findings a file checked ahead of its turn may hold before its job waits.
+const FINDINGS_AHEAD: usize = 64;
+
+/// What a job tells of the file it checks, each finding then the result of the file.
+enum FileEvent {
+    Info(OnceInfo),
+    Done(Result<Summary>),
+}
+
+/// Check all files like `check_files_with_encoding`, handing each finding to `on_info`
+/// as soon as it is checked rather than gathering them.
+///
+/// The findings are still handed over in the order of `paths`: a file checked ahead of its
+/// turn holds up to `FINDINGS_AHEAD` of them, then its job waits for the files before it.
+/// The findings of a file failing part way are handed over up to the failure.
+pub fn stream_files<P, F>(
+    registry: &RuleRegistry,
+    paths: &[P],
+    jobs: usize,
+    encoding: Option<&'static Encoding>,
+    mut on_info: F,
+) -> Result<(Summary, Vec<FileFailure>)>
+where
+    P: AsRef<Path> + Sync,
+    F: FnMut(OnceInfo) -> Result<()>,
+{
     if paths.is_empty() {
         return Err(anyhow!("cannot handle empty paths"));
     }
-    let check_rets: Vec<Result<(Summary, Vec<OnceInfo>)>> =
-        paths.into_iter().map(|p| check_file(p)).collect();
+    let start_clock = Instant::now();
+    let jobs = jobs.clamp(1, paths.len());
+    let next = AtomicUsize::new(0);
+    let mut summary = Summary::default();
+    let mut failures: Vec<FileFailure> = vec![];
+    thread::scope(|scope| -> Result<()> {
+        let (files, checking) = mpsc::sync_channel(jobs);
+        let workers: Vec<_> = (0..jobs)
+            .map(|_| {
+                let files = files.clone();
+                let next = &next;
+                scope.spawn(move || loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let path = match paths.get(index) {
+                        Some(path) => path,
+                        None => return,
+                    };
+                    let (events, received) = mpsc::sync_channel(FINDINGS_AHEAD);
+                    // the receivers are gone only once a finding failed to be handed over
+                    if files.send((index, received)).is_err() {
+                        return;
+                    }
+                    let result = stream_file(registry, path, encoding, |info| {
+                        events
+                            .send(FileEvent::Info(info))
+                            .map_err(|_| anyhow!("check stopped"))
+                    });
+                    if events.send(FileEvent::Done(result)).is_err() {
+                        return;
+                    }
+                })
+            })
+            .collect();
+        drop(files);
 
-    let mut rets: Vec<(Summary, Vec<OnceInfo>)> = vec![];
-    for ret in check_rets {
-        match ret {
-            Ok(t) => rets.push(t),
-            Err(_) => return ret,
+        let mut pending = BTreeMap::new();
+        let mut outcome = Ok(());
+        'files: for (handed, path) in paths.iter().enumerate() {
+            let received = loop {
+                if let Some(received) = pending.remove(&handed) {
+                    break received;
+                }
+                match checking.recv() {
+                    Ok((index, received)) => pending.insert(index, received),
+                    // all jobs are gone, a panic is resumed below
+                    Err(_) => break 'files,
+                };
+            };
+            for event in received.iter() {
+                match event {
+                    FileEvent::Info(info) => {
+                        if let Err(e) = on_info(info) {
+                            outcome = Err(e);
+                            break 'files;
+                        }
+                    }
+                    FileEvent::Done(Ok(sum)) => summary = mem::take(&mut summary) + sum,
+                    FileEvent::Done(Err(e)) => failures.push(FileFailure {
+                        file: path.as_ref().to_path_buf(),
+                        reason: format!("{:#}", e),
+                    }),
+                }
+            }
         }
-    }
-    rets.into_iter()
-        .reduce(|(left_sum, left_info), (right_sum, right_info)| {
-            (left_sum + right_sum, [left_info, right_info].concat())
+        drop(checking);
+        drop(pending);
+        for worker in workers {
+            worker.join().unwrap_or_else(|e| panic::resume_unwind(e));
+        }
+        outcome
+    })?;
+    summary.time_cost = Instant::now() - start_clock;
+    Ok((summary, failures))
+}
+
+/// Apply the rule settings of the config to the findings, the summary is counted again.
+pub fn apply_config(
+    config: &Config,
+    summary: Summary,
+    all_info: Vec<OnceInfo>,
+) -> (Summary, Vec<OnceInfo>) {
+    let all_info: Vec<OnceInfo> = all_info
+        .into_iter()
+        .filter_map(|info| apply_config_to(config, info))
+        .collect();
+    (summary.recount(&all_info), all_info)
+}
+
+/// Apply the rule settings of the config to a finding, none if no record is left.
+pub(crate) fn apply_config_to(config: &Config, mut info: OnceInfo) -> Option<OnceInfo> {
+    info.records = config.apply(info.file.as_deref(), info.records);
+    (!info.records.is_empty()).then_some(info)
+}
+
+/// Keep the findings of rules applying to the TiDB version, the summary is counted again.
+///
+/// It suits findings checked by a registry holding rules for every version, see
+/// `RuleRegistry::retain_version` to skip the other rules while checking.
+pub fn apply_version(
+    version: &Version,
+    summary: Summary,
+    all_info: Vec<OnceInfo>,
+) -> (Summary, Vec<OnceInfo>) {
+    let all_info: Vec<OnceInfo> = all_info
+        .into_iter()
+        .filter_map(|mut info| {
+            info.records.retain(|rec| rec.applies_to(version));
+            (!info.records.is_empty()).then_some(info)
         })
-        .ok_or_else(|| anyhow!("impossable"))
+        .collect();
+    (summary.recount(&all_info), all_info)
+}
+
+/// Compare the findings on TiDB `from` with the ones on TiDB `to`.
+///
+/// The findings must be checked by a registry holding rules for both versions.
+pub fn upgrade_delta(from: &Version, to: &Version, all_info: &[OnceInfo]) -> UpgradeDelta {
+    let mut delta = UpgradeDelta {
+        from: *from,
+        to: *to,
+        resolved: vec![],
+        remaining: vec![],
+        introduced: vec![],
+    };
+    for info in all_info {
+        let part = |keep: fn(bool, bool) -> bool| {
+            let records: Vec<RuleInfo> = info
+                .records
+                .iter()
+                .filter(|rec| keep(rec.applies_to(from), rec.applies_to(to)))
+                .cloned()
+                .collect();
+            (!records.is_empty()).then(|| OnceInfo {
+                records,
+                ..info.clone()
+            })
+        };
+        delta.resolved.extend(part(|old, new| old && !new));
+        delta.remaining.extend(part(|old, new| old && new));
+        delta.introduced.extend(part(|old, new| !old && new));
+    }
+    delta
 }
 
-fn count_info(all_info: &Vec<OnceInfo>) -> (u128, u128) {
+pub(crate) fn count_info(all_info: &Vec<OnceInfo>) -> (u128, u128) {
     let mut error_count: u128 = 0;
     let mut warning_count: u128 = 0;
     for info in all_info {
@@ -1,7 +1,13 @@
+use std::process;
+
 use clap::Parser;
-use tikey::client::{client, TiKeyArgs};
+use tikey::client::{client, ExitStatus, TiKeyArgs};
 
 fn main() {
     let args = TiKeyArgs::parse();
-    client(args).expect("TiKey running failed");
+    let status = client(args).unwrap_or_else(|e| {
+        eprintln!("error: {:#}", e);
+        ExitStatus::of_error(&e)
+    });
+    process::exit(status.code());
 }
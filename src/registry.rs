@@ -1,75 +1,187 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
 
-use once_cell::sync::Lazy;
+use anyhow::{anyhow, Context, Result};
+use once_cell::sync::OnceCell;
 use serde_json::Value;
+use sqlparser::ast::{Expr, ObjectName, Statement};
 
 use crate::rules::{
-    Rule, RuleCharset, RuleColPriv, RuleDelimiter, RuleEndEarly, RuleEvent, RuleForeignKey,
-    RuleFullText, RuleFunction, RuleInfo, RuleMysqlFunc, RuleOptimTrace, RuleProcedure,
-    RuleSavepoint, RuleSpatial, RuleSysSchema, RuleTrigger, RuleUnknown, RuleXA, Trigger,
+    DeclaredRule, Rule, RuleCharset, RuleColPriv, RuleDelimiter, RuleEncoding, RuleEndEarly,
+    RuleEvent, RuleFile, RuleForeignKey, RuleFullText, RuleFunction, RuleInfo, RuleMysqlFunc,
+    RuleOptimTrace, RuleParseError, RuleProcedure, RuleSavepoint, RuleSpatial, RuleSysSchema,
+    RuleTrigger, RuleUnknown, RuleXA, Trigger, Version,
 };
+use crate::visitor::{walk_statement, Visitor};
 
-pub static REGISTRY: Lazy<RuleRegistry> = Lazy::new(|| {
-    let mut reg = RuleRegistry::new();
-    // register rule of `head` type
-    reg.register(RuleFunction {});
-    reg.register(RuleTrigger {});
-    reg.register(RuleEvent {});
-    reg.register(RuleProcedure {});
-    reg.register(RuleFullText {});
-    reg.register(RuleSavepoint {});
-    reg.register(RuleXA {});
-
-    // register rule of `mid` type
-    reg.register(RuleForeignKey {});
-    reg.register(RuleMysqlFunc {});
-    reg.register(RuleSpatial {});
-    reg.register(RuleCharset {});
-    reg.register(RuleSysSchema {});
-    reg.register(RuleOptimTrace {});
-    reg.register(RuleColPriv {});
-
-    // register rule of `special` type
-    reg.register(RuleUnknown {});
-    reg.register(RuleDelimiter {});
-    reg.register(RuleEndEarly {});
-    reg
-});
+static REGISTRY: OnceCell<RuleRegistry> = OnceCell::new();
+
+/// The registry used by checks, built-in rules only unless `load_rules` was called first.
+pub fn registry() -> &'static RuleRegistry {
+    REGISTRY.get_or_init(RuleRegistry::builtin)
+}
+
+/// Load declared rules into the registry used by checks, beside the built-in rules.
+/// It must be called before the first check, see `RuleRegistry::load_rules`.
+///
+/// Returns the count of rules loaded.
+pub fn load_rules<P: AsRef<Path>>(path: P) -> Result<usize> {
+    let mut reg = RuleRegistry::builtin();
+    let count = reg.load_rules(path)?;
+    REGISTRY
+        .set(reg)
+        .map_err(|_| anyhow!("rules must be loaded before any check"))?;
+    Ok(count)
+}
+
+type ValueJudge = (fn(&Value) -> bool, RuleInfo);
+type BoxedPathJudger = Box<dyn Fn(&str, &Value) -> bool + Send + Sync>;
+type PathJudge = (Vec<PathPattern>, BoxedPathJudger, RuleInfo);
+type StatementJudge = (fn(&Statement) -> bool, RuleInfo);
+type ExprJudge = (fn(&Expr) -> bool, RuleInfo);
+type TableJudge = (fn(&ObjectName) -> bool, RuleInfo);
 
 pub struct RuleRegistry {
-    equal_keys_judge: HashMap<String, Vec<(fn(&Value) -> bool, RuleInfo)>>,
+    uids: HashSet<String>,
+    equal_keys_judge: HashMap<String, Vec<ValueJudge>>,
     equal_string_elem: HashMap<String, Vec<RuleInfo>>,
+    path_judge: Vec<PathJudge>,
+    statement_judge: Vec<StatementJudge>,
+    expr_judge: Vec<ExprJudge>,
+    table_judge: Vec<TableJudge>,
+}
+
+/// Collects the findings of typed triggers while walking a statement.
+struct JudgeVisitor<'a> {
+    registry: &'a RuleRegistry,
+    check_result: Vec<RuleInfo>,
+}
+
+impl Visitor for JudgeVisitor<'_> {
+    fn visit_statement(&mut self, statement: &Statement) {
+        for (f, info) in &self.registry.statement_judge {
+            if f(statement) {
+                self.check_result.push(info.clone());
+            }
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        for (f, info) in &self.registry.expr_judge {
+            if f(expr) {
+                self.check_result.push(info.clone());
+            }
+        }
+    }
+
+    fn visit_table(&mut self, name: &ObjectName) {
+        for (f, info) in &self.registry.table_judge {
+            if f(name) {
+                self.check_result.push(info.clone());
+            }
+        }
+    }
+}
+
+impl Default for RuleRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl RuleRegistry {
-    fn new() -> Self {
+    /// An empty registry, rules are added by `register` or `load_rules`.
+    pub fn new() -> Self {
         Self {
+            uids: HashSet::new(),
             equal_keys_judge: HashMap::new(),
             equal_string_elem: HashMap::new(),
+            path_judge: vec![],
+            statement_judge: vec![],
+            expr_judge: vec![],
+            table_judge: vec![],
         }
     }
 
-    fn register(&mut self, rule: impl Rule) {
+    /// A registry holding all built-in rules.
+    pub fn builtin() -> Self {
+        let mut reg = Self::new();
+        // register rule of `head` type
+        reg.register(RuleFunction {});
+        reg.register(RuleTrigger {});
+        reg.register(RuleEvent {});
+        reg.register(RuleProcedure {});
+        reg.register(RuleFullText {});
+        reg.register(RuleSavepoint {});
+        reg.register(RuleXA {});
+
+        // register rule of `mid` type
+        reg.register(RuleForeignKey {});
+        reg.register(RuleMysqlFunc {});
+        reg.register(RuleSpatial {});
+        reg.register(RuleCharset {});
+        reg.register(RuleSysSchema {});
+        reg.register(RuleOptimTrace {});
+        reg.register(RuleColPriv {});
+
+        // register rule of `special` type
+        reg.register(RuleUnknown {});
+        reg.register(RuleDelimiter {});
+        reg.register(RuleEndEarly {});
+        reg.register(RuleParseError {});
+        reg.register(RuleEncoding {});
+        reg
+    }
+
+    /// Register a rule, replacing the registered one with the same uid.
+    pub fn register(&mut self, rule: impl Rule) {
         let info = rule.info();
-        if let Trigger::StringElemEqual(v) = rule.trigger() {
-            for s in v {
-                match self.equal_string_elem.get_mut(&s) {
-                    Some(rules) => rules.push(info.clone()),
-                    None => {
-                        let _ = self.equal_string_elem.insert(s, vec![info.clone()]);
-                    }
-                }
-            }
-            return;
-        }
-        let (s, f) = match rule.trigger() {
+        self.unregister(info.uid());
+        self.uids.insert(info.uid().clone());
+        self.register_trigger(rule.trigger(), info);
+    }
+
+    fn register_trigger(&mut self, trigger: Trigger, info: RuleInfo) {
+        let (s, f) = match trigger {
             Trigger::KeyEqualJudge((s, f)) => (s, f),
             Trigger::KeyEqual(s) => {
                 let f = |_: &Value| true;
                 (s, f as fn(&Value) -> bool)
             }
-            Trigger::StringElemEqual(_) => {
-                unreachable!()
+            Trigger::StringElemEqual(v) => {
+                for s in v {
+                    match self.equal_string_elem.get_mut(&s) {
+                        Some(rules) => rules.push(info.clone()),
+                        None => {
+                            let _ = self.equal_string_elem.insert(s, vec![info.clone()]);
+                        }
+                    }
+                }
+                return;
+            }
+            Trigger::PathMatchJudge((patterns, f)) => {
+                let patterns = patterns.iter().map(|p| PathPattern::new(p)).collect();
+                self.path_judge.push((patterns, Box::new(f), info));
+                return;
+            }
+            Trigger::StatementJudge(f) => {
+                self.statement_judge.push((f, info));
+                return;
+            }
+            Trigger::ExprJudge(f) => {
+                self.expr_judge.push((f, info));
+                return;
+            }
+            Trigger::TableJudge(f) => {
+                self.table_judge.push((f, info));
+                return;
+            }
+            Trigger::Any(triggers) => {
+                for trigger in triggers {
+                    self.register_trigger(trigger, info.clone());
+                }
+                return;
             }
         };
 
@@ -82,7 +194,195 @@ impl RuleRegistry {
         }
     }
 
-    pub fn check_key_equal(&self, key: &String, v: &Value) -> Vec<RuleInfo> {
+    /// Remove the rule with the uid, returns whether it was registered.
+    pub fn unregister(&mut self, uid: &str) -> bool {
+        if !self.uids.remove(uid) {
+            return false;
+        }
+        for rules in self.equal_keys_judge.values_mut() {
+            rules.retain(|(_f, info)| info.uid() != uid);
+        }
+        self.equal_keys_judge
+            .retain(|_key, rules| !rules.is_empty());
+        for rules in self.equal_string_elem.values_mut() {
+            rules.retain(|info| info.uid() != uid);
+        }
+        self.equal_string_elem
+            .retain(|_elem, rules| !rules.is_empty());
+        self.path_judge
+            .retain(|(_patterns, _f, info)| info.uid() != uid);
+        self.statement_judge.retain(|(_f, info)| info.uid() != uid);
+        self.expr_judge.retain(|(_f, info)| info.uid() != uid);
+        self.table_judge.retain(|(_f, info)| info.uid() != uid);
+        true
+    }
+
+    /// Keep only the rules applying to the TiDB version, see `RuleInfo::applies_to`.
+    ///
+    /// Returns the uids of the removed rules.
+    pub fn retain_version(&mut self, version: &Version) -> Vec<String> {
+        let removed: Vec<String> = self
+            .infos()
+            .into_iter()
+            .filter(|info| !info.applies_to(version))
+            .map(|info| info.uid().clone())
+            .collect();
+        for uid in &removed {
+            self.unregister(uid);
+        }
+        removed
+    }
+
+    /// The infos of all registered rules, ordered by uid.
+    pub fn infos(&self) -> Vec<&RuleInfo> {
+        let mut infos: Vec<&RuleInfo> = self
+            .equal_keys_judge
+            .values()
+            .flat_map(|rules| rules.iter().map(|(_f, info)| info))
+            .chain(self.equal_string_elem.values().flatten())
+            .chain(self.path_judge.iter().map(|(_patterns, _f, info)| info))
+            .chain(self.statement_judge.iter().map(|(_f, info)| info))
+            .chain(self.expr_judge.iter().map(|(_f, info)| info))
+            .chain(self.table_judge.iter().map(|(_f, info)| info))
+            .collect();
+        infos.sort_by(|a, b| a.uid().cmp(b.uid()));
+        // a rule of `StringElemEqual` or `Any` is kept once for each of its strings or triggers
+        infos.dedup_by(|a, b| a.uid() == b.uid());
+        infos
+    }
+
+    /// The uids of all registered rules, in no particular order.
+    pub fn uids(&self) -> Vec<&String> {
+        self.uids.iter().collect()
+    }
+
+    /// Load declared rules from a TOML file, or from every `.toml` file in a directory.
+    /// A declared rule must not share its uid with a registered one.
+    ///
+    /// Returns the count of rules loaded.
+    pub fn load_rules<P: AsRef<Path>>(&mut self, path: P) -> Result<usize> {
+        let path = path.as_ref();
+        let mut files = vec![];
+        if path.is_dir() {
+            for entry in fs::read_dir(path).with_context(|| format!("At rules: {:?}", path))? {
+                let file = entry?.path();
+                if file.extension().is_some_and(|s| s == "toml") {
+                    files.push(file);
+                }
+            }
+            files.sort();
+        } else {
+            files.push(path.to_path_buf());
+        }
+
+        let mut rules = vec![];
+        for file in files {
+            let contents =
+                fs::read_to_string(&file).with_context(|| format!("At rule file: {:?}", file))?;
+            let rule_file: RuleFile =
+                toml::from_str(&contents).with_context(|| format!("At rule file: {:?}", file))?;
+            for rule in rule_file.rule {
+                if self.uids.contains(rule.uid()) || rules.iter().any(|(r, _)| r == rule.uid()) {
+                    return Err(anyhow!("rule `{}` is already registered", rule.uid()))
+                        .with_context(|| format!("At rule file: {:?}", file));
+                }
+                rules.push((rule.uid().clone(), rule));
+            }
+        }
+        let count = rules.len();
+        for (_uid, rule) in rules {
+            self.register_declared(rule);
+        }
+        Ok(count)
+    }
+
+    fn register_declared(&mut self, rule: DeclaredRule) {
+        self.uids.insert(rule.uid().clone());
+        let info = rule.info();
+        let matcher = rule.matcher().clone();
+        let patterns = matcher
+            .paths()
+            .iter()
+            .map(|p| PathPattern::new(p))
+            .collect();
+        let f = move |_: &str, v: &Value| matcher.judge(v);
+        self.path_judge.push((patterns, Box::new(f), info));
+    }
+
+    /// Whether any registered rule matches on the JSON form of statements, so that every
+    /// statement is serialized. It holds only when declared rules are loaded.
+    pub fn needs_json(&self) -> bool {
+        !self.equal_keys_judge.is_empty()
+            || !self.equal_string_elem.is_empty()
+            || !self.path_judge.is_empty()
+    }
+
+    /// Check a statement against all registered rules.
+    ///
+    /// Typed triggers are judged first, the statement is serialized only if any rule
+    /// still matches on its JSON form.
+    pub(crate) fn check_statement(&self, statement: &Statement) -> Result<Vec<RuleInfo>> {
+        let mut visitor = JudgeVisitor {
+            registry: self,
+            check_result: vec![],
+        };
+        walk_statement(&mut visitor, statement);
+        let mut check_result = visitor.check_result;
+
+        if self.needs_json() {
+            let value = serde_json::to_value(statement)
+                .with_context(|| format!("Serde SQL statement: {}", statement))?;
+            self.read_value(&value, &mut vec![], &mut check_result);
+        }
+        Ok(check_result)
+    }
+
+    /// Walk the JSON form of a statement, `path` holds the keys from the root to `value`.
+    fn read_value(&self, value: &Value, path: &mut Vec<String>, check_result: &mut Vec<RuleInfo>) {
+        match value {
+            Value::Array(values) => {
+                match path.last_mut() {
+                    Some(key) => key.push_str("[]"),
+                    None => path.push("[]".to_string()),
+                }
+                for inner in values {
+                    self.read_value(inner, path, check_result);
+                }
+                if let Some(key) = path.last_mut() {
+                    key.truncate(key.len() - 2);
+                }
+            }
+            Value::Object(map) => {
+                for (key, v) in map {
+                    path.push(key.clone());
+                    check_result.extend(self.check_key_equal(key, v));
+                    check_result.extend(self.check_path_match(path, v));
+                    self.read_value(v, path, check_result);
+                    path.pop();
+                }
+            }
+            _ => check_result.extend(self.check_string_elem(value)),
+        }
+    }
+
+    fn check_path_match(&self, path: &[String], v: &Value) -> Vec<RuleInfo> {
+        let matched: Vec<&PathJudge> = self
+            .path_judge
+            .iter()
+            .filter(|(patterns, _f, _info)| patterns.iter().any(|p| p.matches(path)))
+            .collect();
+        if matched.is_empty() {
+            return vec![];
+        }
+        let full_path = path.join(".");
+        matched
+            .into_iter()
+            .filter(|(_patterns, f, _info)| f(&full_path, v))
+            .map(|(_patterns, _f, info)| info.clone())
+            .collect()
+    }
+
+    fn check_key_equal(&self, key: &String, v: &Value) -> Vec<RuleInfo> {
         match self.equal_keys_judge.get(key) {
             Some(ops) => ops
                 .iter()
@@ -93,9 +393,10 @@ impl RuleRegistry {
         }
     }
 
-    pub fn check_string_elem(&self, v: &Value) -> Vec<RuleInfo> {
+    /// String elements are matched case-insensitively, registered ones should be lowercase.
+    fn check_string_elem(&self, v: &Value) -> Vec<RuleInfo> {
         match v.as_str() {
-            Some(s) => match self.equal_string_elem.get(s) {
+            Some(s) => match self.equal_string_elem.get(&s.to_lowercase()) {
                 Some(v) => v.clone(),
                 None => {
                     vec![]
@@ -105,3 +406,30 @@ impl RuleRegistry {
         }
     }
 }
+
+/// A pattern of keys, `*` matches any single key and `**` matches any number of keys.
+struct PathPattern(Vec<String>);
+
+impl PathPattern {
+    fn new(pattern: &str) -> Self {
+        Self(pattern.split('.').map(|s| s.to_string()).collect())
+    }
+
+    fn matches(&self, path: &[String]) -> bool {
+        fn matches_from(pattern: &[String], path: &[String]) -> bool {
+            match pattern.split_first() {
+                None => path.is_empty(),
+                Some((first, rest)) if first == "**" => {
+                    (0..=path.len()).any(|skip| matches_from(rest, &path[skip..]))
+                }
+                Some((first, rest)) => match path.split_first() {
+                    Some((key, path_rest)) => {
+                        (first == "*" || first == key) && matches_from(rest, path_rest)
+                    }
+                    None => false,
+                },
+            }
+        }
+        matches_from(&self.0, path)
+    }
+}
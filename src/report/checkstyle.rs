@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use super::{escape_xml, group_by_file};
+use crate::{FileFailure, OnceInfo};
+
+/// Format the report as Checkstyle XML.
+///
+/// Each checked file is a `file` element, with an `error` for each of its findings whose
+/// `severity` is the level of the finding and `source` is `tikey.<uid>`. A file failed to
+/// check has a single `error` of source `tikey.failure`.
+pub fn format_checkstyle(
+    files: &[PathBuf],
+    all_info: &[OnceInfo],
+    failures: &[FileFailure],
+) -> String {
+    let mut elements = String::new();
+    for group in group_by_file(files, all_info, failures) {
+        let mut errors = String::new();
+        for finding in &group.findings {
+            errors.push_str(&format!(
+                "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"tikey.{}\"/>\n",
+                finding.span.start.line,
+                finding.span.start.column,
+                finding.rule.info_level(),
+                escape_xml(finding.rule.description()),
+                escape_xml(finding.rule.uid()),
+            ));
+        }
+        if let Some(failure) = group.failure {
+            errors.push_str(&format!(
+                "    <error severity=\"error\" message=\"{}\" source=\"tikey.failure\"/>\n",
+                escape_xml(failure.reason()),
+            ));
+        }
+        let name = escape_xml(&group.name());
+        if errors.is_empty() {
+            elements.push_str(&format!("  <file name=\"{}\"/>\n", name));
+        } else {
+            elements.push_str(&format!(
+                "  <file name=\"{}\">\n{}  </file>\n",
+                name, errors
+            ));
+        }
+    }
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n{}</checkstyle>",
+        elements
+    )
+}
@@ -0,0 +1,262 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+use super::{escape_xml, group_by_file, Finding};
+use crate::rules::{InfoLevel, RuleInfo};
+use crate::{decode_reader, FileFailure, OnceInfo, Summary};
+
+/// Lines shown around a statement in its snippet.
+const CONTEXT_LINES: u64 = 2;
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 2em auto; max-width: 70em; color: #222; }
+table { border-collapse: collapse; margin-bottom: 1.5em; }
+th, td { border: 1px solid #ccc; padding: 0.3em 0.8em; text-align: left; vertical-align: top; }
+th { background: #f0f0f0; }
+details { border: 1px solid #ddd; border-radius: 4px; margin: 0.4em 0; padding: 0.3em 0.6em; }
+summary { cursor: pointer; }
+pre { background: #f8f8f8; padding: 0.6em; overflow-x: auto; }
+mark { background: #ffe08a; }
+.error { color: #b00020; font-weight: bold; }
+.warning { color: #a06000; font-weight: bold; }
+";
+
+/// Format the report as a single HTML page, with no external assets.
+///
+/// The page holds the summary, the findings counted by rule and by file, and every
+/// finding with a collapsible snippet of its file, where the statement is highlighted.
+/// Each file is read again once for the lines around its statements, decoded from the
+/// encoding detected in it. The statement alone is shown if that fails.
+pub fn format_html(
+    sum: &Summary,
+    files: &[PathBuf],
+    all_info: &[OnceInfo],
+    failures: &[FileFailure],
+) -> String {
+    let groups = group_by_file(files, all_info, failures);
+    let mut body = String::new();
+
+    body.push_str("<h1>TiKey migration report</h1>\n<h2>Summary</h2>\n");
+    body.push_str(&format!(
+        "<table>\n<tr><th>File Count</th><th>SQL Count</th><th>Clean</th><th>Hidden</th>\
+         <th>Errors</th><th>Warnings</th><th>Suppressed</th><th>Time Cost</th></tr>\n\
+         <tr><td>{}</td><td>{}</td><td>{} ({:.2}%)</td><td>{}</td><td>{}</td><td>{}</td>\
+         <td>{}</td><td>{:?}</td></tr>\n</table>\n",
+        sum.file_count(),
+        sum.sql_count(),
+        sum.clean(),
+        sum.clean_percent(),
+        sum.hidden(),
+        sum.errors(),
+        sum.warnings(),
+        sum.suppressed(),
+        sum.time_cost(),
+    ));
+
+    if !failures.is_empty() {
+        body.push_str("<h2>Failed files</h2>\n<table>\n<tr><th>File</th><th>Reason</th></tr>\n");
+        for failure in failures {
+            body.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td></tr>\n",
+                escape_xml(&failure.file().to_string_lossy()),
+                escape_xml(failure.reason()),
+            ));
+        }
+        body.push_str("</table>\n");
+    }
+
+    // findings and files of each rule, by uid
+    let mut by_rule: BTreeMap<&String, (&RuleInfo, usize, Vec<usize>)> = BTreeMap::new();
+    for (index, group) in groups.iter().enumerate() {
+        for finding in &group.findings {
+            let entry = by_rule
+                .entry(finding.rule.uid())
+                .or_insert((finding.rule, 0, vec![]));
+            entry.1 += 1;
+            if !entry.2.contains(&index) {
+                entry.2.push(index);
+            }
+        }
+    }
+    body.push_str("<h2>By rule</h2>\n");
+    body.push_str(
+        "<table>\n<tr><th>Rule</th><th>Level</th><th>Description</th><th>Findings</th>\
+         <th>Files</th></tr>\n",
+    );
+    for (uid, (rule, count, groups_of_rule)) in &by_rule {
+        body.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            rule_link(uid, rule.url()),
+            level(rule.info_level()),
+            escape_xml(rule.description()),
+            count,
+            groups_of_rule.len(),
+        ));
+    }
+    body.push_str("</table>\n");
+
+    body.push_str("<h2>By file</h2>\n");
+    body.push_str(
+        "<table>\n<tr><th>File</th><th>Errors</th><th>Warnings</th><th>Rules</th></tr>\n",
+    );
+    for (index, group) in groups.iter().enumerate() {
+        let errors = group
+            .findings
+            .iter()
+            .filter(|f| *f.rule.info_level() == InfoLevel::ERROR)
+            .count();
+        let mut uids: Vec<&String> = group.findings.iter().map(|f| f.rule.uid()).collect();
+        uids.sort();
+        uids.dedup();
+        let uids: Vec<String> = uids.into_iter().map(|u| escape_xml(u)).collect();
+        let name = escape_xml(&group.name());
+        let name = if group.findings.is_empty() {
+            name
+        } else {
+            format!("<a href=\"#file-{}\">{}</a>", index, name)
+        };
+        body.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            name,
+            errors,
+            group.findings.len() - errors,
+            uids.join(", "),
+        ));
+    }
+    body.push_str("</table>\n");
+
+    body.push_str("<h2>Findings</h2>\n");
+    for (index, group) in groups.iter().enumerate() {
+        if group.findings.is_empty() {
+            continue;
+        }
+        body.push_str(&format!(
+            "<h3 id=\"file-{}\">{}</h3>\n",
+            index,
+            escape_xml(&group.name())
+        ));
+        let lines = group
+            .file
+            .and_then(|file| context_lines(file, &group.findings));
+        for finding in &group.findings {
+            body.push_str(&format_finding(finding, lines.as_ref()));
+        }
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>TiKey migration report</title>\n<style>\n{}</style>\n</head>\n<body>\n{}</body>\n</html>",
+        STYLE, body
+    )
+}
+
+fn format_finding(finding: &Finding, lines: Option<&BTreeMap<u64, String>>) -> String {
+    let rule = finding.rule;
+    let mut detail = String::new();
+    if let Some(routine) = finding.routine {
+        detail.push_str(&format!("<p>In {}</p>\n", escape_xml(routine)));
+    }
+    let snippet = lines
+        .and_then(|lines| snippet(finding, lines))
+        .unwrap_or_else(|| format!("<mark>{}</mark>", escape_xml(finding.sql)));
+    detail.push_str(&format!("<pre>{}</pre>\n", snippet));
+    if let Some(url) = rule.url() {
+        detail.push_str(&format!(
+            "<p>See <a href=\"{0}\">{0}</a></p>\n",
+            escape_xml(url)
+        ));
+    }
+    format!(
+        "<details>\n<summary>{} {} at {}: {}</summary>\n{}</details>\n",
+        level(rule.info_level()),
+        escape_xml(rule.uid()),
+        finding.span,
+        escape_xml(rule.description()),
+        detail,
+    )
+}
+
+/// The lines of the file shown around its findings, by line number, read in one pass.
+fn context_lines(file: &Path, findings: &[Finding]) -> Option<BTreeMap<u64, String>> {
+    let mut wanted: BTreeSet<u64> = BTreeSet::new();
+    for finding in findings {
+        let span = finding.span;
+        wanted.extend(span.start.line.saturating_sub(CONTEXT_LINES).max(1)..=span.start.line);
+        wanted.extend(span.end.line..=span.end.line + CONTEXT_LINES);
+    }
+    let last = *wanted.last()?;
+    let (_detected, mut reader) = decode_reader(File::open(file).ok()?, None).ok()?;
+    let mut lines = BTreeMap::new();
+    let mut text = String::new();
+    for line in 1..=last {
+        text.clear();
+        if reader.read_line(&mut text).ok()? == 0 {
+            break;
+        }
+        if wanted.contains(&line) {
+            let text = text.strip_suffix('\n').unwrap_or(&text);
+            let text = text.strip_suffix('\r').unwrap_or(text);
+            lines.insert(line, text.to_string());
+        }
+    }
+    Some(lines)
+}
+
+/// The statement with the lines around it in its file, the statement being highlighted.
+fn snippet(finding: &Finding, lines: &BTreeMap<u64, String>) -> Option<String> {
+    let span = finding.span;
+    let start = lines.get(&span.start.line)?;
+    let end = lines.get(&span.end.line)?;
+    let (before, from): (String, String) = {
+        let at = span.start.column as usize - 1;
+        (
+            start.chars().take(at).collect(),
+            start.chars().skip(at).collect(),
+        )
+    };
+    let (to, after): (String, String) = {
+        let at = span.end.column as usize;
+        (
+            end.chars().take(at).collect(),
+            end.chars().skip(at).collect(),
+        )
+    };
+    // the file changed since it was checked, or it was checked in another encoding
+    let mut sql_lines = finding.sql.lines();
+    let held = from.starts_with(sql_lines.next().unwrap_or_default())
+        && to.ends_with(sql_lines.last().unwrap_or(finding.sql));
+    if !held {
+        return None;
+    }
+    let first = span.start.line.saturating_sub(CONTEXT_LINES).max(1);
+    let mut snippet = String::new();
+    for text in lines.range(first..span.start.line).map(|(_, t)| t) {
+        snippet.push_str(&escape_xml(text));
+        snippet.push('\n');
+    }
+    snippet.push_str(&format!(
+        "{}<mark>{}</mark>{}\n",
+        escape_xml(&before),
+        escape_xml(finding.sql),
+        escape_xml(&after)
+    ));
+    let last = span.end.line + CONTEXT_LINES;
+    for text in lines.range(span.end.line + 1..=last).map(|(_, t)| t) {
+        snippet.push_str(&escape_xml(text));
+        snippet.push('\n');
+    }
+    Some(snippet)
+}
+
+fn rule_link(uid: &str, url: &Option<String>) -> String {
+    match url {
+        Some(url) => format!("<a href=\"{}\">{}</a>", escape_xml(url), escape_xml(uid)),
+        None => escape_xml(uid),
+    }
+}
+
+fn level(level: &InfoLevel) -> String {
+    format!("<span class=\"{0}\">{0}</span>", level)
+}
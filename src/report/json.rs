@@ -0,0 +1,121 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use super::{findings, Finding, SCHEMA_VERSION};
+use crate::rules::Version;
+use crate::{BaselineEntry, FileFailure, OnceInfo, Summary, UpgradeDelta};
+
+/// The JSON report, an object of
+///
+/// - `schema_version`: see `SCHEMA_VERSION`
+/// - `summary`: the counts of `Summary`, with `time_cost_ms` in milliseconds
+/// - `upgrade`: only with an upgrade delta, its `from` and `to` versions beside the
+///   `resolved`, `remaining` and `introduced` findings
+/// - `failures`: files failed to check, each of `file` and `reason`
+/// - `fixed`: findings of the baseline no longer found, each of `fingerprint`, `uid`,
+///   `file`, the normalized `sql` and the `count` of times missing, empty without a baseline
+/// - `findings`: all findings, see the module doc
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    schema_version: u32,
+    summary: &'a Summary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    upgrade: Option<UpgradeReport<'a>>,
+    failures: &'a [FileFailure],
+    fixed: &'a [BaselineEntry],
+    findings: Vec<Finding<'a>>,
+}
+
+#[derive(Serialize)]
+struct UpgradeReport<'a> {
+    from: &'a Version,
+    to: &'a Version,
+    resolved: Vec<Finding<'a>>,
+    remaining: Vec<Finding<'a>>,
+    introduced: Vec<Finding<'a>>,
+}
+
+impl<'a> From<&'a UpgradeDelta> for UpgradeReport<'a> {
+    fn from(delta: &'a UpgradeDelta) -> Self {
+        Self {
+            from: delta.from(),
+            to: delta.to(),
+            resolved: findings(delta.resolved()),
+            remaining: findings(delta.remaining()),
+            introduced: findings(delta.introduced()),
+        }
+    }
+}
+
+/// A line of the JSON Lines report, tagged by its `type`.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum JsonLine<'a> {
+    Finding(Finding<'a>),
+    Failure(&'a FileFailure),
+    Fixed(&'a BaselineEntry),
+    Upgrade(UpgradeReport<'a>),
+    Summary {
+        schema_version: u32,
+        #[serde(flatten)]
+        summary: &'a Summary,
+    },
+}
+
+/// Format the report as a pretty printed JSON object.
+pub fn format_json(
+    sum: &Summary,
+    delta: Option<&UpgradeDelta>,
+    all_info: &[OnceInfo],
+    failures: &[FileFailure],
+    fixed: &[BaselineEntry],
+) -> Result<String> {
+    let report = JsonReport {
+        schema_version: SCHEMA_VERSION,
+        summary: sum,
+        upgrade: delta.map(UpgradeReport::from),
+        failures,
+        fixed,
+        findings: findings(all_info),
+    };
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+/// Format the report as JSON Lines, one object per line.
+///
+/// Each finding is a line of type `finding`, followed by the lines of type `failure`,
+/// `fixed` and `upgrade`, while the last line is of type `summary` carrying `schema_version`.
+/// The fields of a line are the same as in `format_json`.
+pub fn format_jsonl(
+    sum: &Summary,
+    delta: Option<&UpgradeDelta>,
+    all_info: &[OnceInfo],
+    failures: &[FileFailure],
+    fixed: &[BaselineEntry],
+) -> Result<Vec<String>> {
+    let lines = findings(all_info)
+        .into_iter()
+        .map(JsonLine::Finding)
+        .chain(failures.iter().map(JsonLine::Failure))
+        .chain(fixed.iter().map(JsonLine::Fixed))
+        .chain(delta.map(|d| JsonLine::Upgrade(d.into())))
+        .chain([JsonLine::Summary {
+            schema_version: SCHEMA_VERSION,
+            summary: sum,
+        }]);
+    let mut formatted = vec![];
+    for line in lines {
+        formatted.push(serde_json::to_string(&line)?);
+    }
+    Ok(formatted)
+}
+
+/// Format the findings only as lines of type `finding` of `format_jsonl`, for a report
+/// written while checking before its closing lines.
+pub fn format_jsonl_findings(all_info: &[OnceInfo]) -> Result<Vec<String>> {
+    let mut formatted = vec![];
+    for finding in findings(all_info) {
+        formatted.push(serde_json::to_string(&JsonLine::Finding(finding))?);
+    }
+    Ok(formatted)
+}
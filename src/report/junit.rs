@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+
+use super::{escape_xml, group_by_file};
+use crate::rules::InfoLevel;
+use crate::{FileFailure, OnceInfo, Summary};
+
+/// Format the report as JUnit XML.
+///
+/// Each checked file is a `testsuite`, with a `testcase` for each of its findings. A finding
+/// of level error is an `error`, and one of level warning a `failure`. A file without any
+/// finding has a single passed test case, while a file failed to check has an errored one.
+pub fn format_junit(
+    sum: &Summary,
+    files: &[PathBuf],
+    all_info: &[OnceInfo],
+    failures: &[FileFailure],
+) -> String {
+    let mut suites = String::new();
+    let (mut total_tests, mut total_failures, mut total_errors) = (0, 0, 0);
+    for group in group_by_file(files, all_info, failures) {
+        let name = escape_xml(&group.name());
+        let mut cases = String::new();
+        let (mut tests, mut failures, mut errors) = (0, 0, 0);
+        for finding in &group.findings {
+            let (element, kind) = match finding.rule.info_level() {
+                InfoLevel::ERROR => {
+                    errors += 1;
+                    ("error", "error")
+                }
+                InfoLevel::WARNING => {
+                    failures += 1;
+                    ("failure", "warning")
+                }
+            };
+            tests += 1;
+            let mut detail = finding.sql.clone();
+            if let Some(url) = finding.rule.url() {
+                detail.push_str(&format!("\n\nSee {}", url));
+            }
+            cases.push_str(&format!(
+                "    <testcase name=\"{} at {}\" classname=\"{}\">\n      \
+                 <{} type=\"{}\" message=\"{}\">{}</{}>\n    </testcase>\n",
+                escape_xml(finding.rule.uid()),
+                finding.span,
+                name,
+                element,
+                kind,
+                escape_xml(finding.rule.description()),
+                escape_xml(&detail),
+                element,
+            ));
+        }
+        if let Some(failure) = group.failure {
+            tests += 1;
+            errors += 1;
+            cases.push_str(&format!(
+                "    <testcase name=\"check\" classname=\"{}\">\n      \
+                 <error type=\"failure\" message=\"{}\"/>\n    </testcase>\n",
+                name,
+                escape_xml(failure.reason()),
+            ));
+        }
+        if tests == 0 {
+            tests += 1;
+            cases.push_str(&format!(
+                "    <testcase name=\"check\" classname=\"{}\"/>\n",
+                name
+            ));
+        }
+        suites.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\">\n{}  </testsuite>\n",
+            name, tests, failures, errors, cases
+        ));
+        total_tests += tests;
+        total_failures += failures;
+        total_errors += errors;
+    }
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuites name=\"TiKey\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.3}\">\n\
+         {}</testsuites>",
+        total_tests,
+        total_failures,
+        total_errors,
+        sum.time_cost().as_secs_f64(),
+        suites
+    )
+}
@@ -0,0 +1,167 @@
+//! Machine readable reports, the text tables are in `display`.
+//!
+//! Beside JSON, the findings are also formatted for other tools, such as SARIF, JUnit
+//! and Checkstyle, or as a HTML page for people.
+//!
+//! Every report is built from the same findings: a finding is one record of an
+//! `OnceInfo`, flattened with the statement it was found in. In JSON a finding is
+//!
+//! ```json
+//! {
+//!   "file": "tests/error/m1.sql",
+//!   "span": { "start": { "line": 1, "column": 1 }, "end": { "line": 3, "column": 2 } },
+//!   "routine": null,
+//!   "sql": "CREATE TABLE ...",
+//!   "kind": "CreateTable",
+//!   "schema": "shop",
+//!   "uid": "m1",
+//!   "level": "error",
+//!   "db_version_range": ["earliest", "6.6.0"],
+//!   "future": "will-support",
+//!   "description": "TiDB not supported FOREIGN KEY constraints",
+//!   "url": "https://github.com/pingcap/tidb/issues/18209",
+//!   "effort": 2
+//! }
+//! ```
+//!
+//! `file` is null for statements not read from a file, and `routine` names the routine
+//! holding the statement in its body. `kind` is the kind of the statement, named after
+//! the sqlparser `Statement` variant, and `schema` is the one set by the last `USE`
+//! before the statement, if any. `level` is `error` or `warning`, a bound of
+//! `db_version_range` is `earliest`, `latest` or a version, `future` is `will-support`
+//! or `no-plan`, and `effort` is the effort weight of the rule.
+//!
+//! The schema only grows by new fields within a `SCHEMA_VERSION`.
+
+mod checkstyle;
+mod html;
+mod json;
+mod junit;
+mod sarif;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+
+use crate::{rules::RuleInfo, FileFailure, OnceInfo, Span};
+
+pub use checkstyle::format_checkstyle;
+pub use html::format_html;
+pub use json::{format_json, format_jsonl, format_jsonl_findings};
+pub use junit::format_junit;
+pub use sarif::format_sarif;
+
+/// Version of the JSON report schema, bumped on any incompatible change.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Finding<'a> {
+    file: Option<&'a PathBuf>,
+    span: &'a Span,
+    routine: Option<&'a String>,
+    sql: &'a String,
+    kind: &'a String,
+    schema: Option<&'a String>,
+    #[serde(flatten)]
+    rule: &'a RuleInfo,
+}
+
+/// All findings of the statements, in the order they were found.
+pub(crate) fn findings(all_info: &[OnceInfo]) -> Vec<Finding<'_>> {
+    all_info
+        .iter()
+        .flat_map(|info| {
+            info.records().iter().map(move |rec| Finding {
+                file: info.file().as_ref(),
+                span: info.span(),
+                routine: info.routine().as_ref(),
+                sql: info.sql(),
+                kind: info.kind(),
+                schema: info.schema().as_ref(),
+                rule: rec,
+            })
+        })
+        .collect()
+}
+
+pub(crate) fn serialize_millis<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u128(d.as_millis())
+}
+
+/// The findings of a checked file, or of the statements not read from a file.
+pub(crate) struct FileReport<'a> {
+    file: Option<&'a PathBuf>,
+    findings: Vec<Finding<'a>>,
+    failure: Option<&'a FileFailure>,
+}
+
+impl FileReport<'_> {
+    /// The path of the file, `<statements>` for the statements not read from a file.
+    fn name(&self) -> String {
+        match self.file {
+            Some(p) => p.to_string_lossy().to_string(),
+            None => "<statements>".to_string(),
+        }
+    }
+}
+
+/// Group the findings and failures by file, in the order of `files`. Files only found in
+/// the findings or failures come after them, and the statements not read from a file last.
+pub(crate) fn group_by_file<'a>(
+    files: &'a [PathBuf],
+    all_info: &'a [OnceInfo],
+    failures: &'a [FileFailure],
+) -> Vec<FileReport<'a>> {
+    let mut groups: Vec<FileReport> = files
+        .iter()
+        .map(|f| FileReport {
+            file: Some(f),
+            findings: vec![],
+            failure: None,
+        })
+        .collect();
+    // the group of each file, by index
+    let mut indexes: HashMap<Option<&PathBuf>, usize> = HashMap::new();
+    for (index, group) in groups.iter().enumerate() {
+        indexes.entry(group.file).or_insert(index);
+    }
+    let mut group_of = |groups: &mut Vec<FileReport<'a>>, file: Option<&'a PathBuf>| {
+        *indexes.entry(file).or_insert_with(|| {
+            groups.push(FileReport {
+                file,
+                findings: vec![],
+                failure: None,
+            });
+            groups.len() - 1
+        })
+    };
+    for finding in findings(all_info) {
+        let index = group_of(&mut groups, finding.file);
+        groups[index].findings.push(finding);
+    }
+    for failure in failures {
+        let index = group_of(&mut groups, Some(failure.file()));
+        groups[index].failure = Some(failure);
+    }
+    groups.sort_by_key(|g| g.file.is_none());
+    groups
+}
+
+pub(crate) fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            // characters not allowed in XML 1.0
+            c if (c as u32) < 0x20 && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
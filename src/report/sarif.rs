@@ -0,0 +1,155 @@
+use std::path::{Component, Path};
+
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use crate::rules::{InfoLevel, RuleInfo};
+use crate::{FileFailure, OnceInfo, RuleRegistry};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Format the report as a SARIF 2.1.0 log of a single run.
+///
+/// Every registered rule is a `reportingDescriptor` of the driver, and every record is a
+/// `result` located in its file, with the statement as the region snippet. Files failed
+/// to check are reported as tool execution notifications.
+pub fn format_sarif(
+    registry: &RuleRegistry,
+    all_info: &[OnceInfo],
+    failures: &[FileFailure],
+) -> Result<String> {
+    let infos = registry.infos();
+    let rules: Vec<Value> = infos
+        .iter()
+        .map(|info| reporting_descriptor(info))
+        .collect();
+    let mut results: Vec<Value> = vec![];
+    for info in all_info {
+        for rec in info.records() {
+            let rule_index = infos.iter().position(|i| i.uid() == rec.uid());
+            results.push(result(info, rec, rule_index));
+        }
+    }
+    let notifications: Vec<Value> = failures
+        .iter()
+        .map(|failure| {
+            json!({
+                "level": "error",
+                "message": { "text": failure.reason() },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": file_uri(failure.file()) },
+                    },
+                }],
+            })
+        })
+        .collect();
+
+    let log = json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "TiKey",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                },
+            },
+            "invocations": [{
+                "executionSuccessful": failures.is_empty(),
+                "toolExecutionNotifications": notifications,
+            }],
+            "results": results,
+        }],
+    });
+    Ok(serde_json::to_string_pretty(&log)?)
+}
+
+fn reporting_descriptor(info: &RuleInfo) -> Value {
+    let (from, to) = info.db_version_range();
+    let mut descriptor = json!({
+        "id": info.uid(),
+        "shortDescription": { "text": info.description() },
+        "defaultConfiguration": { "level": level(info.info_level()) },
+        "properties": {
+            "db_version_range": [from, to],
+            "future": info.future(),
+            "effort": info.effort(),
+        },
+    });
+    if let Some(url) = info.url() {
+        descriptor["helpUri"] = json!(url);
+    }
+    descriptor
+}
+
+fn result(info: &OnceInfo, rec: &RuleInfo, rule_index: Option<usize>) -> Value {
+    let mut result = json!({
+        "ruleId": rec.uid(),
+        "level": level(rec.info_level()),
+        "message": { "text": rec.description() },
+    });
+    if let Some(index) = rule_index {
+        result["ruleIndex"] = json!(index);
+    }
+    if let Some(file) = info.file() {
+        let span = info.span();
+        // the end column of a SARIF region is exclusive
+        let mut location = json!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": file_uri(file) },
+                "region": {
+                    "startLine": span.start.line,
+                    "startColumn": span.start.column,
+                    "endLine": span.end.line,
+                    "endColumn": span.end.column + 1,
+                    "snippet": { "text": info.sql() },
+                },
+            },
+        });
+        if let Some(routine) = info.routine() {
+            location["logicalLocations"] = json!([{ "name": routine, "kind": "function" }]);
+        }
+        result["locations"] = json!([location]);
+    }
+    result
+}
+
+fn level(level: &InfoLevel) -> &'static str {
+    match level {
+        InfoLevel::ERROR => "error",
+        InfoLevel::WARNING => "warning",
+    }
+}
+
+/// A relative path stays a relative reference, an absolute one becomes a `file` URI.
+fn file_uri(path: &Path) -> String {
+    let parts: Vec<String> = path
+        .components()
+        .filter_map(|c| match c {
+            Component::RootDir | Component::CurDir => None,
+            c => Some(percent_encode(&c.as_os_str().to_string_lossy())),
+        })
+        .collect();
+    let joined = parts.join("/");
+    if path.is_absolute() {
+        format!("file:///{}", joined)
+    } else {
+        joined
+    }
+}
+
+fn percent_encode(s: &str) -> String {
+    let mut encoded = String::new();
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' => {
+                encoded.push(b as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,106 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{DBVersion, InfoLevel, RuleFuture, RuleInfo, DEFAULT_EFFORT};
+
+/// A rule defined in a rule file rather than in Rust, e.g.
+///
+/// ```toml
+/// [[rule]]
+/// uid = "c1"
+/// level = "error"
+/// db_version_range = ["earliest", "latest"]
+/// future = "no-plan"
+/// description = "LOAD_FILE is forbidden on our clusters"
+/// effort = 3
+///
+/// [rule.matcher]
+/// paths = ["**.Function.name[].value"]
+/// equals = ["load_file"]
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeclaredRule {
+    uid: String,
+    level: InfoLevel,
+    #[serde(default = "default_version_range")]
+    db_version_range: (DBVersion, DBVersion),
+    future: RuleFuture,
+    description: String,
+    url: Option<String>,
+    #[serde(default = "default_effort")]
+    effort: u32,
+    matcher: Matcher,
+}
+
+/// Matches the values at any of `paths`, see `Trigger::PathMatchJudge` for the pattern syntax.
+///
+/// A value matches if it equals one of `equals` or starts with one of `starts_with`, both
+/// ignoring case. With neither given, anything found at the paths matches.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Matcher {
+    paths: Vec<String>,
+    #[serde(default)]
+    equals: Vec<String>,
+    #[serde(default)]
+    starts_with: Vec<String>,
+}
+
+/// The layout of a rule file, holding any number of `[[rule]]` tables.
+#[derive(Debug, Deserialize)]
+pub struct RuleFile {
+    #[serde(default)]
+    pub rule: Vec<DeclaredRule>,
+}
+
+fn default_version_range() -> (DBVersion, DBVersion) {
+    (DBVersion::Earliest, DBVersion::Latest)
+}
+
+fn default_effort() -> u32 {
+    DEFAULT_EFFORT
+}
+
+impl DeclaredRule {
+    pub fn uid(&self) -> &String {
+        &self.uid
+    }
+
+    pub fn matcher(&self) -> &Matcher {
+        &self.matcher
+    }
+
+    pub fn info(&self) -> RuleInfo {
+        RuleInfo::new(
+            self.uid.clone(),
+            self.level.clone(),
+            self.db_version_range.clone(),
+            self.future.clone(),
+            self.description.clone(),
+            self.url.clone(),
+        )
+        .with_effort(self.effort)
+    }
+}
+
+impl Matcher {
+    pub fn paths(&self) -> &Vec<String> {
+        &self.paths
+    }
+
+    pub fn judge(&self, v: &Value) -> bool {
+        if self.equals.is_empty() && self.starts_with.is_empty() {
+            return true;
+        }
+        let s = match v {
+            Value::String(s) => s.to_lowercase(),
+            Value::Number(n) => n.to_string(),
+            Value::Bool(b) => b.to_string(),
+            _ => return false,
+        };
+        self.equals.iter().any(|e| e.to_lowercase() == s)
+            || self
+                .starts_with
+                .iter()
+                .any(|p| s.starts_with(&p.to_lowercase()))
+    }
+}
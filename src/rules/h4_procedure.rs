@@ -1,5 +1,3 @@
-use serde_json::Value;
-
 use crate::dialect::AddupStatement;
 
 use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
@@ -7,45 +5,12 @@ use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
 pub struct RuleProcedure {}
 
 impl Rule for RuleProcedure {
-    fn uid() -> String {
-        return "h4".to_string();
+    fn uid(&self) -> String {
+        "h4".to_string()
     }
 
     fn trigger(&self) -> Trigger {
-        let judger = |v: &Value| {
-            let v = match v.as_object() {
-                Some(o) => o,
-                None => return false,
-            };
-            let v = match v.get("object_name") {
-                Some(v) => v,
-                None => return false,
-            };
-            let v = match v.as_array() {
-                Some(a) => a,
-                None => return false,
-            };
-            if v.len() != 1 {
-                return false;
-            }
-            let v = match v.first() {
-                Some(e) => e,
-                None => return false,
-            };
-            let v = match v.as_object() {
-                Some(o) => o,
-                None => return false,
-            };
-            let v = match v.get("value") {
-                Some(v) => v,
-                None => return false,
-            };
-            match v.as_str() {
-                Some(s) if s == AddupStatement::CreateProcedure.to_string() => true,
-                _ => return false,
-            }
-        };
-        Trigger::KeyEqualJudge(("Comment".to_string(), judger))
+        Trigger::StatementJudge(|s| AddupStatement::CreateProcedure.matches(s))
     }
 
     fn info(&self) -> RuleInfo {
@@ -59,13 +24,16 @@ impl Rule for RuleProcedure {
 
         let url: Option<String> = None;
 
+        let effort: u32 = 8;
+
         RuleInfo::new(
-            Self::uid(),
+            self.uid(),
             info_level,
             db_version_range,
             future,
             description,
             url,
         )
+        .with_effort(effort)
     }
 }
@@ -1,20 +1,25 @@
-use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
+use sqlparser::ast::Statement;
+
+use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger, Version};
 
 pub struct RuleSavepoint {}
 
 impl Rule for RuleSavepoint {
-    fn uid() -> String {
-        return "h6".to_string();
+    fn uid(&self) -> String {
+        "h6".to_string()
     }
 
     fn trigger(&self) -> Trigger {
-        Trigger::KeyEqual("Savepoint".to_string())
+        Trigger::StatementJudge(|s| matches!(s, Statement::Savepoint { .. }))
     }
 
     fn info(&self) -> RuleInfo {
         let info_level: InfoLevel = InfoLevel::ERROR;
 
-        let db_version_range: (DBVersion, DBVersion) = (DBVersion::Earliest, DBVersion::Latest);
+        let db_version_range: (DBVersion, DBVersion) = (
+            DBVersion::Earliest,
+            DBVersion::Version(Version::new(6, 2, 0)),
+        );
 
         let future: RuleFuture = RuleFuture::WillSupport;
 
@@ -22,13 +27,16 @@ impl Rule for RuleSavepoint {
 
         let url: Option<String> = Some("https://github.com/pingcap/tidb/issues/6840".to_string());
 
+        let effort: u32 = 2;
+
         RuleInfo::new(
-            Self::uid(),
+            self.uid(),
             info_level,
             db_version_range,
             future,
             description,
             url,
         )
+        .with_effort(effort)
     }
 }
@@ -1,20 +1,54 @@
-use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
+use sqlparser::ast::{AlterTableOperation, ColumnOption, Statement, TableConstraint};
+
+use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger, Version};
 
 pub struct RuleForeignKey {}
 
 impl Rule for RuleForeignKey {
-    fn uid() -> String {
-        return "m1".to_string();
+    fn uid(&self) -> String {
+        "m1".to_string()
     }
 
     fn trigger(&self) -> Trigger {
-        Trigger::KeyEqual("ForeignKey".to_string())
+        let judger = |s: &Statement| {
+            let is_foreign_key =
+                |c: &TableConstraint| matches!(c, TableConstraint::ForeignKey { .. });
+            let is_references = |o: &ColumnOption| matches!(o, ColumnOption::ForeignKey { .. });
+            match s {
+                Statement::CreateTable {
+                    columns,
+                    constraints,
+                    ..
+                } => {
+                    constraints.iter().any(is_foreign_key)
+                        || columns
+                            .iter()
+                            .any(|c| c.options.iter().any(|o| is_references(&o.option)))
+                }
+                Statement::AlterTable { operation, .. } => match operation {
+                    AlterTableOperation::AddConstraint(c) => is_foreign_key(c),
+                    AlterTableOperation::AddColumn { column_def } => {
+                        column_def.options.iter().any(|o| is_references(&o.option))
+                    }
+                    AlterTableOperation::ChangeColumn { options, .. } => {
+                        options.iter().any(is_references)
+                    }
+                    _ => false,
+                },
+                _ => false,
+            }
+        };
+
+        Trigger::StatementJudge(judger)
     }
 
     fn info(&self) -> RuleInfo {
         let info_level: InfoLevel = InfoLevel::ERROR;
 
-        let db_version_range: (DBVersion, DBVersion) = (DBVersion::Earliest, DBVersion::Latest);
+        let db_version_range: (DBVersion, DBVersion) = (
+            DBVersion::Earliest,
+            DBVersion::Version(Version::new(6, 6, 0)),
+        );
 
         let future: RuleFuture = RuleFuture::WillSupport;
 
@@ -22,13 +56,16 @@ impl Rule for RuleForeignKey {
 
         let url: Option<String> = Some("https://github.com/pingcap/tidb/issues/18209".to_string());
 
+        let effort: u32 = 2;
+
         RuleInfo::new(
-            Self::uid(),
+            self.uid(),
             info_level,
             db_version_range,
             future,
             description,
             url,
         )
+        .with_effort(effort)
     }
 }
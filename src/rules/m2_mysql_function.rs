@@ -1,14 +1,16 @@
+use sqlparser::ast::Expr;
+
 use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
 
 pub struct RuleMysqlFunc {}
 
 impl Rule for RuleMysqlFunc {
-    fn uid() -> String {
-        return "m2".to_string();
+    fn uid(&self) -> String {
+        "m2".to_string()
     }
 
     fn trigger(&self) -> Trigger {
-        Trigger::KeyEqual("Function".to_string())
+        Trigger::ExprJudge(|e| matches!(e, Expr::Function(_)))
     }
 
     fn info(&self) -> RuleInfo {
@@ -22,13 +24,16 @@ impl Rule for RuleMysqlFunc {
 
         let url: Option<String> = None;
 
+        let effort: u32 = 1;
+
         RuleInfo::new(
-            Self::uid(),
+            self.uid(),
             info_level,
             db_version_range,
             future,
             description,
             url,
         )
+        .with_effort(effort)
     }
 }
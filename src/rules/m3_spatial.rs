@@ -1,21 +1,48 @@
-use serde_json::Value;
+use sqlparser::ast::{AlterTableOperation, DataType, Expr, Statement};
 
 use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
 
+/// A column or table named `geometry` is fine, only the data type is not.
+fn is_geometry(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Custom(name) => name
+            .0
+            .last()
+            .is_some_and(|i| i.value.eq_ignore_ascii_case("geometry")),
+        DataType::Array(inner) => is_geometry(inner),
+        _ => false,
+    }
+}
+
+fn judge_statement(statement: &Statement) -> bool {
+    match statement {
+        Statement::CreateTable { columns, .. } => columns.iter().any(|c| is_geometry(&c.data_type)),
+        Statement::AlterTable { operation, .. } => match operation {
+            AlterTableOperation::AddColumn { column_def } => is_geometry(&column_def.data_type),
+            AlterTableOperation::ChangeColumn { data_type, .. } => is_geometry(data_type),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 pub struct RuleSpatial {}
 
 impl Rule for RuleSpatial {
-    fn uid() -> String {
-        return "m3".to_string();
+    fn uid(&self) -> String {
+        "m3".to_string()
     }
 
     fn trigger(&self) -> Trigger {
-        let judger = |v: &Value| match v.as_str() {
-            Some("geometry") => true,
-            _ => false,
-        };
-
-        Trigger::KeyEqualJudge(("value".to_string(), judger))
+        Trigger::Any(vec![
+            Trigger::StatementJudge(judge_statement),
+            Trigger::ExprJudge(|e| match e {
+                Expr::Cast { data_type, .. }
+                | Expr::TryCast { data_type, .. }
+                | Expr::SafeCast { data_type, .. } => is_geometry(data_type),
+                _ => false,
+            }),
+        ])
     }
 
     fn info(&self) -> RuleInfo {
@@ -29,13 +56,16 @@ impl Rule for RuleSpatial {
 
         let url: Option<String> = Some("https://github.com/pingcap/tidb/issues/6347".to_string());
 
+        let effort: u32 = 5;
+
         RuleInfo::new(
-            Self::uid(),
+            self.uid(),
             info_level,
             db_version_range,
             future,
             description,
             url,
         )
+        .with_effort(effort)
     }
 }
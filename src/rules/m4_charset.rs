@@ -1,22 +1,92 @@
 // sometimes will fallback to `end_early`
+use sqlparser::ast::{
+    AlterTableOperation, ColumnDef, ColumnOption, Expr, ObjectName, Statement, Value,
+};
+
 use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
 
+const CHARSETS: [&str; 36] = [
+    "armscii8", "big5", "cp1250", "cp1251", "cp1256", "cp1257", "cp850", "cp852", "cp866", "cp932",
+    "dec8", "eucjpms", "euckr", "gb18030", "gb2312", "geostd8", "greek", "hp8", "keybcs2", "koi8r",
+    "koi8u", "latin1", "latin2", "latin5", "latin7", "macce", "macroman", "sjis", "swe7", "tis620",
+    "ucs2", "ujis", "utf16", "utf16le", "utf32", "utf8mb3",
+];
+
+/// Whether the charset, or the charset of the collation like `latin1_swedish_ci`, is not
+/// supported.
+fn unsupported(name: &str) -> bool {
+    let charset = name.split('_').next().unwrap_or_default();
+    CHARSETS.iter().any(|c| c.eq_ignore_ascii_case(charset))
+}
+
+fn unsupported_name(name: &ObjectName) -> bool {
+    name.0.last().is_some_and(|i| unsupported(&i.value))
+}
+
+/// Variables like `character_set_client` or `collation_connection`.
+fn is_charset_variable(variable: &ObjectName) -> bool {
+    variable.0.last().is_some_and(|i| {
+        let name = i.value.to_ascii_lowercase();
+        name.starts_with("character_set_") || name.starts_with("collation_")
+    })
+}
+
+fn unsupported_option(option: &ColumnOption) -> bool {
+    matches!(option, ColumnOption::CharacterSet(name) if unsupported_name(name))
+}
+
+fn unsupported_column(column: &ColumnDef) -> bool {
+    column.collation.as_ref().is_some_and(unsupported_name)
+        || column.options.iter().any(|o| unsupported_option(&o.option))
+}
+
+/// Charsets and collations named by the statement itself, not by its expressions.
+fn judge_statement(statement: &Statement) -> bool {
+    match statement {
+        Statement::SetVariable {
+            variable, value, ..
+        } if is_charset_variable(variable) => value.iter().any(|v| match v {
+            Expr::Identifier(ident) => unsupported(&ident.value),
+            Expr::Value(Value::SingleQuotedString(s) | Value::DoubleQuotedString(s)) => {
+                unsupported(s)
+            }
+            _ => false,
+        }),
+        Statement::CreateTable {
+            default_charset,
+            collation,
+            columns,
+            ..
+        } => {
+            default_charset.as_deref().is_some_and(unsupported)
+                || collation.as_deref().is_some_and(unsupported)
+                || columns.iter().any(unsupported_column)
+        }
+        Statement::AlterTable { operation, .. } => match operation {
+            AlterTableOperation::AddColumn { column_def } => unsupported_column(column_def),
+            AlterTableOperation::ChangeColumn { options, .. } => {
+                options.iter().any(unsupported_option)
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 pub struct RuleCharset {}
 
 impl Rule for RuleCharset {
-    fn uid() -> String {
-        return "m4".to_string();
+    fn uid(&self) -> String {
+        "m4".to_string()
     }
 
     fn trigger(&self) -> Trigger {
-        let elems = vec![
-            "armscii8", "big5", "cp1250", "cp1251", "cp1256", "cp1257", "cp850", "cp852", "cp866",
-            "cp932", "dec8", "eucjpms", "euckr", "gb18030", "gb2312", "geostd8", "greek", "greek",
-            "hp8", "keybcs2", "koi8r", "koi8u", "latin1", "latin2", "latin5", "latin7", "macce",
-            "macroman", "sjis", "swe7", "tis620", "ucs2", "ujis", "utf16", "utf16le", "utf32",
-            "utf8mb3",
-        ];
-        Trigger::StringElemEqual(elems.into_iter().map(|s| s.to_string()).collect())
+        Trigger::Any(vec![
+            Trigger::StatementJudge(judge_statement),
+            Trigger::ExprJudge(
+                |e| matches!(e, Expr::Collate { collation, .. } if unsupported_name(collation)),
+            ),
+        ])
     }
 
     fn info(&self) -> RuleInfo {
@@ -30,13 +100,16 @@ impl Rule for RuleCharset {
 
         let url: Option<String> = None;
 
+        let effort: u32 = 1;
+
         RuleInfo::new(
-            Self::uid(),
+            self.uid(),
             info_level,
             db_version_range,
             future,
             description,
             url,
         )
+        .with_effort(effort)
     }
 }
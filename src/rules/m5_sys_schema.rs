@@ -1,44 +1,28 @@
-use serde_json::Value;
+use sqlparser::ast::{Expr, ObjectName};
 
 use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
 
+/// A name in the `sys` schema, like `sys.host_summary_by_statement_type`.
+fn in_sys(name: &ObjectName) -> bool {
+    match &name.0[..] {
+        [schema, _] => schema.value.eq_ignore_ascii_case("sys"),
+        _ => false,
+    }
+}
+
 pub struct RuleSysSchema {}
 
 impl Rule for RuleSysSchema {
-    // "name": Array [Object {"quote_style": Null, "value": String("sys")},
-    // Object {"quote_style": Null, "value": String("host_summary_by_statement_type")}]
-    fn uid() -> String {
-        return "m5".to_string();
+    fn uid(&self) -> String {
+        "m5".to_string()
     }
 
     fn trigger(&self) -> Trigger {
-        let judger = |v: &Value| {
-            let v = match v.as_array() {
-                Some(t) => t,
-                _ => return false,
-            };
-            if v.len() != 2 {
-                return false;
-            }
-            let v = match v.first() {
-                Some(o) => o,
-                None => return false,
-            };
-            let v = match v.as_object() {
-                Some(o) => o,
-                None => return false,
-            };
-            let v = match v.get("value") {
-                Some(s) => s,
-                None => return false,
-            };
-            match v.as_str() {
-                Some("sys") => true,
-                _ => false,
-            }
-        };
-
-        Trigger::KeyEqualJudge(("name".to_string(), judger))
+        // the views of the schema, or its functions like `sys.format_bytes()`
+        Trigger::Any(vec![
+            Trigger::TableJudge(in_sys),
+            Trigger::ExprJudge(|e| matches!(e, Expr::Function(f) if in_sys(&f.name))),
+        ])
     }
 
     fn info(&self) -> RuleInfo {
@@ -52,13 +36,16 @@ impl Rule for RuleSysSchema {
 
         let url: Option<String> = None;
 
+        let effort: u32 = 2;
+
         RuleInfo::new(
-            Self::uid(),
+            self.uid(),
             info_level,
             db_version_range,
             future,
             description,
             url,
         )
+        .with_effort(effort)
     }
 }
@@ -1,22 +1,32 @@
-use serde_json::Value;
+use sqlparser::ast::Statement;
 
 use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
 
 pub struct RuleOptimTrace {}
 
 impl Rule for RuleOptimTrace {
-    // "value": String("OPTIMIZER_TRACE")
-    fn uid() -> String {
-        return "m6".to_string();
+    fn uid(&self) -> String {
+        "m6".to_string()
     }
 
     fn trigger(&self) -> Trigger {
-        let judger = |v: &Value| match v.as_str() {
-            Some("optimizer_trace") => true,
-            _ => false,
-        };
-
-        Trigger::KeyEqualJudge(("value".to_string(), judger))
+        // `SET optimizer_trace = ...` or reading `INFORMATION_SCHEMA.OPTIMIZER_TRACE`
+        Trigger::Any(vec![
+            Trigger::StatementJudge(|s| match s {
+                Statement::SetVariable { variable, .. } => variable
+                    .0
+                    .last()
+                    .is_some_and(|i| i.value.eq_ignore_ascii_case("optimizer_trace")),
+                _ => false,
+            }),
+            Trigger::TableJudge(|name| match &name.0[..] {
+                [schema, table] => {
+                    schema.value.eq_ignore_ascii_case("information_schema")
+                        && table.value.eq_ignore_ascii_case("optimizer_trace")
+                }
+                _ => false,
+            }),
+        ])
     }
 
     fn info(&self) -> RuleInfo {
@@ -30,13 +40,16 @@ impl Rule for RuleOptimTrace {
 
         let url: Option<String> = None;
 
+        let effort: u32 = 1;
+
         RuleInfo::new(
-            Self::uid(),
+            self.uid(),
             info_level,
             db_version_range,
             future,
             description,
             url,
         )
+        .with_effort(effort)
     }
 }
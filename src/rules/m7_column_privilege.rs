@@ -1,64 +1,30 @@
-use serde_json::Value;
+use sqlparser::ast::{Action, Privileges, Statement};
 
 use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
 
 pub struct RuleColPriv {}
 
 impl Rule for RuleColPriv {
-    // "Grant": Object{"privileges": Object {"Actions": Array [Object {"Select":..}]}}
-    fn uid() -> String {
-        return "m7".to_string();
+    fn uid(&self) -> String {
+        "m7".to_string()
     }
 
     fn trigger(&self) -> Trigger {
-        let judger = |v: &Value| {
-            let v = match v.as_object() {
-                Some(t) => t,
-                _ => return false,
-            };
-
-            let v = match v.get("privileges") {
-                Some(o) => o,
-                None => return false,
-            };
-            let v = match v.as_object() {
-                Some(o) => o,
-                None => return false,
-            };
-            let v = match v.get("Actions") {
-                Some(s) => s,
-                None => return false,
-            };
-            let v = match v.as_array() {
-                Some(t) => t,
-                _ => return false,
-            };
-            if v.len() != 1 {
-                return false;
-            }
-            let v = match v.first() {
-                Some(t) => t,
-                _ => return false,
-            };
-            let v = match v.as_object() {
-                Some(t) => t,
-                _ => return false,
-            };
-            let v = match v.get("Select") {
-                Some(s) => s,
-                None => return false,
-            };
-            let v = match v.as_object() {
-                Some(t) => t,
-                _ => return false,
-            };
-            match v.get("columns") {
-                Some(_) => true,
-                None => false,
-            }
+        let judger = |s: &Statement| match s {
+            Statement::Grant {
+                privileges: Privileges::Actions(actions),
+                ..
+            } => actions.iter().any(|a| match a {
+                Action::Insert { columns }
+                | Action::References { columns }
+                | Action::Select { columns }
+                | Action::Update { columns } => columns.is_some(),
+                _ => false,
+            }),
+            _ => false,
         };
 
-        Trigger::KeyEqualJudge(("Grant".to_string(), judger))
+        Trigger::StatementJudge(judger)
     }
 
     fn info(&self) -> RuleInfo {
@@ -72,13 +38,16 @@ impl Rule for RuleColPriv {
 
         let url: Option<String> = Some("https://github.com/pingcap/tidb/issues/9766".to_string());
 
+        let effort: u32 = 2;
+
         RuleInfo::new(
-            Self::uid(),
+            self.uid(),
             info_level,
             db_version_range,
             future,
             description,
             url,
         )
+        .with_effort(effort)
     }
 }
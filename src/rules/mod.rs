@@ -1,3 +1,4 @@
+mod declared;
 mod h1_function;
 mod h2_trigger;
 mod h3_event;
@@ -15,7 +16,11 @@ mod m7_column_privilege;
 mod s1_unknown;
 mod s2_delimiter;
 mod s3_end_early;
+mod s4_parse_error;
+mod s5_encoding;
+mod version;
 
+pub use declared::{DeclaredRule, Matcher, RuleFile};
 pub use h1_function::RuleFunction;
 pub use h2_trigger::RuleTrigger;
 pub use h3_event::RuleEvent;
@@ -33,72 +38,134 @@ pub use m7_column_privilege::RuleColPriv;
 pub use s1_unknown::RuleUnknown;
 pub use s2_delimiter::RuleDelimiter;
 pub use s3_end_early::RuleEndEarly;
+pub use s4_parse_error::RuleParseError;
+pub use s5_encoding::RuleEncoding;
+pub use version::Version;
 
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Value;
+use sqlparser::ast::{Expr, ObjectName, Statement};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum InfoLevel {
     WARNING,
     ERROR,
 }
 
-impl ToString for InfoLevel {
-    fn to_string(&self) -> String {
-        match self {
-            InfoLevel::WARNING => "warning".to_string(),
-            InfoLevel::ERROR => "error".to_string(),
-        }
+impl fmt::Display for InfoLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            InfoLevel::WARNING => "warning",
+            InfoLevel::ERROR => "error",
+        };
+        f.write_str(s)
     }
 }
 
-#[derive(Debug, Clone)]
+/// A bound of the TiDB versions a rule applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
 pub enum DBVersion {
     Earliest,
     Latest,
-    Version(String),
+    Version(Version),
+}
+
+impl FromStr for DBVersion {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "earliest" => Ok(Self::Earliest),
+            "latest" => Ok(Self::Latest),
+            _ => s.parse().map(Self::Version),
+        }
+    }
+}
+
+impl TryFrom<String> for DBVersion {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> anyhow::Result<Self> {
+        s.parse()
+    }
+}
+
+impl Serialize for DBVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
 }
 
-impl ToString for DBVersion {
-    fn to_string(&self) -> String {
+impl fmt::Display for DBVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            DBVersion::Earliest => "earliest".to_string(),
-            DBVersion::Latest => "latest".to_string(),
-            DBVersion::Version(v) => v.clone(),
+            DBVersion::Earliest => f.write_str("earliest"),
+            DBVersion::Latest => f.write_str("latest"),
+            DBVersion::Version(v) => v.fmt(f),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum RuleFuture {
     WillSupport,
     NoPlan,
 }
 
-impl ToString for RuleFuture {
-    fn to_string(&self) -> String {
-        match self {
-            RuleFuture::WillSupport => "have a plan to support".to_string(),
-            RuleFuture::NoPlan => "no plan to support".to_string(),
-        }
+impl fmt::Display for RuleFuture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            RuleFuture::WillSupport => "have a plan to support",
+            RuleFuture::NoPlan => "no plan to support",
+        };
+        f.write_str(s)
     }
 }
 
+/// Judge a value by its path from the statement root and the value itself.
+pub type PathJudger = fn(&str, &Value) -> bool;
+
 pub enum Trigger {
     KeyEqual(String),
     KeyEqualJudge((String, fn(&Value) -> bool)),
     StringElemEqual(Vec<String>),
+    /// Judge the values whose path from the statement root matches any of the patterns.
+    ///
+    /// A path is made of keys joined by `.`, with `[]` appended to a key holding an array,
+    /// e.g. `CreateTable.columns[].data_type.Custom[].value`. In a pattern, `*` matches any
+    /// single key and `**` matches any number of keys. The judge gets the full path.
+    PathMatchJudge((Vec<String>, PathJudger)),
+    /// Judge every statement, including the ones nested in `EXPLAIN` or `PREPARE`.
+    StatementJudge(fn(&Statement) -> bool),
+    /// Judge every expression, one finding is reported for each matched expression.
+    ExprJudge(fn(&Expr) -> bool),
+    /// Judge the name of every table read or written, as in `FROM` or `INSERT INTO`.
+    TableJudge(fn(&ObjectName) -> bool),
+    /// Judge by each of the triggers, e.g. both statements and expressions.
+    Any(Vec<Trigger>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RuleInfo {
     uid: String,
+    #[serde(rename = "level")]
     info_level: InfoLevel,
     db_version_range: (DBVersion, DBVersion),
     future: RuleFuture,
     description: String,
     url: Option<String>,
+    effort: u32,
 }
 
+/// Effort weight of a rule not giving its own.
+pub const DEFAULT_EFFORT: u32 = 1;
+
 impl RuleInfo {
     pub fn new(
         uid: String,
@@ -115,32 +182,88 @@ impl RuleInfo {
             future,
             description,
             url,
+            effort: DEFAULT_EFFORT,
         }
     }
 
+    /// Set the effort weight, the relative cost to migrate one finding of the rule.
+    pub fn with_effort(mut self, effort: u32) -> Self {
+        self.effort = effort;
+        self
+    }
+
     pub fn uid(&self) -> &String {
-        return &self.uid;
+        &self.uid
     }
 
     pub fn info_level(&self) -> &InfoLevel {
-        return &self.info_level;
+        &self.info_level
+    }
+
+    pub(crate) fn set_info_level(&mut self, info_level: InfoLevel) {
+        self.info_level = info_level;
     }
+
     pub fn db_version_range(&self) -> &(DBVersion, DBVersion) {
-        return &self.db_version_range;
+        &self.db_version_range
+    }
+
+    /// Whether the rule applies to the TiDB version, the range includes its start
+    /// and excludes its end, i.e. the end is the first version the feature works on.
+    pub fn applies_to(&self, version: &Version) -> bool {
+        let (from, to) = &self.db_version_range;
+        let after_from = match from {
+            DBVersion::Earliest => true,
+            DBVersion::Latest => false,
+            DBVersion::Version(v) => v <= version,
+        };
+        let before_to = match to {
+            DBVersion::Earliest => false,
+            DBVersion::Latest => true,
+            DBVersion::Version(v) => version < v,
+        };
+        after_from && before_to
     }
+
     pub fn future(&self) -> &RuleFuture {
-        return &self.future;
+        &self.future
     }
     pub fn description(&self) -> &String {
-        return &self.description;
+        &self.description
+    }
+
+    pub(crate) fn set_description(&mut self, description: String) {
+        self.description = description;
     }
     pub fn url(&self) -> &Option<String> {
-        return &self.url;
+        &self.url
+    }
+
+    pub fn effort(&self) -> &u32 {
+        &self.effort
+    }
+
+    pub(crate) fn set_effort(&mut self, effort: u32) {
+        self.effort = effort;
     }
 }
 
 pub trait Rule {
-    fn uid() -> String;
+    fn uid(&self) -> String;
     fn trigger(&self) -> Trigger;
     fn info(&self) -> RuleInfo;
 }
+
+impl<R: Rule + ?Sized> Rule for Box<R> {
+    fn uid(&self) -> String {
+        (**self).uid()
+    }
+
+    fn trigger(&self) -> Trigger {
+        (**self).trigger()
+    }
+
+    fn info(&self) -> RuleInfo {
+        (**self).info()
+    }
+}
@@ -0,0 +1,40 @@
+use crate::dialect::AddupStatement;
+
+use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
+
+pub struct RuleParseError {}
+
+impl Rule for RuleParseError {
+    fn uid(&self) -> String {
+        "s4".to_string()
+    }
+
+    fn trigger(&self) -> Trigger {
+        Trigger::StatementJudge(|s| AddupStatement::ParseError.matches(s))
+    }
+
+    fn info(&self) -> RuleInfo {
+        let info_level: InfoLevel = InfoLevel::WARNING;
+
+        let db_version_range: (DBVersion, DBVersion) = (DBVersion::Earliest, DBVersion::Latest);
+
+        let future: RuleFuture = RuleFuture::NoPlan;
+
+        let description: String =
+            "Tikey failed to parse the statement, it is skipped from checking".to_string();
+
+        let url: Option<String> = None;
+
+        let effort: u32 = 1;
+
+        RuleInfo::new(
+            self.uid(),
+            info_level,
+            db_version_range,
+            future,
+            description,
+            url,
+        )
+        .with_effort(effort)
+    }
+}
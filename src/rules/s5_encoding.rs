@@ -0,0 +1,41 @@
+use crate::dialect::AddupStatement;
+
+use super::{DBVersion, InfoLevel, Rule, RuleFuture, RuleInfo, Trigger};
+
+pub struct RuleEncoding {}
+
+impl Rule for RuleEncoding {
+    fn uid(&self) -> String {
+        "s5".to_string()
+    }
+
+    fn trigger(&self) -> Trigger {
+        Trigger::StatementJudge(|s| AddupStatement::Encoding.matches(s))
+    }
+
+    fn info(&self) -> RuleInfo {
+        let info_level: InfoLevel = InfoLevel::WARNING;
+
+        let db_version_range: (DBVersion, DBVersion) = (DBVersion::Earliest, DBVersion::Latest);
+
+        let future: RuleFuture = RuleFuture::NoPlan;
+
+        let description: String =
+            "The file is not encoded in UTF-8, it is decoded from the detected encoding"
+                .to_string();
+
+        let url: Option<String> = None;
+
+        let effort: u32 = 1;
+
+        RuleInfo::new(
+            self.uid(),
+            info_level,
+            db_version_range,
+            future,
+            description,
+            url,
+        )
+        .with_effort(effort)
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use serde::{Serialize, Serializer};
+
+/// A TiDB release such as `6.5.0`, compared by its numbers rather than as text.
+///
+/// A leading `v` is accepted and missing parts default to zero, so `v6.5` is `6.5.0`.
+/// Pre-release and build suffixes like `-alpha` are dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl Version {
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    pub fn major(&self) -> &u64 {
+        &self.major
+    }
+
+    pub fn minor(&self) -> &u64 {
+        &self.minor
+    }
+
+    pub fn patch(&self) -> &u64 {
+        &self.patch
+    }
+}
+
+impl FromStr for Version {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || anyhow!("invalid version `{}`, expect one like `6.5.0`", s);
+        let trimmed = s.trim();
+        let trimmed = trimmed
+            .strip_prefix('v')
+            .or_else(|| trimmed.strip_prefix('V'))
+            .unwrap_or(trimmed);
+        let numbers = match trimmed.split_once(['-', '+']) {
+            Some((numbers, _suffix)) => numbers,
+            None => trimmed,
+        };
+        let parts = numbers
+            .split('.')
+            .map(|p| p.parse::<u64>().map_err(|_| invalid()))
+            .collect::<Result<Vec<u64>>>()?;
+        match parts[..] {
+            [major] => Ok(Self::new(major, 0, 0)),
+            [major, minor] => Ok(Self::new(major, minor, 0)),
+            [major, minor, patch] => Ok(Self::new(major, minor, patch)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
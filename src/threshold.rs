@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use clap::ValueEnum;
+
+use crate::rules::InfoLevel;
+use crate::{RuleRegistry, Summary};
+
+/// The lowest level of findings failing a check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum FailOn {
+    /// fail on errors only
+    #[default]
+    Error,
+    /// fail on errors or warnings
+    Warning,
+    /// never fail on a level, only on the maximum of a rule
+    Never,
+}
+
+/// Decide whether the findings of a check fail it.
+///
+/// Findings of a level failing the check are allowed up to the maximum of that level,
+/// which is zero by default. A maximum of a rule applies to its findings whatever the level.
+#[derive(Debug, Clone, Default)]
+pub struct Thresholds {
+    fail_on: FailOn,
+    max_errors: u128,
+    max_warnings: u128,
+    max_rules: BTreeMap<String, u128>,
+}
+
+impl Thresholds {
+    pub fn new(fail_on: FailOn) -> Self {
+        Self {
+            fail_on,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_errors(mut self, max: u128) -> Self {
+        self.max_errors = max;
+        self
+    }
+
+    pub fn with_max_warnings(mut self, max: u128) -> Self {
+        self.max_warnings = max;
+        self
+    }
+
+    pub fn with_max_rule(mut self, uid: String, max: u128) -> Self {
+        self.max_rules.insert(uid, max);
+        self
+    }
+
+    /// Make sure every rule with a maximum is registered, to catch typos.
+    pub fn validate(&self, registry: &RuleRegistry) -> Result<()> {
+        let uids = registry.uids();
+        for uid in self.max_rules.keys() {
+            if !uids.contains(&uid) {
+                return Err(anyhow!("unknown rule `{}` in maximum", uid));
+            }
+        }
+        Ok(())
+    }
+
+    /// The reasons the findings fail the check, empty if they pass it.
+    pub fn exceeded(&self, summary: &Summary) -> Vec<String> {
+        let mut reasons = vec![];
+        let levels = match self.fail_on {
+            FailOn::Error => vec![InfoLevel::ERROR],
+            FailOn::Warning => vec![InfoLevel::ERROR, InfoLevel::WARNING],
+            FailOn::Never => vec![],
+        };
+        for level in levels {
+            let (count, max) = match level {
+                InfoLevel::ERROR => (*summary.errors(), self.max_errors),
+                InfoLevel::WARNING => (*summary.warnings(), self.max_warnings),
+            };
+            if count > max {
+                reasons.push(format!("{} {}s, more than {} allowed", count, level, max));
+            }
+        }
+        for (uid, max) in &self.max_rules {
+            let count = summary.by_rule().get(uid).copied().unwrap_or_default();
+            if count > *max {
+                reasons.push(format!(
+                    "{} findings of rule {}, more than {} allowed",
+                    count, uid, max
+                ));
+            }
+        }
+        reasons
+    }
+}
@@ -0,0 +1,501 @@
+use sqlparser::ast::{
+    AlterTableOperation, Assignment, ColumnDef, ColumnOption, Expr, FunctionArg, FunctionArgExpr,
+    JoinConstraint, JoinOperator, MergeClause, ObjectName, OnInsert, OrderByExpr, Query, Select,
+    SelectItem, SetExpr, ShowStatementFilter, Statement, TableConstraint, TableFactor,
+    TableWithJoins, WindowFrameBound,
+};
+
+/// Receives the typed nodes of a statement, in the order they are met by `walk_statement`.
+pub trait Visitor {
+    fn visit_statement(&mut self, _statement: &Statement) {}
+    fn visit_expr(&mut self, _expr: &Expr) {}
+    /// The name of a table read or written, as in `FROM` or `INSERT INTO`.
+    fn visit_table(&mut self, _name: &ObjectName) {}
+}
+
+/// Visit the statement, then every statement and expression nested in it.
+pub fn walk_statement<V: Visitor>(visitor: &mut V, statement: &Statement) {
+    visitor.visit_statement(statement);
+    match statement {
+        Statement::Analyze { partitions, .. } | Statement::Truncate { partitions, .. } => {
+            if let Some(partitions) = partitions {
+                walk_exprs(visitor, partitions);
+            }
+        }
+        Statement::Query(query) => walk_query(visitor, query),
+        Statement::Insert {
+            table_name,
+            source,
+            partitioned,
+            on,
+            ..
+        } => {
+            visitor.visit_table(table_name);
+            if let Some(partitioned) = partitioned {
+                walk_exprs(visitor, partitioned);
+            }
+            walk_query(visitor, source);
+            if let Some(OnInsert::DuplicateKeyUpdate(assignments)) = on {
+                walk_assignments(visitor, assignments);
+            }
+        }
+        Statement::Directory { source, .. } => walk_query(visitor, source),
+        Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+        } => {
+            walk_table_with_joins(visitor, table);
+            walk_assignments(visitor, assignments);
+            if let Some(from) = from {
+                walk_table_with_joins(visitor, from);
+            }
+            walk_opt_expr(visitor, selection);
+        }
+        Statement::Delete {
+            table_name,
+            using,
+            selection,
+        } => {
+            walk_table_factor(visitor, table_name);
+            if let Some(using) = using {
+                walk_table_factor(visitor, using);
+            }
+            walk_opt_expr(visitor, selection);
+        }
+        Statement::CreateView { query, .. } | Statement::Declare { query, .. } => {
+            walk_query(visitor, query)
+        }
+        Statement::CreateTable {
+            columns,
+            constraints,
+            query,
+            ..
+        } => {
+            for column in columns {
+                walk_column_def(visitor, column);
+            }
+            for constraint in constraints {
+                walk_table_constraint(visitor, constraint);
+            }
+            if let Some(query) = query {
+                walk_query(visitor, query);
+            }
+        }
+        Statement::CreateIndex { columns, .. } => walk_order_by(visitor, columns),
+        Statement::CreateRole {
+            connection_limit,
+            valid_until,
+            ..
+        } => {
+            walk_opt_expr(visitor, connection_limit);
+            walk_opt_expr(visitor, valid_until);
+        }
+        Statement::AlterTable { operation, .. } => walk_alter_table(visitor, operation),
+        Statement::SetVariable { value, .. } => walk_exprs(visitor, value),
+        Statement::ShowFunctions { filter }
+        | Statement::ShowVariables { filter }
+        | Statement::ShowColumns { filter, .. }
+        | Statement::ShowTables { filter, .. }
+        | Statement::ShowCollation { filter } => {
+            if let Some(ShowStatementFilter::Where(expr)) = filter {
+                walk_expr(visitor, expr);
+            }
+        }
+        Statement::Assert { condition, message } => {
+            walk_expr(visitor, condition);
+            walk_opt_expr(visitor, message);
+        }
+        Statement::Execute { parameters, .. } => walk_exprs(visitor, parameters),
+        Statement::Prepare { statement, .. } | Statement::Explain { statement, .. } => {
+            walk_statement(visitor, statement)
+        }
+        Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } => {
+            walk_table_factor(visitor, table);
+            walk_table_factor(visitor, source);
+            walk_expr(visitor, on);
+            for clause in clauses {
+                match clause {
+                    MergeClause::MatchedUpdate {
+                        predicate,
+                        assignments,
+                    } => {
+                        walk_opt_expr(visitor, predicate);
+                        walk_assignments(visitor, assignments);
+                    }
+                    MergeClause::MatchedDelete(predicate) => walk_opt_expr(visitor, predicate),
+                    MergeClause::NotMatched {
+                        predicate, values, ..
+                    } => {
+                        walk_opt_expr(visitor, predicate);
+                        for row in &values.0 {
+                            walk_exprs(visitor, row);
+                        }
+                    }
+                }
+            }
+        }
+        Statement::Cache {
+            query: Some(query), ..
+        } => walk_query(visitor, query),
+        _ => {}
+    }
+}
+
+fn walk_query<V: Visitor>(visitor: &mut V, query: &Query) {
+    if let Some(with) = &query.with {
+        for cte in &with.cte_tables {
+            walk_query(visitor, &cte.query);
+        }
+    }
+    walk_set_expr(visitor, &query.body);
+    walk_order_by(visitor, &query.order_by);
+    walk_opt_expr(visitor, &query.limit);
+    if let Some(offset) = &query.offset {
+        walk_expr(visitor, &offset.value);
+    }
+    if let Some(fetch) = &query.fetch {
+        walk_opt_expr(visitor, &fetch.quantity);
+    }
+}
+
+fn walk_set_expr<V: Visitor>(visitor: &mut V, set_expr: &SetExpr) {
+    match set_expr {
+        SetExpr::Select(select) => walk_select(visitor, select),
+        SetExpr::Query(query) => walk_query(visitor, query),
+        SetExpr::SetOperation { left, right, .. } => {
+            walk_set_expr(visitor, left);
+            walk_set_expr(visitor, right);
+        }
+        SetExpr::Values(values) => {
+            for row in &values.0 {
+                walk_exprs(visitor, row);
+            }
+        }
+        SetExpr::Insert(statement) => walk_statement(visitor, statement),
+    }
+}
+
+fn walk_select<V: Visitor>(visitor: &mut V, select: &Select) {
+    if let Some(top) = &select.top {
+        walk_opt_expr(visitor, &top.quantity);
+    }
+    for item in &select.projection {
+        match item {
+            SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                walk_expr(visitor, expr)
+            }
+            SelectItem::QualifiedWildcard(_) | SelectItem::Wildcard => {}
+        }
+    }
+    for table in &select.from {
+        walk_table_with_joins(visitor, table);
+    }
+    for view in &select.lateral_views {
+        walk_expr(visitor, &view.lateral_view);
+    }
+    walk_opt_expr(visitor, &select.selection);
+    walk_exprs(visitor, &select.group_by);
+    walk_exprs(visitor, &select.cluster_by);
+    walk_exprs(visitor, &select.distribute_by);
+    walk_exprs(visitor, &select.sort_by);
+    walk_opt_expr(visitor, &select.having);
+    walk_opt_expr(visitor, &select.qualify);
+}
+
+fn walk_table_with_joins<V: Visitor>(visitor: &mut V, table: &TableWithJoins) {
+    walk_table_factor(visitor, &table.relation);
+    for join in &table.joins {
+        walk_table_factor(visitor, &join.relation);
+        match &join.join_operator {
+            JoinOperator::Inner(constraint)
+            | JoinOperator::LeftOuter(constraint)
+            | JoinOperator::RightOuter(constraint)
+            | JoinOperator::FullOuter(constraint) => {
+                if let JoinConstraint::On(expr) = constraint {
+                    walk_expr(visitor, expr);
+                }
+            }
+            JoinOperator::CrossJoin | JoinOperator::CrossApply | JoinOperator::OuterApply => {}
+        }
+    }
+}
+
+fn walk_table_factor<V: Visitor>(visitor: &mut V, factor: &TableFactor) {
+    match factor {
+        TableFactor::Table {
+            name,
+            args,
+            with_hints,
+            ..
+        } => {
+            visitor.visit_table(name);
+            if let Some(args) = args {
+                walk_function_args(visitor, args);
+            }
+            walk_exprs(visitor, with_hints);
+        }
+        TableFactor::Derived { subquery, .. } => walk_query(visitor, subquery),
+        TableFactor::TableFunction { expr, .. } => walk_expr(visitor, expr),
+        TableFactor::UNNEST { array_expr, .. } => walk_expr(visitor, array_expr),
+        TableFactor::NestedJoin {
+            table_with_joins, ..
+        } => walk_table_with_joins(visitor, table_with_joins),
+    }
+}
+
+fn walk_column_def<V: Visitor>(visitor: &mut V, column: &ColumnDef) {
+    for option in &column.options {
+        walk_column_option(visitor, &option.option);
+    }
+}
+
+fn walk_column_option<V: Visitor>(visitor: &mut V, option: &ColumnOption) {
+    if let ColumnOption::Default(expr) | ColumnOption::Check(expr) = option {
+        walk_expr(visitor, expr);
+    }
+}
+
+fn walk_table_constraint<V: Visitor>(visitor: &mut V, constraint: &TableConstraint) {
+    if let TableConstraint::Check { expr, .. } = constraint {
+        walk_expr(visitor, expr);
+    }
+}
+
+fn walk_alter_table<V: Visitor>(visitor: &mut V, operation: &AlterTableOperation) {
+    match operation {
+        AlterTableOperation::AddConstraint(constraint) => {
+            walk_table_constraint(visitor, constraint)
+        }
+        AlterTableOperation::AddColumn { column_def } => walk_column_def(visitor, column_def),
+        AlterTableOperation::RenamePartitions {
+            old_partitions,
+            new_partitions,
+        } => {
+            walk_exprs(visitor, old_partitions);
+            walk_exprs(visitor, new_partitions);
+        }
+        AlterTableOperation::AddPartitions { new_partitions, .. } => {
+            walk_exprs(visitor, new_partitions)
+        }
+        AlterTableOperation::DropPartitions { partitions, .. } => walk_exprs(visitor, partitions),
+        AlterTableOperation::ChangeColumn { options, .. } => {
+            for option in options {
+                walk_column_option(visitor, option);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn walk_assignments<V: Visitor>(visitor: &mut V, assignments: &[Assignment]) {
+    for assignment in assignments {
+        walk_expr(visitor, &assignment.value);
+    }
+}
+
+fn walk_order_by<V: Visitor>(visitor: &mut V, order_by: &[OrderByExpr]) {
+    for order in order_by {
+        walk_expr(visitor, &order.expr);
+    }
+}
+
+fn walk_function_args<V: Visitor>(visitor: &mut V, args: &[FunctionArg]) {
+    for arg in args {
+        match arg {
+            FunctionArg::Named { arg, .. } | FunctionArg::Unnamed(arg) => {
+                if let FunctionArgExpr::Expr(expr) = arg {
+                    walk_expr(visitor, expr);
+                }
+            }
+        }
+    }
+}
+
+fn walk_exprs<V: Visitor>(visitor: &mut V, exprs: &[Expr]) {
+    for expr in exprs {
+        walk_expr(visitor, expr);
+    }
+}
+
+fn walk_opt_expr<V: Visitor>(visitor: &mut V, expr: &Option<Expr>) {
+    if let Some(expr) = expr {
+        walk_expr(visitor, expr);
+    }
+}
+
+/// Visit the expression, then its sub-expressions and subqueries.
+pub fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Expr) {
+    visitor.visit_expr(expr);
+    match expr {
+        Expr::JsonAccess { left, right, .. } => {
+            walk_expr(visitor, left);
+            walk_expr(visitor, right);
+        }
+        Expr::CompositeAccess { expr, .. }
+        | Expr::IsFalse(expr)
+        | Expr::IsNotFalse(expr)
+        | Expr::IsTrue(expr)
+        | Expr::IsNotTrue(expr)
+        | Expr::IsNull(expr)
+        | Expr::IsNotNull(expr)
+        | Expr::IsUnknown(expr)
+        | Expr::IsNotUnknown(expr)
+        | Expr::AnyOp(expr)
+        | Expr::AllOp(expr)
+        | Expr::UnaryOp { expr, .. }
+        | Expr::Cast { expr, .. }
+        | Expr::TryCast { expr, .. }
+        | Expr::SafeCast { expr, .. }
+        | Expr::Extract { expr, .. }
+        | Expr::Ceil { expr, .. }
+        | Expr::Floor { expr, .. }
+        | Expr::Collate { expr, .. }
+        | Expr::Nested(expr) => walk_expr(visitor, expr),
+        Expr::AtTimeZone { timestamp, .. } => walk_expr(visitor, timestamp),
+        Expr::IsDistinctFrom(left, right)
+        | Expr::IsNotDistinctFrom(left, right)
+        | Expr::BinaryOp { left, right, .. }
+        | Expr::Position {
+            expr: left,
+            r#in: right,
+        }
+        | Expr::AggregateExpressionWithFilter {
+            expr: left,
+            filter: right,
+        } => {
+            walk_expr(visitor, left);
+            walk_expr(visitor, right);
+        }
+        Expr::InList { expr, list, .. } => {
+            walk_expr(visitor, expr);
+            walk_exprs(visitor, list);
+        }
+        Expr::InSubquery { expr, subquery, .. } => {
+            walk_expr(visitor, expr);
+            walk_query(visitor, subquery);
+        }
+        Expr::InUnnest {
+            expr, array_expr, ..
+        } => {
+            walk_expr(visitor, expr);
+            walk_expr(visitor, array_expr);
+        }
+        Expr::Between {
+            expr, low, high, ..
+        } => {
+            walk_expr(visitor, expr);
+            walk_expr(visitor, low);
+            walk_expr(visitor, high);
+        }
+        Expr::Like { expr, pattern, .. }
+        | Expr::ILike { expr, pattern, .. }
+        | Expr::SimilarTo { expr, pattern, .. } => {
+            walk_expr(visitor, expr);
+            walk_expr(visitor, pattern);
+        }
+        Expr::Substring {
+            expr,
+            substring_from,
+            substring_for,
+        } => {
+            walk_expr(visitor, expr);
+            if let Some(from) = substring_from {
+                walk_expr(visitor, from);
+            }
+            if let Some(count) = substring_for {
+                walk_expr(visitor, count);
+            }
+        }
+        Expr::Trim {
+            expr, trim_what, ..
+        } => {
+            walk_expr(visitor, expr);
+            if let Some(what) = trim_what {
+                walk_expr(visitor, what);
+            }
+        }
+        Expr::Overlay {
+            expr,
+            overlay_what,
+            overlay_from,
+            overlay_for,
+        } => {
+            walk_expr(visitor, expr);
+            walk_expr(visitor, overlay_what);
+            walk_expr(visitor, overlay_from);
+            if let Some(count) = overlay_for {
+                walk_expr(visitor, count);
+            }
+        }
+        Expr::MapAccess { column, keys } => {
+            walk_expr(visitor, column);
+            walk_exprs(visitor, keys);
+        }
+        Expr::Function(function) => {
+            walk_function_args(visitor, &function.args);
+            if let Some(over) = &function.over {
+                walk_exprs(visitor, &over.partition_by);
+                walk_order_by(visitor, &over.order_by);
+                if let Some(frame) = &over.window_frame {
+                    let bounds = std::iter::once(&frame.start_bound).chain(&frame.end_bound);
+                    for bound in bounds {
+                        if let WindowFrameBound::Preceding(Some(expr))
+                        | WindowFrameBound::Following(Some(expr)) = bound
+                        {
+                            walk_expr(visitor, expr);
+                        }
+                    }
+                }
+            }
+        }
+        Expr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => {
+            if let Some(operand) = operand {
+                walk_expr(visitor, operand);
+            }
+            walk_exprs(visitor, conditions);
+            walk_exprs(visitor, results);
+            if let Some(else_result) = else_result {
+                walk_expr(visitor, else_result);
+            }
+        }
+        Expr::Exists { subquery, .. }
+        | Expr::Subquery(subquery)
+        | Expr::ArraySubquery(subquery) => walk_query(visitor, subquery),
+        Expr::ListAgg(list_agg) => {
+            walk_expr(visitor, &list_agg.expr);
+            if let Some(separator) = &list_agg.separator {
+                walk_expr(visitor, separator);
+            }
+            walk_order_by(visitor, &list_agg.within_group);
+        }
+        Expr::GroupingSets(sets) | Expr::Cube(sets) | Expr::Rollup(sets) => {
+            for set in sets {
+                walk_exprs(visitor, set);
+            }
+        }
+        Expr::Tuple(exprs) => walk_exprs(visitor, exprs),
+        Expr::ArrayIndex { obj, indexes } => {
+            walk_expr(visitor, obj);
+            walk_exprs(visitor, indexes);
+        }
+        Expr::Array(array) => walk_exprs(visitor, &array.elem),
+        Expr::Interval { value, .. } => walk_expr(visitor, value),
+        Expr::Identifier(_)
+        | Expr::CompoundIdentifier(_)
+        | Expr::Value(_)
+        | Expr::TypedString { .. } => {}
+    }
+}
@@ -0,0 +1,2 @@
+SELECT CONV(a, 16, 2) FROM t1;
+SAVEPOINT s1;
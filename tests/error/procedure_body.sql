@@ -0,0 +1,13 @@
+DELIMITER ;;
+CREATE DEFINER=`root`@`localhost` PROCEDURE `transfer`(IN amount INT)
+BEGIN
+  DECLARE done INT DEFAULT 0;
+  DECLARE CONTINUE HANDLER FOR SQLEXCEPTION SET done = 1;
+  START TRANSACTION;
+  IF amount > 0 THEN
+    SAVEPOINT before_update;
+    UPDATE account SET balance = balance - amount WHERE id = 1;
+  END IF;
+  COMMIT;
+END ;;
+DELIMITER ;
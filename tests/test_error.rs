@@ -1,8 +1,13 @@
+#![allow(
+    clippy::borrow_interior_mutable_const,
+    clippy::declare_interior_mutable_const
+)]
+
 use std::path::PathBuf;
 
 use once_cell::sync::Lazy;
 use tikey::{
-    check_file,
+    check_file, check_statements,
     rules::{
         Rule, RuleCharset, RuleColPriv, RuleEndEarly, RuleEvent, RuleForeignKey, RuleFullText,
         RuleFunction, RuleMysqlFunc, RuleOptimTrace, RuleProcedure, RuleSavepoint, RuleSpatial,
@@ -23,7 +28,7 @@ fn h1_function() {
         assert_eq!(info.records().len(), 1);
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleFunction::uid());
+        assert_eq!(*error_uid, RuleFunction {}.uid());
     }
 }
 
@@ -33,13 +38,18 @@ fn h2_trigger() {
     path.push("trigger.sql");
     let (_summary, right) = check_file(path).unwrap();
 
-    assert_eq!(right.len(), 1);
-    for info in right {
-        assert_eq!(info.records().len(), 1);
-        let error_uid = info.records().first().unwrap().uid();
-
-        assert_eq!(*error_uid, RuleTrigger::uid());
-    }
+    assert_eq!(right.len(), 2);
+    assert_eq!(right[0].records().len(), 1);
+    assert_eq!(
+        *right[0].records().first().unwrap().uid(),
+        RuleTrigger {}.uid()
+    );
+    // `NOW()` inside the trigger body
+    assert_eq!(
+        *right[1].records().first().unwrap().uid(),
+        RuleMysqlFunc {}.uid()
+    );
+    assert_eq!(*right[1].routine(), Some("TRIGGER T1".to_string()));
 }
 
 #[test]
@@ -53,7 +63,7 @@ fn h3_event() {
         assert_eq!(info.records().len(), 1);
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleEvent::uid());
+        assert_eq!(*error_uid, RuleEvent {}.uid());
     }
 }
 
@@ -68,10 +78,33 @@ fn h4_procedure() {
         assert_eq!(info.records().len(), 1);
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleProcedure::uid());
+        assert_eq!(*error_uid, RuleProcedure {}.uid());
     }
 }
 
+#[test]
+fn h4_procedure_body() {
+    let mut path = ERROR_PREFIX.clone();
+    path.push("procedure_body.sql");
+    let (_summary, right) = check_file(path).unwrap();
+
+    assert_eq!(right.len(), 3);
+    assert_eq!(
+        *right[1].records().first().unwrap().uid(),
+        RuleProcedure {}.uid()
+    );
+    assert_eq!(
+        *right[2].records().first().unwrap().uid(),
+        RuleSavepoint {}.uid()
+    );
+    assert_eq!(right[2].sql(), "SAVEPOINT before_update");
+    assert_eq!(right[2].span().start.line, 8);
+    assert_eq!(
+        *right[2].routine(),
+        Some("PROCEDURE `transfer`".to_string())
+    );
+}
+
 #[test]
 fn h5_fulltext() {
     let mut path = ERROR_PREFIX.clone();
@@ -81,11 +114,11 @@ fn h5_fulltext() {
     assert_eq!(right.len(), 2);
     assert_eq!(
         *right[0].records().first().unwrap().uid(),
-        RuleFullText::uid()
+        RuleFullText {}.uid()
     );
     assert_eq!(
         *right[1].records().first().unwrap().uid(),
-        RuleEndEarly::uid()
+        RuleEndEarly {}.uid()
     );
 }
 
@@ -100,7 +133,7 @@ fn h6_save_point() {
         assert_eq!(info.records().len(), 1);
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleSavepoint::uid());
+        assert_eq!(*error_uid, RuleSavepoint {}.uid());
     }
 }
 
@@ -115,7 +148,7 @@ fn m1_foreign_key() {
         assert_eq!(info.records().len(), 1);
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleForeignKey::uid());
+        assert_eq!(*error_uid, RuleForeignKey {}.uid());
     }
 }
 
@@ -129,7 +162,7 @@ fn m2_mysql_function() {
     for info in right {
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleMysqlFunc::uid());
+        assert_eq!(*error_uid, RuleMysqlFunc {}.uid());
     }
 }
 
@@ -143,7 +176,7 @@ fn m3_spatial() {
     for info in right {
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleSpatial::uid());
+        assert_eq!(*error_uid, RuleSpatial {}.uid());
     }
 }
 
@@ -156,19 +189,19 @@ fn m4_charset() {
     assert_eq!(right.len(), 4);
     assert_eq!(
         *right[0].records().first().unwrap().uid(),
-        RuleCharset::uid()
+        RuleCharset {}.uid()
     );
     assert_eq!(
         *right[1].records().first().unwrap().uid(),
-        RuleCharset::uid()
+        RuleCharset {}.uid()
     );
     assert_eq!(
         *right[2].records().first().unwrap().uid(),
-        RuleUnknown::uid()
+        RuleUnknown {}.uid()
     );
     assert_eq!(
         *right[3].records().first().unwrap().uid(),
-        RuleEndEarly::uid()
+        RuleEndEarly {}.uid()
     );
 }
 
@@ -182,10 +215,35 @@ fn m5_sys_schema() {
     for info in right {
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleSysSchema::uid());
+        assert_eq!(*error_uid, RuleSysSchema {}.uid());
     }
 }
 
+#[test]
+fn m3_m5_typed_positions() {
+    let sql = "SELECT CAST(a AS GEOMETRY) FROM t1;
+ALTER TABLE t1 CHANGE COLUMN a b GEOMETRY;
+SELECT sys.format_bytes(total) FROM t1;
+INSERT INTO sys.sys_config VALUES ('a', 1);
+SELECT * FROM mydb.sys.t1;";
+    let (_summary, right) = check_statements(sql.to_string()).unwrap();
+
+    let uids: Vec<String> = right
+        .iter()
+        .flat_map(|info| info.records().iter().map(|rec| rec.uid().clone()))
+        .collect();
+    assert_eq!(
+        uids,
+        vec![
+            RuleSpatial {}.uid(),
+            RuleSpatial {}.uid(),
+            RuleMysqlFunc {}.uid(),
+            RuleSysSchema {}.uid(),
+            RuleSysSchema {}.uid(),
+        ]
+    );
+}
+
 #[test]
 fn m6_optim_trace() {
     let mut path = ERROR_PREFIX.clone();
@@ -196,10 +254,63 @@ fn m6_optim_trace() {
     for info in right {
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleOptimTrace::uid());
+        assert_eq!(*error_uid, RuleOptimTrace {}.uid());
+    }
+}
+
+#[test]
+fn m6_optim_trace_of_other_schemas() {
+    let sql = "SELECT * FROM mydb.optimizer_trace;
+SELECT * FROM optimizer_trace;
+INSERT INTO information_schema.optimizer_trace.t VALUES (1);
+SELECT a FROM t1 JOIN information_schema.optimizer_trace ON 1 = 1;";
+    let (_summary, right) = check_statements(sql.to_string()).unwrap();
+
+    assert_eq!(right.len(), 1);
+    assert!(right[0].sql().starts_with("SELECT a FROM t1 JOIN"));
+    assert_eq!(*right[0].records()[0].uid(), RuleOptimTrace {}.uid());
+}
+
+#[test]
+fn m4_charset_positions() {
+    let sql = "SELECT 'latin1', latin1 FROM latin1;
+CREATE TABLE t1 (a VARCHAR(10) CHARACTER SET latin1);
+CREATE TABLE t2 (a VARCHAR(10) COLLATE latin1_swedish_ci);
+CREATE TABLE t3 (a INT) DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_bin;
+SET character_set_client = utf8mb4;
+SET collation_connection = 'big5_chinese_ci';
+SET sql_mode = 'latin1';
+SELECT a COLLATE latin1_bin FROM t1;";
+    let (_summary, right) = check_statements(sql.to_string()).unwrap();
+
+    let sqls: Vec<&str> = right.iter().map(|info| info.sql().as_str()).collect();
+    assert_eq!(
+        sqls,
+        vec![
+            "CREATE TABLE t1 (a VARCHAR(10) CHARACTER SET latin1)",
+            "CREATE TABLE t2 (a VARCHAR(10) COLLATE latin1_swedish_ci)",
+            "SET collation_connection = 'big5_chinese_ci'",
+            "SELECT a COLLATE latin1_bin FROM t1",
+        ]
+    );
+    for info in right {
+        assert_eq!(*info.records()[0].uid(), RuleCharset {}.uid());
     }
 }
 
+#[test]
+fn names_are_not_features() {
+    let sql = "CREATE TABLE geometry (geometry INT NOT NULL);
+SELECT optimizer_trace FROM geometry;
+ALTER TABLE t1 ADD COLUMN g GEOMETRY;";
+    let (_summary, right) = check_statements(sql.to_string()).unwrap();
+
+    assert_eq!(right.len(), 1);
+    assert_eq!(right[0].sql(), "ALTER TABLE t1 ADD COLUMN g GEOMETRY");
+    assert_eq!(right[0].records().len(), 1);
+    assert_eq!(*right[0].records()[0].uid(), RuleSpatial {}.uid());
+}
+
 #[test]
 fn m7_column_privilege() {
     let mut path = ERROR_PREFIX.clone();
@@ -211,6 +322,29 @@ fn m7_column_privilege() {
         assert_eq!(info.records().len(), 1);
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleColPriv::uid());
+        assert_eq!(*error_uid, RuleColPriv {}.uid());
     }
 }
+
+#[test]
+fn typed_triggers() {
+    let sql = "ALTER TABLE t2 ADD CONSTRAINT fk FOREIGN KEY (t1_id) REFERENCES t1(id);
+CREATE TABLE t3 (t1_id INT REFERENCES t1(id));
+GRANT UPDATE (c) ON test_test.v1 TO test_test_1@localhost;
+EXPLAIN SELECT * FROM t1 WHERE id IN (SELECT CONV(a, 16, 2) FROM t2);";
+    let (_summary, right) = check_statements(sql.to_string()).unwrap();
+
+    let uids: Vec<String> = right
+        .iter()
+        .map(|info| info.records().first().unwrap().uid().clone())
+        .collect();
+    assert_eq!(
+        uids,
+        vec![
+            RuleForeignKey {}.uid(),
+            RuleForeignKey {}.uid(),
+            RuleColPriv {}.uid(),
+            RuleMysqlFunc {}.uid(),
+        ]
+    );
+}
@@ -1,9 +1,25 @@
+#![allow(
+    clippy::borrow_interior_mutable_const,
+    clippy::declare_interior_mutable_const,
+    clippy::match_like_matches_macro
+)]
+
 use std::path::PathBuf;
 
 use once_cell::sync::Lazy;
+use serde_json::Value;
+use sqlparser::ast::Statement;
 use tikey::{
-    check_files,
-    client::{client, Target, TiKeyArgs},
+    apply_config, apply_version, check_file_with, check_files, check_files_with_jobs, check_reader,
+    check_statements, check_statements_with,
+    client::{client, BaselineCommand, ExitStatus, Format, Target, TiKeyArgs},
+    format_checkstyle, format_html, format_json, format_jsonl, format_junit, format_sarif,
+    rules::{
+        DBVersion, InfoLevel, Rule, RuleDelimiter, RuleForeignKey, RuleFuture, RuleInfo,
+        RuleMysqlFunc, RuleProcedure, RuleSavepoint, Trigger, Version,
+    },
+    upgrade_delta, Baseline, Config, FailOn, Location, OnceInfo, RuleRegistry, Thresholds,
+    SCHEMA_VERSION,
 };
 use walkdir::WalkDir;
 
@@ -24,12 +40,156 @@ fn use_as_lib() {
         })
         .collect();
 
-    let (summary, _right) = check_files(all_files).unwrap();
+    let (summary, _right, failures) = check_files(all_files).unwrap();
+
+    assert!(failures.is_empty());
+    assert_eq!(*summary.file_count(), 35);
+    assert_eq!(*summary.sql_count(), 130);
+    assert_eq!(*summary.errors(), 101);
+    assert_eq!(*summary.warnings(), 21);
+}
+
+#[test]
+fn parallel_check() {
+    let mut all_files: Vec<PathBuf> = WalkDir::new(INTEGRATION_PREFIX.clone())
+        .into_iter()
+        .map(|a| a.unwrap().into_path())
+        .filter(|p| match p.extension() {
+            Some(s) => match s.to_str() {
+                Some("sql") => true,
+                _ => false,
+            },
+            None => false,
+        })
+        .collect();
+    all_files.push(PathBuf::from("tests/missing.sql"));
+
+    let registry = RuleRegistry::builtin();
+    let (one, one_info, one_failures) =
+        check_files_with_jobs(&registry, all_files.clone(), 1).unwrap();
+    let (many, many_info, many_failures) = check_files_with_jobs(&registry, all_files, 4).unwrap();
+
+    assert_eq!(*many.file_count(), *one.file_count());
+    assert_eq!(*many.sql_count(), *one.sql_count());
+    assert_eq!(many.by_file(), one.by_file());
+    // in the order of files whatever the jobs
+    let locations = |all_info: &[OnceInfo]| -> Vec<(Option<PathBuf>, Location)> {
+        all_info
+            .iter()
+            .map(|info| (info.file().clone(), info.span().start))
+            .collect()
+    };
+    assert_eq!(locations(&many_info), locations(&one_info));
+    assert_eq!(many_failures.len(), 1);
+    assert_eq!(many_failures[0].file(), one_failures[0].file());
+}
+
+#[test]
+fn streaming_check() {
+    // delimiters ending lines inside quotes and comments do not end the statements
+    let sql = "SELECT 'a;
+b;', CONV(a, 16, 2) FROM t1;
+/* tikey:disable h6 ;
+*/ SAVEPOINT s1;
+/* tikey:enable */
+DELIMITER //
+CREATE PROCEDURE p() BEGIN SAVEPOINT s2; END//
+DELIMITER ;
+SAVEPOINT s3; SELECT 1;
+SAVEPOINT s4";
+    let mut seen: Vec<(String, u64)> = vec![];
+    let summary = check_reader(sql.as_bytes(), None, |info| {
+        let uid = info.records()[0].uid().clone();
+        seen.push((uid, info.span().start.line));
+        Ok(())
+    })
+    .unwrap();
+    let seen: Vec<(&str, u64)> = seen.iter().map(|(u, l)| (u.as_str(), *l)).collect();
+    assert_eq!(
+        seen,
+        vec![
+            ("m2", 1),
+            ("s2", 6),
+            ("h4", 7),
+            ("h6", 7),
+            ("h6", 9),
+            ("h6", 10)
+        ]
+    );
+    assert_eq!(*summary.suppressed(), 1);
+    assert_eq!(*summary.hidden(), 1);
+    assert_eq!(*summary.clean(), *summary.sql_count() - 6 - 1);
+
+    // the same as checking the whole input at once
+    let (whole, all_info) = check_statements(sql.to_string()).unwrap();
+    assert_eq!(*whole.sql_count(), *summary.sql_count());
+    assert_eq!(all_info.len(), seen.len());
+
+    // an error of the callback stops the check
+    let mut count = 0;
+    let stopped = check_reader(sql.as_bytes(), None, |_info| {
+        count += 1;
+        Err(anyhow::anyhow!("enough"))
+    });
+    assert!(stopped.is_err());
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn statement_spans() {
+    // `#` comments end at the line, `--` only starts a comment before a whitespace
+    let sql = "# don't do this
+SELECT 1;
+-- a comment; with delimiter
+/* block ; comment */ SAVEPOINT a;
+SELECT 'it''s;
+multi', CONV(a,
+  16, 2) FROM t; SELECT 5--1 FROM t;
+SAVEPOINT `b;c`;";
+    let (summary, all_info) = check_statements(sql.to_string()).unwrap();
+    assert_eq!(*summary.sql_count(), 5);
+    let spans: Vec<_> = all_info
+        .iter()
+        .map(|info| {
+            let span = info.span();
+            (
+                info.records()[0].uid().as_str(),
+                (span.start.line, span.start.column),
+                (span.end.line, span.end.column),
+            )
+        })
+        .collect();
+    assert_eq!(
+        spans,
+        vec![
+            ("h6", (4, 23), (4, 33)),
+            ("m2", (5, 1), (7, 15)),
+            ("h6", (8, 1), (8, 15))
+        ]
+    );
+    assert_eq!(all_info[0].sql(), "SAVEPOINT a");
+    assert!(all_info[1].sql().ends_with("16, 2) FROM t"));
+}
+
+#[test]
+fn bulk_insert() {
+    // only the rows of plain data inserts are skipped
+    let sql = "INSERT INTO `shop`.`t1` (`a`, `b`) VALUES (1,'x;y'),(-2.5e3,NULL),(0x1F,'it''s');
+INSERT INTO t1 VALUES (CONV('a', 16, 2));
+INSERT INTO t1 (a) SELECT CONV(a, 16, 2) FROM t2;
+INSERT INTO t1 VALUES (1,'a'),(2,'b');";
+    let (summary, all_info) = check_statements(sql.to_string()).unwrap();
+    assert_eq!(*summary.sql_count(), 4);
+    assert_eq!(*summary.bulk_inserts(), 2);
+    let found: Vec<(&str, u64)> = all_info
+        .iter()
+        .map(|info| (info.records()[0].uid().as_str(), info.span().start.line))
+        .collect();
+    assert_eq!(found, vec![("m2", 2), ("m2", 3)]);
 
-    assert_eq!(*summary.file_count(), 28);
-    assert_eq!(*summary.sql_count(), 99);
-    assert_eq!(*summary.errors(), 90);
-    assert_eq!(*summary.warnings(), 16);
+    let report = format_json(&summary, None, &all_info, &[], &[]).unwrap();
+    let report: Value = serde_json::from_str(&report).unwrap();
+    assert_eq!(report["summary"]["bulk_inserts"], 2);
 }
 
 #[test]
@@ -42,5 +202,544 @@ fn use_as_command() {
         path.into_os_string().into_string().unwrap(),
         Some(out),
     );
-    client(args).unwrap()
+    // tests/warning/parse_error.sql fails to parse
+    assert_eq!(client(args).unwrap(), ExitStatus::InvalidInput);
+}
+
+#[test]
+fn json_report() {
+    let sql = "SAVEPOINT s1;\nSELECT CONV(a, 16, 2) FROM t1;\n";
+    let (summary, all_info) = check_statements(sql.to_string()).unwrap();
+
+    let report = format_json(&summary, None, &all_info, &[], &[]).unwrap();
+    let report: Value = serde_json::from_str(&report).unwrap();
+    assert_eq!(report["schema_version"], SCHEMA_VERSION);
+    assert_eq!(report["summary"]["errors"], 2);
+    assert!(report.get("upgrade").is_none());
+    let finding = &report["findings"][0];
+    assert_eq!(finding["file"], Value::Null);
+    assert_eq!(finding["span"]["start"]["line"], 1);
+    assert_eq!(finding["span"]["end"]["column"], 12);
+    assert_eq!(finding["sql"], "SAVEPOINT s1");
+    assert_eq!(finding["uid"], "h6");
+    assert_eq!(finding["level"], "error");
+    assert_eq!(finding["db_version_range"][0], "earliest");
+    assert_eq!(finding["db_version_range"][1], "6.2.0");
+    assert_eq!(finding["future"], "will-support");
+    assert_eq!(report["findings"][1]["uid"], "m2");
+
+    let lines = format_jsonl(&summary, None, &all_info, &[], &[]).unwrap();
+    assert_eq!(lines.len(), 3);
+    let lines: Vec<Value> = lines
+        .iter()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    assert_eq!(lines[0]["type"], "finding");
+    assert_eq!(lines[0]["uid"], "h6");
+    assert_eq!(lines[2]["type"], "summary");
+    assert_eq!(lines[2]["schema_version"], SCHEMA_VERSION);
+    assert_eq!(lines[2]["sql_count"], 2);
+}
+
+#[test]
+fn summary_counts() {
+    let sql = "SAVEPOINT s1;\nSELECT CONV(a, 16, 2) FROM t1;\nSELECT 1;\nSELECT 2;\n";
+    let (summary, all_info) = check_statements(sql.to_string()).unwrap();
+    assert_eq!(*summary.clean(), 2);
+    assert_eq!(*summary.clean_percent(), 50.0);
+    assert_eq!(summary.by_rule()["h6"], 1);
+    assert_eq!(summary.by_rule()["m2"], 1);
+    assert_eq!(summary.by_future()[&RuleFuture::WillSupport], 2);
+    assert_eq!(summary.by_kind()["Savepoint"], 1);
+    assert_eq!(summary.by_kind()["Query"], 1);
+    assert!(summary.by_file().is_empty());
+
+    let report = format_json(&summary, None, &all_info, &[], &[]).unwrap();
+    let report: Value = serde_json::from_str(&report).unwrap();
+    assert_eq!(report["summary"]["clean_percent"], 50.0);
+    assert_eq!(report["summary"]["by_future"]["will-support"], 2);
+    assert_eq!(report["findings"][0]["kind"], "Savepoint");
+
+    // counted again once the config is applied
+    let new = PathBuf::from("tests/config/new.sql");
+    let old = PathBuf::from("tests/config/legacy/old.sql");
+    let config = Config::find(&new).unwrap().unwrap();
+    let (summary, all_info, _failures) = check_files(vec![new, old]).unwrap();
+    assert_eq!(summary.by_file().values().sum::<u128>(), 4);
+    let (summary, _all_info) = apply_config(&config, summary, all_info);
+    assert_eq!(summary.by_file().values().sum::<u128>(), 2);
+    assert_eq!(summary.by_rule().len(), 2);
+    assert_eq!(
+        *summary.clean() + 2,
+        *summary.sql_count(),
+        "a statement with findings left is not clean"
+    );
+}
+
+#[test]
+fn effort_estimate() {
+    let sql = "SAVEPOINT s1;\nUSE shop;\nSELECT CONV(a, 16, 2) FROM t1;\nUSE crm;\nSAVEPOINT s2;\n";
+    let (summary, all_info) = check_statements(sql.to_string()).unwrap();
+    assert_eq!(*all_info[0].schema(), None);
+    assert_eq!(all_info[1].schema().as_deref(), Some("shop"));
+    assert_eq!(*summary.effort().total(), 5);
+    assert_eq!(summary.effort().of_future(&RuleFuture::NoPlan), 0);
+    assert_eq!(*summary.effort_by_schema()["shop"].total(), 1);
+    assert_eq!(*summary.effort_by_schema()["crm"].total(), 2);
+
+    let report = format_json(&summary, None, &all_info, &[], &[]).unwrap();
+    let report: Value = serde_json::from_str(&report).unwrap();
+    assert_eq!(report["summary"]["effort"]["by_future"]["will-support"], 5);
+    assert_eq!(report["findings"][0]["effort"], 2);
+    assert_eq!(report["findings"][1]["schema"], "shop");
+
+    // procedures have no plan to be supported, and cost the most
+    let procedure = INTEGRATION_PREFIX.join("error").join("procedure.sql");
+    let (summary, _all_info) = check_file_with(&RuleRegistry::builtin(), &procedure).unwrap();
+    let effort = &summary.effort_by_file()[&procedure];
+    assert_eq!(effort.of_future(&RuleFuture::NoPlan), 8);
+
+    // the config sets the effort of m2
+    let new = PathBuf::from("tests/config/new.sql");
+    let old = PathBuf::from("tests/config/legacy/old.sql");
+    let config = Config::find(&new).unwrap().unwrap();
+    let (summary, all_info, _failures) = check_files(vec![new, old.clone()]).unwrap();
+    let (summary, _all_info) = apply_config(&config, summary, all_info);
+    assert_eq!(*summary.effort().total(), 2 + 3);
+    assert_eq!(*summary.effort_by_file()[&old].total(), 3);
+}
+
+#[test]
+fn sarif_report() {
+    let registry = RuleRegistry::builtin();
+    let path = INTEGRATION_PREFIX.join("error").join("save_point.sql");
+    let (_summary, all_info) = check_file_with(&registry, &path).unwrap();
+
+    let log = format_sarif(&registry, &all_info, &[]).unwrap();
+    let log: Value = serde_json::from_str(&log).unwrap();
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), registry.uids().len());
+
+    let result = &run["results"][0];
+    assert_eq!(result["ruleId"], "h6");
+    let rule = &rules[result["ruleIndex"].as_u64().unwrap() as usize];
+    assert_eq!(rule["id"], "h6");
+    assert_eq!(
+        rule["helpUri"],
+        "https://github.com/pingcap/tidb/issues/6840"
+    );
+    assert_eq!(result["level"], "error");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(
+        location["artifactLocation"]["uri"],
+        "tests/error/save_point.sql"
+    );
+    assert!(location["region"]["startLine"].as_u64().unwrap() >= 1);
+}
+
+#[test]
+fn xml_reports() {
+    let files: Vec<PathBuf> = ["save_point.sql", "foreign_key.sql"]
+        .iter()
+        .map(|f| INTEGRATION_PREFIX.join("error").join(f))
+        .chain([INTEGRATION_PREFIX.join("practice").join("missing.sql")])
+        .collect();
+    let (summary, all_info, failures) = check_files(files.clone()).unwrap();
+    assert_eq!(failures.len(), 1);
+
+    let junit = format_junit(&summary, &files, &all_info, &failures);
+    assert!(junit.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites"));
+    assert_eq!(junit.matches("<testsuite ").count(), 3);
+    assert_eq!(junit.matches("<error type=\"error\"").count(), 2);
+    assert_eq!(junit.matches("<error type=\"failure\"").count(), 1);
+    let save_point = files[0].to_string_lossy();
+    assert!(junit.contains(&format!(
+        "<testsuite name=\"{}\" tests=\"1\" failures=\"0\" errors=\"1\">",
+        save_point
+    )));
+
+    let checkstyle = format_checkstyle(&files, &all_info, &failures);
+    assert_eq!(checkstyle.matches("<file ").count(), 3);
+    assert_eq!(checkstyle.matches("source=\"tikey.m1\"").count(), 1);
+    assert!(checkstyle.contains("severity=\"error\" message=\"TiDB not supported savepoint\""));
+    assert_eq!(checkstyle.matches("source=\"tikey.failure\"").count(), 1);
+}
+
+#[test]
+fn html_report() {
+    let files = vec![INTEGRATION_PREFIX.join("error").join("foreign_key.sql")];
+    let (summary, all_info, failures) = check_files(files.clone()).unwrap();
+
+    let html = format_html(&summary, &files, &all_info, &failures);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    // works offline
+    assert!(!html.contains("src="));
+    assert!(!html.contains("<link"));
+    assert!(html.contains("<a href=\"https://github.com/pingcap/tidb/issues/18209\">m1</a>"));
+    assert_eq!(html.matches("<details>").count(), 1);
+    // the statement is highlighted with the lines around it
+    assert!(html.contains("<mark>CREATE TABLE t2 (\n id INT"));
+    assert!(html.contains("REFERENCES t1(id)\n)</mark>;\n\nINSERT INTO t1"));
+    assert!(html.contains("<pre>);\n\n<mark>"));
+
+    // decoded like it was checked, rather than read as UTF-8
+    let files = vec![INTEGRATION_PREFIX.join("warning").join("encoding_gbk.sql")];
+    let (summary, all_info, failures) = check_files(files.clone()).unwrap();
+    let html = format_html(&summary, &files, &all_info, &failures);
+    assert!(html.contains("FROM t2;\n<mark>SAVEPOINT 保存点</mark>;\n</pre>"));
+
+    let (summary, all_info) =
+        check_statements("SELECT '<b>' FROM t1 FOR UPDATE; SAVEPOINT s;".to_string()).unwrap();
+    let html = format_html(&summary, &[], &all_info, &[]);
+    assert!(html.contains("<h3 id=\"file-0\">&lt;statements&gt;</h3>"));
+    assert!(html.contains("<pre><mark>SAVEPOINT s</mark></pre>"));
+}
+
+#[test]
+fn fail_thresholds() {
+    let sql = "SAVEPOINT s1;\nSELECT CONV(a, 16, 2) FROM t1;\nSELECT FROM WHERE;\n";
+    let (summary, _all_info) = check_statements(sql.to_string()).unwrap();
+    assert_eq!((*summary.errors(), *summary.warnings()), (2, 1));
+
+    let exceeded = |thresholds: Thresholds| thresholds.exceeded(&summary).len();
+    assert_eq!(exceeded(Thresholds::default()), 1);
+    assert_eq!(
+        exceeded(Thresholds::new(FailOn::Error).with_max_errors(2)),
+        0
+    );
+    assert_eq!(
+        exceeded(Thresholds::new(FailOn::Warning).with_max_errors(2)),
+        1
+    );
+    assert_eq!(exceeded(Thresholds::new(FailOn::Never)), 0);
+    let thresholds = Thresholds::new(FailOn::Never).with_max_rule("m2".to_string(), 0);
+    assert_eq!(exceeded(thresholds), 1);
+
+    let out = std::env::temp_dir().join("tikey_fail_thresholds.txt");
+    let file = INTEGRATION_PREFIX.join("error").join("save_point.sql");
+    let args = |file: &PathBuf| {
+        TiKeyArgs::new(
+            Target::File,
+            file.to_string_lossy().to_string(),
+            Some(out.clone()),
+        )
+    };
+    assert_eq!(client(args(&file)).unwrap(), ExitStatus::Findings);
+    let args_never = args(&file).with_thresholds(FailOn::Never, 0, 0);
+    assert_eq!(client(args_never).unwrap(), ExitStatus::Success);
+    let args_rule = args(&file)
+        .with_thresholds(FailOn::Never, 0, 0)
+        .with_max_rule("h6".to_string(), 0);
+    assert_eq!(client(args_rule).unwrap(), ExitStatus::Findings);
+    let args_unknown = args(&file).with_max_rule("zz".to_string(), 0);
+    let e = client(args_unknown).unwrap_err();
+    assert_eq!(ExitStatus::of_error(&e), ExitStatus::InvalidInput);
+    let e = client(args(&INTEGRATION_PREFIX.join("missing.sql"))).unwrap_err();
+    assert_eq!(ExitStatus::of_error(&e), ExitStatus::InvalidInput);
+    assert_eq!(ExitStatus::of_error(&e).code(), 2);
+
+    // statements failing to parse are an input failure, not findings
+    let unparsed = INTEGRATION_PREFIX.join("warning").join("parse_error.sql");
+    assert_eq!(client(args(&unparsed)).unwrap(), ExitStatus::InvalidInput);
+    let args_never = args(&unparsed).with_thresholds(FailOn::Never, 0, 0);
+    assert_eq!(client(args_never).unwrap(), ExitStatus::InvalidInput);
+}
+
+#[test]
+fn streamed_report() {
+    // a baseline needs all findings first, so an empty one turns streaming off
+    let empty = std::env::temp_dir().join("tikey_streamed_report_baseline.json");
+    Baseline::create(&[]).save(&empty).unwrap();
+    let dir = INTEGRATION_PREFIX.join("error");
+    let report = |format: Format, baseline: bool| {
+        let out = std::env::temp_dir().join(format!("tikey_streamed_report_{}.txt", baseline));
+        let args = TiKeyArgs::new(
+            Target::Dir,
+            dir.to_string_lossy().to_string(),
+            Some(out.clone()),
+        )
+        .with_format(format)
+        .with_jobs(2);
+        let args = match baseline {
+            true => args.with_baseline(empty.clone()),
+            false => args,
+        };
+        let status = client(args).unwrap();
+        (status, std::fs::read_to_string(out).unwrap())
+    };
+
+    let (streamed_status, streamed) = report(Format::Compact, false);
+    let (status, whole) = report(Format::Compact, true);
+    assert_eq!(streamed_status, status);
+    assert!(streamed.lines().count() > 1);
+    assert_eq!(streamed, whole);
+
+    // the same but the time cost of the summary line
+    let (_, streamed) = report(Format::Jsonl, false);
+    let (_, whole) = report(Format::Jsonl, true);
+    let findings = |report: &str| {
+        report
+            .lines()
+            .rev()
+            .skip(1)
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(findings(&streamed), findings(&whole));
+    let last: Value = serde_json::from_str(streamed.lines().last().unwrap()).unwrap();
+    assert_eq!(last["type"], "summary");
+}
+
+#[test]
+fn baseline_findings() {
+    let sql = "SAVEPOINT s1;\nSELECT CONV(a, 16, 2) FROM t1;\nSELECT CONV(a, 16, 2) FROM t1;\n";
+    let (_summary, all_info) = check_statements(sql.to_string()).unwrap();
+    let baseline = Baseline::create(&all_info);
+    assert_eq!(baseline.findings().len(), 2);
+    assert_eq!(*baseline.findings()[1].count(), 2);
+
+    let path = std::env::temp_dir().join("tikey_baseline_findings.json");
+    baseline.save(&path).unwrap();
+    let baseline = Baseline::load(&path).unwrap();
+
+    // shifted and reformatted, with a known finding fixed and a new one
+    let sql = "\n\nselect   CONV(a, 16, 2)\n FROM t1;\nSAVEPOINT s1;\nSAVEPOINT s2;\n";
+    let (summary, all_info) = check_statements(sql.to_string()).unwrap();
+    let (summary, all_info, fixed) = baseline.apply(summary, all_info);
+    assert_eq!(*summary.errors(), 1);
+    // statements with known findings only are hidden rather than clean
+    assert_eq!((*summary.clean(), *summary.hidden()), (0, 2));
+    assert_eq!(all_info.len(), 1);
+    assert_eq!(all_info[0].sql(), "SAVEPOINT s2");
+    assert_eq!(fixed.len(), 1);
+    assert_eq!(fixed[0].uid(), "m2");
+    assert_eq!(fixed[0].sql(), "select conv(a, 16, 2) from t1");
+    assert_eq!(*fixed[0].count(), 1);
+}
+
+#[test]
+fn baseline_paths() {
+    // created and checked with the input by two paths, from another directory
+    let dir = std::env::temp_dir().join("tikey_baseline_paths");
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("tikey-baseline.json");
+    let create = Target::Baseline(BaselineCommand::Create { file: file.clone() });
+    let args = TiKeyArgs::new(create, "tests/error".to_string(), None);
+    assert_eq!(client(args).unwrap(), ExitStatus::Success);
+
+    let baseline = Baseline::load(&file).unwrap();
+    let known = baseline.findings()[0].file().clone().unwrap();
+    // relative to the directory of the baseline file
+    assert!(known.starts_with("../") && known.contains("/tests/error/"));
+
+    let spelled = std::env::current_dir()
+        .unwrap()
+        .join("tests")
+        .join("config")
+        .join("..")
+        .join("error");
+    let out = dir.join("report.txt");
+    let args = TiKeyArgs::new(
+        Target::Dir,
+        spelled.to_string_lossy().to_string(),
+        Some(out),
+    )
+    .with_baseline(file);
+    assert_eq!(client(args).unwrap(), ExitStatus::Success);
+}
+
+#[test]
+fn keep_original_statement() {
+    let sql = "-- ignored; comment\nSELECT 1;\n  SAVEPOINT `Save_1`;\n";
+    let (summary, right) = check_statements(sql.to_string()).unwrap();
+
+    assert_eq!(*summary.sql_count(), 2);
+    assert_eq!(right.len(), 1);
+    assert_eq!(right[0].sql(), "SAVEPOINT `Save_1`");
+    assert_eq!(right[0].span().start, Location { line: 3, column: 3 });
+    assert_eq!(
+        right[0].span().end,
+        Location {
+            line: 3,
+            column: 20
+        }
+    );
+}
+
+#[test]
+fn suppression_comments() {
+    let sql = "-- tikey:disable-next-statement h6
+SAVEPOINT s1;
+SAVEPOINT s2;
+/* tikey:disable */
+SELECT CONV(a, 16, 2) FROM t1;
+/* tikey:enable M2 */
+SELECT CONV(a, 16, 2) FROM t1;
+SAVEPOINT s3;
+-- tikey:enable
+DELIMITER //
+CREATE PROCEDURE p()
+BEGIN
+  -- tikey:disable-next-statement h6, m2
+  SAVEPOINT s4;
+  SAVEPOINT s5;
+END//
+";
+    let (summary, right) = check_statements(sql.to_string()).unwrap();
+
+    let found: Vec<(String, u64)> = right
+        .iter()
+        .flat_map(|info| {
+            info.records()
+                .iter()
+                .map(|rec| (rec.uid().clone(), info.span().start.line))
+        })
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            (RuleSavepoint {}.uid(), 3),
+            (RuleMysqlFunc {}.uid(), 7),
+            (RuleDelimiter {}.uid(), 10),
+            (RuleProcedure {}.uid(), 11),
+            (RuleSavepoint {}.uid(), 15),
+        ]
+    );
+    assert_eq!(*summary.suppressed(), 4);
+    // SAVEPOINT s1, s3 and s4 and the first CONV have suppressed findings only
+    assert_eq!(*summary.hidden(), 4);
+    assert_eq!(
+        *summary.clean(),
+        *summary.sql_count() - right.len() as u128 - 4
+    );
+}
+
+struct RuleTruncate {}
+
+impl Rule for RuleTruncate {
+    fn uid(&self) -> String {
+        "x1".to_string()
+    }
+
+    fn trigger(&self) -> Trigger {
+        Trigger::StatementJudge(|s| matches!(s, Statement::Truncate { .. }))
+    }
+
+    fn info(&self) -> RuleInfo {
+        RuleInfo::new(
+            self.uid(),
+            InfoLevel::WARNING,
+            (DBVersion::Earliest, DBVersion::Latest),
+            RuleFuture::NoPlan,
+            "TRUNCATE is not allowed by our DBA".to_string(),
+            None,
+        )
+    }
+}
+
+#[test]
+fn use_own_registry() {
+    let rules: Vec<Box<dyn Rule>> = vec![Box::new(RuleTruncate {}), Box::new(RuleSavepoint {})];
+    let mut registry = RuleRegistry::new();
+    for rule in rules {
+        registry.register(rule);
+    }
+    let sql = "SELECT CONV(a, 16, 2) FROM t1;\nTRUNCATE TABLE t1;\nSAVEPOINT s1;\n";
+    let (summary, right) = check_statements_with(&registry, sql.to_string()).unwrap();
+    assert_eq!(right.len(), 2);
+    assert_eq!(*right[0].records()[0].uid(), RuleTruncate {}.uid());
+    assert_eq!(*summary.warnings(), 1);
+    assert_eq!(*summary.errors(), 1);
+
+    let mut registry = RuleRegistry::builtin();
+    assert!(registry.unregister(&RuleMysqlFunc {}.uid()));
+    assert!(!registry.unregister(&RuleMysqlFunc {}.uid()));
+    let (_summary, right) = check_statements_with(&registry, sql.to_string()).unwrap();
+    assert_eq!(right.len(), 1);
+    assert_eq!(*right[0].records()[0].uid(), RuleSavepoint {}.uid());
+}
+
+#[test]
+fn target_version() {
+    assert_eq!("v6.5".parse::<Version>().unwrap(), Version::new(6, 5, 0));
+    assert_eq!(
+        "6.5.1-alpha".parse::<Version>().unwrap(),
+        Version::new(6, 5, 1)
+    );
+    assert!("6.10.0".parse::<Version>().unwrap() > Version::new(6, 9, 0));
+    assert!("six".parse::<Version>().is_err());
+    assert!("6.5.0.1".parse::<Version>().is_err());
+
+    let info = RuleForeignKey {}.info();
+    assert!(info.applies_to(&Version::new(6, 5, 0)));
+    assert!(!info.applies_to(&Version::new(6, 6, 0)));
+
+    let sql = "CREATE TABLE t1 (a INT, FOREIGN KEY (a) REFERENCES t2 (a));\nSAVEPOINT s1;\n";
+    let uids = |version: Version| {
+        let mut registry = RuleRegistry::builtin();
+        registry.retain_version(&version);
+        let (_summary, right) = check_statements_with(&registry, sql.to_string()).unwrap();
+        right
+            .iter()
+            .flat_map(|once| once.records().iter().map(|rec| rec.uid().clone()))
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(uids(Version::new(6, 1, 0)), vec!["m1", "h6"]);
+    assert_eq!(uids(Version::new(6, 2, 0)), vec!["m1"]);
+    assert!(uids(Version::new(7, 1, 0)).is_empty());
+}
+
+#[test]
+fn upgrade_report() {
+    let sql = "CREATE TABLE t1 (a INT, FOREIGN KEY (a) REFERENCES t2 (a));\n\
+        SAVEPOINT s1;\n\
+        SELECT CONV(a, 16, 2) FROM t1;\n";
+    let (summary, all_info) = check_statements(sql.to_string()).unwrap();
+    assert_eq!(*summary.errors(), 3);
+
+    let uids = |all_info: &Vec<OnceInfo>| {
+        all_info
+            .iter()
+            .flat_map(|once| once.records().iter().map(|rec| rec.uid().clone()))
+            .collect::<Vec<String>>()
+    };
+    let (from, to) = (Version::new(6, 1, 0), Version::new(6, 6, 0));
+    let delta = upgrade_delta(&from, &to, &all_info);
+    assert_eq!(uids(delta.resolved()), vec!["m1", "h6"]);
+    assert_eq!(uids(delta.remaining()), vec!["m2"]);
+    assert!(delta.introduced().is_empty());
+
+    // downgrading brings the findings back
+    let delta = upgrade_delta(&to, &from, &all_info);
+    assert!(delta.resolved().is_empty());
+    assert_eq!(uids(delta.introduced()), vec!["m1", "h6"]);
+
+    let (summary, all_info) = apply_version(&to, summary, all_info);
+    assert_eq!(*summary.errors(), 1);
+    assert_eq!(uids(&all_info), vec!["m2"]);
+}
+
+#[test]
+fn use_config() {
+    let new = PathBuf::from("tests/config/new.sql");
+    let old = PathBuf::from("tests/config/legacy/old.sql");
+    let config = Config::find(&new).unwrap().unwrap();
+    config.validate(&RuleRegistry::builtin()).unwrap();
+
+    let (summary, right, _failures) = check_files(vec![new, old]).unwrap();
+    assert_eq!(*summary.errors(), 4);
+    let (summary, right) = apply_config(&config, summary, right);
+
+    assert_eq!(right.len(), 2);
+    let rec = &right[0].records()[0];
+    assert_eq!(*rec.uid(), RuleSavepoint {}.uid());
+    assert_eq!(*rec.info_level(), InfoLevel::WARNING);
+    assert_eq!(*right[1].records()[0].uid(), RuleMysqlFunc {}.uid());
+    assert_eq!(*summary.errors(), 1);
+    assert_eq!(*summary.warnings(), 1);
+
+    let invalid = Config::load("tests/config/invalid.toml").unwrap();
+    assert!(invalid.validate(&RuleRegistry::builtin()).is_err());
 }
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use tikey::{
+    check_statements, load_rules,
+    rules::{InfoLevel, Rule, RuleMysqlFunc},
+    RuleRegistry,
+};
+
+#[test]
+fn declared_rules() {
+    // a failed load leaves the registry untouched
+    let duplicate = PathBuf::from("tests/rules/invalid/duplicate.toml");
+    assert!(load_rules(duplicate).is_err());
+
+    let path = PathBuf::from("tests/rules");
+    assert_eq!(load_rules(&path).unwrap(), 2);
+
+    let sql = "SELECT LOAD_FILE('/tmp/a.txt');
+CREATE TEMPORARY TABLE t1 (id INT);
+CREATE TABLE t2 (id INT);";
+    let (summary, right) = check_statements(sql.to_string()).unwrap();
+
+    assert_eq!(right.len(), 2);
+    let uids: Vec<&String> = right[0].records().iter().map(|r| r.uid()).collect();
+    assert_eq!(uids, vec![&RuleMysqlFunc {}.uid(), &"c1".to_string()]);
+    assert_eq!(*right[0].records()[1].effort(), 3);
+    let rec = &right[1].records()[0];
+    assert_eq!(rec.uid(), "c2");
+    assert_eq!(*rec.info_level(), InfoLevel::WARNING);
+    assert_eq!(rec.db_version_range().1.to_string(), "6.5.0");
+    assert_eq!(*rec.effort(), 1);
+    assert_eq!(*summary.errors(), 2);
+    assert_eq!(*summary.warnings(), 1);
+
+    // the registry is settled by the first check
+    assert!(load_rules(&path).is_err());
+}
+
+#[test]
+fn builtin_rules_are_typed() {
+    // statements are serialized only for declared rules
+    let mut registry = RuleRegistry::builtin();
+    assert!(!registry.needs_json());
+    registry.load_rules("tests/rules").unwrap();
+    assert!(registry.needs_json());
+}
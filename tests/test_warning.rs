@@ -1,9 +1,18 @@
+#![allow(
+    clippy::borrow_interior_mutable_const,
+    clippy::declare_interior_mutable_const
+)]
+
 use std::path::PathBuf;
 
 use once_cell::sync::Lazy;
 use tikey::{
-    check_file,
-    rules::{Rule, RuleDelimiter, RuleEndEarly, RuleUnknown},
+    check_file, check_file_with_encoding, encoding_for_label,
+    rules::{
+        Rule, RuleDelimiter, RuleEncoding, RuleEndEarly, RuleFunction, RuleParseError,
+        RuleSavepoint, RuleUnknown,
+    },
+    RuleRegistry,
 };
 
 const WARNING_PREFIX: Lazy<PathBuf> = Lazy::new(|| PathBuf::from("tests/warning"));
@@ -19,7 +28,7 @@ fn s1_unknown() {
     for info in right {
         assert_eq!(info.records().len(), 1);
         let error_uid = info.records().first().unwrap().uid();
-        assert_eq!(*error_uid, RuleUnknown::uid());
+        assert_eq!(*error_uid, RuleUnknown {}.uid());
     }
 }
 
@@ -33,19 +42,24 @@ fn s2_delimiter() {
     for info in right {
         assert_eq!(info.records().len(), 1);
         let error_uid = info.records().first().unwrap().uid();
-        assert_eq!(*error_uid, RuleDelimiter::uid());
+        assert_eq!(*error_uid, RuleDelimiter {}.uid());
     }
 
     let mut path = WARNING_PREFIX.clone();
     path.push("delimiter_bad.sql");
-    let (_summary, right) = check_file(path).unwrap();
+    let (summary, right) = check_file(path).unwrap();
 
-    assert_eq!(right.len(), 1);
-    for info in right {
-        assert_eq!(info.records().len(), 1);
-        let error_uid = info.records().first().unwrap().uid();
-        assert_eq!(*error_uid, RuleDelimiter::uid());
-    }
+    // statements inside the function body are checked as well
+    assert_eq!(*summary.sql_count(), 8);
+    assert_eq!(right.len(), 2);
+    assert_eq!(
+        *right[0].records().first().unwrap().uid(),
+        RuleDelimiter {}.uid()
+    );
+    assert_eq!(
+        *right[1].records().first().unwrap().uid(),
+        RuleFunction {}.uid()
+    );
 }
 
 #[test]
@@ -59,6 +73,111 @@ fn s3_create_select() {
         assert_eq!(info.records().len(), 1);
         let error_uid = info.records().first().unwrap().uid();
 
-        assert_eq!(*error_uid, RuleEndEarly::uid());
+        assert_eq!(*error_uid, RuleEndEarly {}.uid());
     }
 }
+
+#[test]
+fn s4_parse_error() {
+    let mut path = WARNING_PREFIX.clone();
+    path.push("parse_error.sql");
+    let (summary, right) = check_file(path).unwrap();
+
+    assert_eq!(*summary.sql_count(), 2);
+    assert_eq!(right.len(), 2);
+    assert_eq!(
+        *right[0].records().first().unwrap().uid(),
+        RuleParseError {}.uid()
+    );
+    // the parser error is kept to tell why
+    assert!(right[0].records()[0]
+        .description()
+        .ends_with(": sql parser error: Unterminated string literal at Line: 1, Column 28"));
+    assert_eq!(
+        *right[1].records().first().unwrap().uid(),
+        RuleSavepoint {}.uid()
+    );
+}
+
+#[test]
+fn s5_encoding() {
+    // declared by `SET NAMES`, and decoded before parsing
+    let mut path = WARNING_PREFIX.clone();
+    path.push("encoding_gbk.sql");
+    let (summary, right) = check_file(path).unwrap();
+
+    assert_eq!(*summary.sql_count(), 2);
+    assert_eq!(*summary.clean(), 1);
+    assert_eq!(right.len(), 2);
+    assert_eq!(
+        *right[0].records().first().unwrap().uid(),
+        RuleEncoding {}.uid()
+    );
+    assert_eq!(right[0].sql(), "encoding GBK detected by SET NAMES");
+    assert_eq!(right[1].sql(), "SAVEPOINT 保存点");
+
+    // guessed from the content
+    let mut path = WARNING_PREFIX.clone();
+    path.push("encoding_latin1.sql");
+    let (_summary, right) = check_file(&path).unwrap();
+
+    assert_eq!(right.len(), 1);
+    assert_eq!(right[0].sql(), "encoding windows-1252 guessed from content");
+
+    // given rather than detected
+    let registry = RuleRegistry::builtin();
+    let utf8 = encoding_for_label("utf8mb4").unwrap();
+    let (summary, right) = check_file_with_encoding(&registry, &path, Some(utf8)).unwrap();
+
+    assert!(right.is_empty());
+    assert_eq!(*summary.sql_count(), 1);
+
+    // a given encoding is not a finding, even if it is not UTF-8
+    let mut path = WARNING_PREFIX.clone();
+    path.push("encoding_gbk.sql");
+    let gbk = encoding_for_label("gbk").unwrap();
+    let (_summary, right) = check_file_with_encoding(&registry, &path, Some(gbk)).unwrap();
+
+    assert_eq!(right.len(), 1);
+    assert_eq!(right[0].sql(), "SAVEPOINT 保存点");
+}
+
+#[test]
+fn s5_encoding_after_head() {
+    // the head is ASCII only, so its `SET NAMES` tells the encoding of the bytes after it
+    let mut path = WARNING_PREFIX.clone();
+    path.push("encoding_latin1_dump.sql");
+    let (_summary, right) = check_file(&path).unwrap();
+
+    assert_eq!(right.len(), 2);
+    assert_eq!(
+        right[0].sql(),
+        "encoding windows-1252 detected by SET NAMES"
+    );
+    assert_eq!(right[1].sql(), "SAVEPOINT café");
+
+    // nothing declared, so the head guessed as UTF-8 fails on the bytes after it
+    let mut sql = "SELECT 1;\n".repeat(8 * 1024).into_bytes();
+    sql.extend_from_slice(b"SAVEPOINT caf\xe9;\n");
+    let path = std::env::temp_dir().join("tikey_encoding_after_head.sql");
+    std::fs::write(&path, sql).unwrap();
+    let e = check_file(&path).unwrap_err();
+
+    assert!(format!("{:#}", e).contains("invalid UTF-8 at byte 81933"));
+}
+
+#[test]
+fn s5_encoding_utf8_set_names() {
+    // valid UTF-8 is read as UTF-8, whatever charset it declares
+    let sql = "/*!40101 SET NAMES latin1 */;\nSAVEPOINT café;\n";
+    let path = std::env::temp_dir().join("tikey_encoding_utf8_set_names.sql");
+    std::fs::write(&path, sql).unwrap();
+    let (_summary, right) = check_file(&path).unwrap();
+
+    assert_eq!(right.len(), 1);
+    assert_eq!(right[0].sql(), "SAVEPOINT café");
+    assert_eq!(
+        *right[0].records().first().unwrap().uid(),
+        RuleSavepoint {}.uid()
+    );
+}
@@ -0,0 +1,3 @@
+/*!40101 SET NAMES gbk */;
+INSERT INTO t1 (name) SELECT 'ݿǨ' FROM t2;
+SAVEPOINT ;
@@ -0,0 +1,2 @@
+-- liste des commandes, cre  la main
+SELECT 'caf crme', 'dj vu' FROM menu WHERE libell = 'entre';
@@ -0,0 +1,4004 @@
+-- MySQL dump, the head is ASCII and the latin1 comes after it
+/*!40101 SET NAMES latin1 */;
+INSERT INTO t1 VALUES
+(0, 'row 0'),
+(1, 'row 1'),
+(2, 'row 2'),
+(3, 'row 3'),
+(4, 'row 4'),
+(5, 'row 5'),
+(6, 'row 6'),
+(7, 'row 7'),
+(8, 'row 8'),
+(9, 'row 9'),
+(10, 'row 10'),
+(11, 'row 11'),
+(12, 'row 12'),
+(13, 'row 13'),
+(14, 'row 14'),
+(15, 'row 15'),
+(16, 'row 16'),
+(17, 'row 17'),
+(18, 'row 18'),
+(19, 'row 19'),
+(20, 'row 20'),
+(21, 'row 21'),
+(22, 'row 22'),
+(23, 'row 23'),
+(24, 'row 24'),
+(25, 'row 25'),
+(26, 'row 26'),
+(27, 'row 27'),
+(28, 'row 28'),
+(29, 'row 29'),
+(30, 'row 30'),
+(31, 'row 31'),
+(32, 'row 32'),
+(33, 'row 33'),
+(34, 'row 34'),
+(35, 'row 35'),
+(36, 'row 36'),
+(37, 'row 37'),
+(38, 'row 38'),
+(39, 'row 39'),
+(40, 'row 40'),
+(41, 'row 41'),
+(42, 'row 42'),
+(43, 'row 43'),
+(44, 'row 44'),
+(45, 'row 45'),
+(46, 'row 46'),
+(47, 'row 47'),
+(48, 'row 48'),
+(49, 'row 49'),
+(50, 'row 50'),
+(51, 'row 51'),
+(52, 'row 52'),
+(53, 'row 53'),
+(54, 'row 54'),
+(55, 'row 55'),
+(56, 'row 56'),
+(57, 'row 57'),
+(58, 'row 58'),
+(59, 'row 59'),
+(60, 'row 60'),
+(61, 'row 61'),
+(62, 'row 62'),
+(63, 'row 63'),
+(64, 'row 64'),
+(65, 'row 65'),
+(66, 'row 66'),
+(67, 'row 67'),
+(68, 'row 68'),
+(69, 'row 69'),
+(70, 'row 70'),
+(71, 'row 71'),
+(72, 'row 72'),
+(73, 'row 73'),
+(74, 'row 74'),
+(75, 'row 75'),
+(76, 'row 76'),
+(77, 'row 77'),
+(78, 'row 78'),
+(79, 'row 79'),
+(80, 'row 80'),
+(81, 'row 81'),
+(82, 'row 82'),
+(83, 'row 83'),
+(84, 'row 84'),
+(85, 'row 85'),
+(86, 'row 86'),
+(87, 'row 87'),
+(88, 'row 88'),
+(89, 'row 89'),
+(90, 'row 90'),
+(91, 'row 91'),
+(92, 'row 92'),
+(93, 'row 93'),
+(94, 'row 94'),
+(95, 'row 95'),
+(96, 'row 96'),
+(97, 'row 97'),
+(98, 'row 98'),
+(99, 'row 99'),
+(100, 'row 100'),
+(101, 'row 101'),
+(102, 'row 102'),
+(103, 'row 103'),
+(104, 'row 104'),
+(105, 'row 105'),
+(106, 'row 106'),
+(107, 'row 107'),
+(108, 'row 108'),
+(109, 'row 109'),
+(110, 'row 110'),
+(111, 'row 111'),
+(112, 'row 112'),
+(113, 'row 113'),
+(114, 'row 114'),
+(115, 'row 115'),
+(116, 'row 116'),
+(117, 'row 117'),
+(118, 'row 118'),
+(119, 'row 119'),
+(120, 'row 120'),
+(121, 'row 121'),
+(122, 'row 122'),
+(123, 'row 123'),
+(124, 'row 124'),
+(125, 'row 125'),
+(126, 'row 126'),
+(127, 'row 127'),
+(128, 'row 128'),
+(129, 'row 129'),
+(130, 'row 130'),
+(131, 'row 131'),
+(132, 'row 132'),
+(133, 'row 133'),
+(134, 'row 134'),
+(135, 'row 135'),
+(136, 'row 136'),
+(137, 'row 137'),
+(138, 'row 138'),
+(139, 'row 139'),
+(140, 'row 140'),
+(141, 'row 141'),
+(142, 'row 142'),
+(143, 'row 143'),
+(144, 'row 144'),
+(145, 'row 145'),
+(146, 'row 146'),
+(147, 'row 147'),
+(148, 'row 148'),
+(149, 'row 149'),
+(150, 'row 150'),
+(151, 'row 151'),
+(152, 'row 152'),
+(153, 'row 153'),
+(154, 'row 154'),
+(155, 'row 155'),
+(156, 'row 156'),
+(157, 'row 157'),
+(158, 'row 158'),
+(159, 'row 159'),
+(160, 'row 160'),
+(161, 'row 161'),
+(162, 'row 162'),
+(163, 'row 163'),
+(164, 'row 164'),
+(165, 'row 165'),
+(166, 'row 166'),
+(167, 'row 167'),
+(168, 'row 168'),
+(169, 'row 169'),
+(170, 'row 170'),
+(171, 'row 171'),
+(172, 'row 172'),
+(173, 'row 173'),
+(174, 'row 174'),
+(175, 'row 175'),
+(176, 'row 176'),
+(177, 'row 177'),
+(178, 'row 178'),
+(179, 'row 179'),
+(180, 'row 180'),
+(181, 'row 181'),
+(182, 'row 182'),
+(183, 'row 183'),
+(184, 'row 184'),
+(185, 'row 185'),
+(186, 'row 186'),
+(187, 'row 187'),
+(188, 'row 188'),
+(189, 'row 189'),
+(190, 'row 190'),
+(191, 'row 191'),
+(192, 'row 192'),
+(193, 'row 193'),
+(194, 'row 194'),
+(195, 'row 195'),
+(196, 'row 196'),
+(197, 'row 197'),
+(198, 'row 198'),
+(199, 'row 199'),
+(200, 'row 200'),
+(201, 'row 201'),
+(202, 'row 202'),
+(203, 'row 203'),
+(204, 'row 204'),
+(205, 'row 205'),
+(206, 'row 206'),
+(207, 'row 207'),
+(208, 'row 208'),
+(209, 'row 209'),
+(210, 'row 210'),
+(211, 'row 211'),
+(212, 'row 212'),
+(213, 'row 213'),
+(214, 'row 214'),
+(215, 'row 215'),
+(216, 'row 216'),
+(217, 'row 217'),
+(218, 'row 218'),
+(219, 'row 219'),
+(220, 'row 220'),
+(221, 'row 221'),
+(222, 'row 222'),
+(223, 'row 223'),
+(224, 'row 224'),
+(225, 'row 225'),
+(226, 'row 226'),
+(227, 'row 227'),
+(228, 'row 228'),
+(229, 'row 229'),
+(230, 'row 230'),
+(231, 'row 231'),
+(232, 'row 232'),
+(233, 'row 233'),
+(234, 'row 234'),
+(235, 'row 235'),
+(236, 'row 236'),
+(237, 'row 237'),
+(238, 'row 238'),
+(239, 'row 239'),
+(240, 'row 240'),
+(241, 'row 241'),
+(242, 'row 242'),
+(243, 'row 243'),
+(244, 'row 244'),
+(245, 'row 245'),
+(246, 'row 246'),
+(247, 'row 247'),
+(248, 'row 248'),
+(249, 'row 249'),
+(250, 'row 250'),
+(251, 'row 251'),
+(252, 'row 252'),
+(253, 'row 253'),
+(254, 'row 254'),
+(255, 'row 255'),
+(256, 'row 256'),
+(257, 'row 257'),
+(258, 'row 258'),
+(259, 'row 259'),
+(260, 'row 260'),
+(261, 'row 261'),
+(262, 'row 262'),
+(263, 'row 263'),
+(264, 'row 264'),
+(265, 'row 265'),
+(266, 'row 266'),
+(267, 'row 267'),
+(268, 'row 268'),
+(269, 'row 269'),
+(270, 'row 270'),
+(271, 'row 271'),
+(272, 'row 272'),
+(273, 'row 273'),
+(274, 'row 274'),
+(275, 'row 275'),
+(276, 'row 276'),
+(277, 'row 277'),
+(278, 'row 278'),
+(279, 'row 279'),
+(280, 'row 280'),
+(281, 'row 281'),
+(282, 'row 282'),
+(283, 'row 283'),
+(284, 'row 284'),
+(285, 'row 285'),
+(286, 'row 286'),
+(287, 'row 287'),
+(288, 'row 288'),
+(289, 'row 289'),
+(290, 'row 290'),
+(291, 'row 291'),
+(292, 'row 292'),
+(293, 'row 293'),
+(294, 'row 294'),
+(295, 'row 295'),
+(296, 'row 296'),
+(297, 'row 297'),
+(298, 'row 298'),
+(299, 'row 299'),
+(300, 'row 300'),
+(301, 'row 301'),
+(302, 'row 302'),
+(303, 'row 303'),
+(304, 'row 304'),
+(305, 'row 305'),
+(306, 'row 306'),
+(307, 'row 307'),
+(308, 'row 308'),
+(309, 'row 309'),
+(310, 'row 310'),
+(311, 'row 311'),
+(312, 'row 312'),
+(313, 'row 313'),
+(314, 'row 314'),
+(315, 'row 315'),
+(316, 'row 316'),
+(317, 'row 317'),
+(318, 'row 318'),
+(319, 'row 319'),
+(320, 'row 320'),
+(321, 'row 321'),
+(322, 'row 322'),
+(323, 'row 323'),
+(324, 'row 324'),
+(325, 'row 325'),
+(326, 'row 326'),
+(327, 'row 327'),
+(328, 'row 328'),
+(329, 'row 329'),
+(330, 'row 330'),
+(331, 'row 331'),
+(332, 'row 332'),
+(333, 'row 333'),
+(334, 'row 334'),
+(335, 'row 335'),
+(336, 'row 336'),
+(337, 'row 337'),
+(338, 'row 338'),
+(339, 'row 339'),
+(340, 'row 340'),
+(341, 'row 341'),
+(342, 'row 342'),
+(343, 'row 343'),
+(344, 'row 344'),
+(345, 'row 345'),
+(346, 'row 346'),
+(347, 'row 347'),
+(348, 'row 348'),
+(349, 'row 349'),
+(350, 'row 350'),
+(351, 'row 351'),
+(352, 'row 352'),
+(353, 'row 353'),
+(354, 'row 354'),
+(355, 'row 355'),
+(356, 'row 356'),
+(357, 'row 357'),
+(358, 'row 358'),
+(359, 'row 359'),
+(360, 'row 360'),
+(361, 'row 361'),
+(362, 'row 362'),
+(363, 'row 363'),
+(364, 'row 364'),
+(365, 'row 365'),
+(366, 'row 366'),
+(367, 'row 367'),
+(368, 'row 368'),
+(369, 'row 369'),
+(370, 'row 370'),
+(371, 'row 371'),
+(372, 'row 372'),
+(373, 'row 373'),
+(374, 'row 374'),
+(375, 'row 375'),
+(376, 'row 376'),
+(377, 'row 377'),
+(378, 'row 378'),
+(379, 'row 379'),
+(380, 'row 380'),
+(381, 'row 381'),
+(382, 'row 382'),
+(383, 'row 383'),
+(384, 'row 384'),
+(385, 'row 385'),
+(386, 'row 386'),
+(387, 'row 387'),
+(388, 'row 388'),
+(389, 'row 389'),
+(390, 'row 390'),
+(391, 'row 391'),
+(392, 'row 392'),
+(393, 'row 393'),
+(394, 'row 394'),
+(395, 'row 395'),
+(396, 'row 396'),
+(397, 'row 397'),
+(398, 'row 398'),
+(399, 'row 399'),
+(400, 'row 400'),
+(401, 'row 401'),
+(402, 'row 402'),
+(403, 'row 403'),
+(404, 'row 404'),
+(405, 'row 405'),
+(406, 'row 406'),
+(407, 'row 407'),
+(408, 'row 408'),
+(409, 'row 409'),
+(410, 'row 410'),
+(411, 'row 411'),
+(412, 'row 412'),
+(413, 'row 413'),
+(414, 'row 414'),
+(415, 'row 415'),
+(416, 'row 416'),
+(417, 'row 417'),
+(418, 'row 418'),
+(419, 'row 419'),
+(420, 'row 420'),
+(421, 'row 421'),
+(422, 'row 422'),
+(423, 'row 423'),
+(424, 'row 424'),
+(425, 'row 425'),
+(426, 'row 426'),
+(427, 'row 427'),
+(428, 'row 428'),
+(429, 'row 429'),
+(430, 'row 430'),
+(431, 'row 431'),
+(432, 'row 432'),
+(433, 'row 433'),
+(434, 'row 434'),
+(435, 'row 435'),
+(436, 'row 436'),
+(437, 'row 437'),
+(438, 'row 438'),
+(439, 'row 439'),
+(440, 'row 440'),
+(441, 'row 441'),
+(442, 'row 442'),
+(443, 'row 443'),
+(444, 'row 444'),
+(445, 'row 445'),
+(446, 'row 446'),
+(447, 'row 447'),
+(448, 'row 448'),
+(449, 'row 449'),
+(450, 'row 450'),
+(451, 'row 451'),
+(452, 'row 452'),
+(453, 'row 453'),
+(454, 'row 454'),
+(455, 'row 455'),
+(456, 'row 456'),
+(457, 'row 457'),
+(458, 'row 458'),
+(459, 'row 459'),
+(460, 'row 460'),
+(461, 'row 461'),
+(462, 'row 462'),
+(463, 'row 463'),
+(464, 'row 464'),
+(465, 'row 465'),
+(466, 'row 466'),
+(467, 'row 467'),
+(468, 'row 468'),
+(469, 'row 469'),
+(470, 'row 470'),
+(471, 'row 471'),
+(472, 'row 472'),
+(473, 'row 473'),
+(474, 'row 474'),
+(475, 'row 475'),
+(476, 'row 476'),
+(477, 'row 477'),
+(478, 'row 478'),
+(479, 'row 479'),
+(480, 'row 480'),
+(481, 'row 481'),
+(482, 'row 482'),
+(483, 'row 483'),
+(484, 'row 484'),
+(485, 'row 485'),
+(486, 'row 486'),
+(487, 'row 487'),
+(488, 'row 488'),
+(489, 'row 489'),
+(490, 'row 490'),
+(491, 'row 491'),
+(492, 'row 492'),
+(493, 'row 493'),
+(494, 'row 494'),
+(495, 'row 495'),
+(496, 'row 496'),
+(497, 'row 497'),
+(498, 'row 498'),
+(499, 'row 499'),
+(500, 'row 500'),
+(501, 'row 501'),
+(502, 'row 502'),
+(503, 'row 503'),
+(504, 'row 504'),
+(505, 'row 505'),
+(506, 'row 506'),
+(507, 'row 507'),
+(508, 'row 508'),
+(509, 'row 509'),
+(510, 'row 510'),
+(511, 'row 511'),
+(512, 'row 512'),
+(513, 'row 513'),
+(514, 'row 514'),
+(515, 'row 515'),
+(516, 'row 516'),
+(517, 'row 517'),
+(518, 'row 518'),
+(519, 'row 519'),
+(520, 'row 520'),
+(521, 'row 521'),
+(522, 'row 522'),
+(523, 'row 523'),
+(524, 'row 524'),
+(525, 'row 525'),
+(526, 'row 526'),
+(527, 'row 527'),
+(528, 'row 528'),
+(529, 'row 529'),
+(530, 'row 530'),
+(531, 'row 531'),
+(532, 'row 532'),
+(533, 'row 533'),
+(534, 'row 534'),
+(535, 'row 535'),
+(536, 'row 536'),
+(537, 'row 537'),
+(538, 'row 538'),
+(539, 'row 539'),
+(540, 'row 540'),
+(541, 'row 541'),
+(542, 'row 542'),
+(543, 'row 543'),
+(544, 'row 544'),
+(545, 'row 545'),
+(546, 'row 546'),
+(547, 'row 547'),
+(548, 'row 548'),
+(549, 'row 549'),
+(550, 'row 550'),
+(551, 'row 551'),
+(552, 'row 552'),
+(553, 'row 553'),
+(554, 'row 554'),
+(555, 'row 555'),
+(556, 'row 556'),
+(557, 'row 557'),
+(558, 'row 558'),
+(559, 'row 559'),
+(560, 'row 560'),
+(561, 'row 561'),
+(562, 'row 562'),
+(563, 'row 563'),
+(564, 'row 564'),
+(565, 'row 565'),
+(566, 'row 566'),
+(567, 'row 567'),
+(568, 'row 568'),
+(569, 'row 569'),
+(570, 'row 570'),
+(571, 'row 571'),
+(572, 'row 572'),
+(573, 'row 573'),
+(574, 'row 574'),
+(575, 'row 575'),
+(576, 'row 576'),
+(577, 'row 577'),
+(578, 'row 578'),
+(579, 'row 579'),
+(580, 'row 580'),
+(581, 'row 581'),
+(582, 'row 582'),
+(583, 'row 583'),
+(584, 'row 584'),
+(585, 'row 585'),
+(586, 'row 586'),
+(587, 'row 587'),
+(588, 'row 588'),
+(589, 'row 589'),
+(590, 'row 590'),
+(591, 'row 591'),
+(592, 'row 592'),
+(593, 'row 593'),
+(594, 'row 594'),
+(595, 'row 595'),
+(596, 'row 596'),
+(597, 'row 597'),
+(598, 'row 598'),
+(599, 'row 599'),
+(600, 'row 600'),
+(601, 'row 601'),
+(602, 'row 602'),
+(603, 'row 603'),
+(604, 'row 604'),
+(605, 'row 605'),
+(606, 'row 606'),
+(607, 'row 607'),
+(608, 'row 608'),
+(609, 'row 609'),
+(610, 'row 610'),
+(611, 'row 611'),
+(612, 'row 612'),
+(613, 'row 613'),
+(614, 'row 614'),
+(615, 'row 615'),
+(616, 'row 616'),
+(617, 'row 617'),
+(618, 'row 618'),
+(619, 'row 619'),
+(620, 'row 620'),
+(621, 'row 621'),
+(622, 'row 622'),
+(623, 'row 623'),
+(624, 'row 624'),
+(625, 'row 625'),
+(626, 'row 626'),
+(627, 'row 627'),
+(628, 'row 628'),
+(629, 'row 629'),
+(630, 'row 630'),
+(631, 'row 631'),
+(632, 'row 632'),
+(633, 'row 633'),
+(634, 'row 634'),
+(635, 'row 635'),
+(636, 'row 636'),
+(637, 'row 637'),
+(638, 'row 638'),
+(639, 'row 639'),
+(640, 'row 640'),
+(641, 'row 641'),
+(642, 'row 642'),
+(643, 'row 643'),
+(644, 'row 644'),
+(645, 'row 645'),
+(646, 'row 646'),
+(647, 'row 647'),
+(648, 'row 648'),
+(649, 'row 649'),
+(650, 'row 650'),
+(651, 'row 651'),
+(652, 'row 652'),
+(653, 'row 653'),
+(654, 'row 654'),
+(655, 'row 655'),
+(656, 'row 656'),
+(657, 'row 657'),
+(658, 'row 658'),
+(659, 'row 659'),
+(660, 'row 660'),
+(661, 'row 661'),
+(662, 'row 662'),
+(663, 'row 663'),
+(664, 'row 664'),
+(665, 'row 665'),
+(666, 'row 666'),
+(667, 'row 667'),
+(668, 'row 668'),
+(669, 'row 669'),
+(670, 'row 670'),
+(671, 'row 671'),
+(672, 'row 672'),
+(673, 'row 673'),
+(674, 'row 674'),
+(675, 'row 675'),
+(676, 'row 676'),
+(677, 'row 677'),
+(678, 'row 678'),
+(679, 'row 679'),
+(680, 'row 680'),
+(681, 'row 681'),
+(682, 'row 682'),
+(683, 'row 683'),
+(684, 'row 684'),
+(685, 'row 685'),
+(686, 'row 686'),
+(687, 'row 687'),
+(688, 'row 688'),
+(689, 'row 689'),
+(690, 'row 690'),
+(691, 'row 691'),
+(692, 'row 692'),
+(693, 'row 693'),
+(694, 'row 694'),
+(695, 'row 695'),
+(696, 'row 696'),
+(697, 'row 697'),
+(698, 'row 698'),
+(699, 'row 699'),
+(700, 'row 700'),
+(701, 'row 701'),
+(702, 'row 702'),
+(703, 'row 703'),
+(704, 'row 704'),
+(705, 'row 705'),
+(706, 'row 706'),
+(707, 'row 707'),
+(708, 'row 708'),
+(709, 'row 709'),
+(710, 'row 710'),
+(711, 'row 711'),
+(712, 'row 712'),
+(713, 'row 713'),
+(714, 'row 714'),
+(715, 'row 715'),
+(716, 'row 716'),
+(717, 'row 717'),
+(718, 'row 718'),
+(719, 'row 719'),
+(720, 'row 720'),
+(721, 'row 721'),
+(722, 'row 722'),
+(723, 'row 723'),
+(724, 'row 724'),
+(725, 'row 725'),
+(726, 'row 726'),
+(727, 'row 727'),
+(728, 'row 728'),
+(729, 'row 729'),
+(730, 'row 730'),
+(731, 'row 731'),
+(732, 'row 732'),
+(733, 'row 733'),
+(734, 'row 734'),
+(735, 'row 735'),
+(736, 'row 736'),
+(737, 'row 737'),
+(738, 'row 738'),
+(739, 'row 739'),
+(740, 'row 740'),
+(741, 'row 741'),
+(742, 'row 742'),
+(743, 'row 743'),
+(744, 'row 744'),
+(745, 'row 745'),
+(746, 'row 746'),
+(747, 'row 747'),
+(748, 'row 748'),
+(749, 'row 749'),
+(750, 'row 750'),
+(751, 'row 751'),
+(752, 'row 752'),
+(753, 'row 753'),
+(754, 'row 754'),
+(755, 'row 755'),
+(756, 'row 756'),
+(757, 'row 757'),
+(758, 'row 758'),
+(759, 'row 759'),
+(760, 'row 760'),
+(761, 'row 761'),
+(762, 'row 762'),
+(763, 'row 763'),
+(764, 'row 764'),
+(765, 'row 765'),
+(766, 'row 766'),
+(767, 'row 767'),
+(768, 'row 768'),
+(769, 'row 769'),
+(770, 'row 770'),
+(771, 'row 771'),
+(772, 'row 772'),
+(773, 'row 773'),
+(774, 'row 774'),
+(775, 'row 775'),
+(776, 'row 776'),
+(777, 'row 777'),
+(778, 'row 778'),
+(779, 'row 779'),
+(780, 'row 780'),
+(781, 'row 781'),
+(782, 'row 782'),
+(783, 'row 783'),
+(784, 'row 784'),
+(785, 'row 785'),
+(786, 'row 786'),
+(787, 'row 787'),
+(788, 'row 788'),
+(789, 'row 789'),
+(790, 'row 790'),
+(791, 'row 791'),
+(792, 'row 792'),
+(793, 'row 793'),
+(794, 'row 794'),
+(795, 'row 795'),
+(796, 'row 796'),
+(797, 'row 797'),
+(798, 'row 798'),
+(799, 'row 799'),
+(800, 'row 800'),
+(801, 'row 801'),
+(802, 'row 802'),
+(803, 'row 803'),
+(804, 'row 804'),
+(805, 'row 805'),
+(806, 'row 806'),
+(807, 'row 807'),
+(808, 'row 808'),
+(809, 'row 809'),
+(810, 'row 810'),
+(811, 'row 811'),
+(812, 'row 812'),
+(813, 'row 813'),
+(814, 'row 814'),
+(815, 'row 815'),
+(816, 'row 816'),
+(817, 'row 817'),
+(818, 'row 818'),
+(819, 'row 819'),
+(820, 'row 820'),
+(821, 'row 821'),
+(822, 'row 822'),
+(823, 'row 823'),
+(824, 'row 824'),
+(825, 'row 825'),
+(826, 'row 826'),
+(827, 'row 827'),
+(828, 'row 828'),
+(829, 'row 829'),
+(830, 'row 830'),
+(831, 'row 831'),
+(832, 'row 832'),
+(833, 'row 833'),
+(834, 'row 834'),
+(835, 'row 835'),
+(836, 'row 836'),
+(837, 'row 837'),
+(838, 'row 838'),
+(839, 'row 839'),
+(840, 'row 840'),
+(841, 'row 841'),
+(842, 'row 842'),
+(843, 'row 843'),
+(844, 'row 844'),
+(845, 'row 845'),
+(846, 'row 846'),
+(847, 'row 847'),
+(848, 'row 848'),
+(849, 'row 849'),
+(850, 'row 850'),
+(851, 'row 851'),
+(852, 'row 852'),
+(853, 'row 853'),
+(854, 'row 854'),
+(855, 'row 855'),
+(856, 'row 856'),
+(857, 'row 857'),
+(858, 'row 858'),
+(859, 'row 859'),
+(860, 'row 860'),
+(861, 'row 861'),
+(862, 'row 862'),
+(863, 'row 863'),
+(864, 'row 864'),
+(865, 'row 865'),
+(866, 'row 866'),
+(867, 'row 867'),
+(868, 'row 868'),
+(869, 'row 869'),
+(870, 'row 870'),
+(871, 'row 871'),
+(872, 'row 872'),
+(873, 'row 873'),
+(874, 'row 874'),
+(875, 'row 875'),
+(876, 'row 876'),
+(877, 'row 877'),
+(878, 'row 878'),
+(879, 'row 879'),
+(880, 'row 880'),
+(881, 'row 881'),
+(882, 'row 882'),
+(883, 'row 883'),
+(884, 'row 884'),
+(885, 'row 885'),
+(886, 'row 886'),
+(887, 'row 887'),
+(888, 'row 888'),
+(889, 'row 889'),
+(890, 'row 890'),
+(891, 'row 891'),
+(892, 'row 892'),
+(893, 'row 893'),
+(894, 'row 894'),
+(895, 'row 895'),
+(896, 'row 896'),
+(897, 'row 897'),
+(898, 'row 898'),
+(899, 'row 899'),
+(900, 'row 900'),
+(901, 'row 901'),
+(902, 'row 902'),
+(903, 'row 903'),
+(904, 'row 904'),
+(905, 'row 905'),
+(906, 'row 906'),
+(907, 'row 907'),
+(908, 'row 908'),
+(909, 'row 909'),
+(910, 'row 910'),
+(911, 'row 911'),
+(912, 'row 912'),
+(913, 'row 913'),
+(914, 'row 914'),
+(915, 'row 915'),
+(916, 'row 916'),
+(917, 'row 917'),
+(918, 'row 918'),
+(919, 'row 919'),
+(920, 'row 920'),
+(921, 'row 921'),
+(922, 'row 922'),
+(923, 'row 923'),
+(924, 'row 924'),
+(925, 'row 925'),
+(926, 'row 926'),
+(927, 'row 927'),
+(928, 'row 928'),
+(929, 'row 929'),
+(930, 'row 930'),
+(931, 'row 931'),
+(932, 'row 932'),
+(933, 'row 933'),
+(934, 'row 934'),
+(935, 'row 935'),
+(936, 'row 936'),
+(937, 'row 937'),
+(938, 'row 938'),
+(939, 'row 939'),
+(940, 'row 940'),
+(941, 'row 941'),
+(942, 'row 942'),
+(943, 'row 943'),
+(944, 'row 944'),
+(945, 'row 945'),
+(946, 'row 946'),
+(947, 'row 947'),
+(948, 'row 948'),
+(949, 'row 949'),
+(950, 'row 950'),
+(951, 'row 951'),
+(952, 'row 952'),
+(953, 'row 953'),
+(954, 'row 954'),
+(955, 'row 955'),
+(956, 'row 956'),
+(957, 'row 957'),
+(958, 'row 958'),
+(959, 'row 959'),
+(960, 'row 960'),
+(961, 'row 961'),
+(962, 'row 962'),
+(963, 'row 963'),
+(964, 'row 964'),
+(965, 'row 965'),
+(966, 'row 966'),
+(967, 'row 967'),
+(968, 'row 968'),
+(969, 'row 969'),
+(970, 'row 970'),
+(971, 'row 971'),
+(972, 'row 972'),
+(973, 'row 973'),
+(974, 'row 974'),
+(975, 'row 975'),
+(976, 'row 976'),
+(977, 'row 977'),
+(978, 'row 978'),
+(979, 'row 979'),
+(980, 'row 980'),
+(981, 'row 981'),
+(982, 'row 982'),
+(983, 'row 983'),
+(984, 'row 984'),
+(985, 'row 985'),
+(986, 'row 986'),
+(987, 'row 987'),
+(988, 'row 988'),
+(989, 'row 989'),
+(990, 'row 990'),
+(991, 'row 991'),
+(992, 'row 992'),
+(993, 'row 993'),
+(994, 'row 994'),
+(995, 'row 995'),
+(996, 'row 996'),
+(997, 'row 997'),
+(998, 'row 998'),
+(999, 'row 999'),
+(1000, 'row 1000'),
+(1001, 'row 1001'),
+(1002, 'row 1002'),
+(1003, 'row 1003'),
+(1004, 'row 1004'),
+(1005, 'row 1005'),
+(1006, 'row 1006'),
+(1007, 'row 1007'),
+(1008, 'row 1008'),
+(1009, 'row 1009'),
+(1010, 'row 1010'),
+(1011, 'row 1011'),
+(1012, 'row 1012'),
+(1013, 'row 1013'),
+(1014, 'row 1014'),
+(1015, 'row 1015'),
+(1016, 'row 1016'),
+(1017, 'row 1017'),
+(1018, 'row 1018'),
+(1019, 'row 1019'),
+(1020, 'row 1020'),
+(1021, 'row 1021'),
+(1022, 'row 1022'),
+(1023, 'row 1023'),
+(1024, 'row 1024'),
+(1025, 'row 1025'),
+(1026, 'row 1026'),
+(1027, 'row 1027'),
+(1028, 'row 1028'),
+(1029, 'row 1029'),
+(1030, 'row 1030'),
+(1031, 'row 1031'),
+(1032, 'row 1032'),
+(1033, 'row 1033'),
+(1034, 'row 1034'),
+(1035, 'row 1035'),
+(1036, 'row 1036'),
+(1037, 'row 1037'),
+(1038, 'row 1038'),
+(1039, 'row 1039'),
+(1040, 'row 1040'),
+(1041, 'row 1041'),
+(1042, 'row 1042'),
+(1043, 'row 1043'),
+(1044, 'row 1044'),
+(1045, 'row 1045'),
+(1046, 'row 1046'),
+(1047, 'row 1047'),
+(1048, 'row 1048'),
+(1049, 'row 1049'),
+(1050, 'row 1050'),
+(1051, 'row 1051'),
+(1052, 'row 1052'),
+(1053, 'row 1053'),
+(1054, 'row 1054'),
+(1055, 'row 1055'),
+(1056, 'row 1056'),
+(1057, 'row 1057'),
+(1058, 'row 1058'),
+(1059, 'row 1059'),
+(1060, 'row 1060'),
+(1061, 'row 1061'),
+(1062, 'row 1062'),
+(1063, 'row 1063'),
+(1064, 'row 1064'),
+(1065, 'row 1065'),
+(1066, 'row 1066'),
+(1067, 'row 1067'),
+(1068, 'row 1068'),
+(1069, 'row 1069'),
+(1070, 'row 1070'),
+(1071, 'row 1071'),
+(1072, 'row 1072'),
+(1073, 'row 1073'),
+(1074, 'row 1074'),
+(1075, 'row 1075'),
+(1076, 'row 1076'),
+(1077, 'row 1077'),
+(1078, 'row 1078'),
+(1079, 'row 1079'),
+(1080, 'row 1080'),
+(1081, 'row 1081'),
+(1082, 'row 1082'),
+(1083, 'row 1083'),
+(1084, 'row 1084'),
+(1085, 'row 1085'),
+(1086, 'row 1086'),
+(1087, 'row 1087'),
+(1088, 'row 1088'),
+(1089, 'row 1089'),
+(1090, 'row 1090'),
+(1091, 'row 1091'),
+(1092, 'row 1092'),
+(1093, 'row 1093'),
+(1094, 'row 1094'),
+(1095, 'row 1095'),
+(1096, 'row 1096'),
+(1097, 'row 1097'),
+(1098, 'row 1098'),
+(1099, 'row 1099'),
+(1100, 'row 1100'),
+(1101, 'row 1101'),
+(1102, 'row 1102'),
+(1103, 'row 1103'),
+(1104, 'row 1104'),
+(1105, 'row 1105'),
+(1106, 'row 1106'),
+(1107, 'row 1107'),
+(1108, 'row 1108'),
+(1109, 'row 1109'),
+(1110, 'row 1110'),
+(1111, 'row 1111'),
+(1112, 'row 1112'),
+(1113, 'row 1113'),
+(1114, 'row 1114'),
+(1115, 'row 1115'),
+(1116, 'row 1116'),
+(1117, 'row 1117'),
+(1118, 'row 1118'),
+(1119, 'row 1119'),
+(1120, 'row 1120'),
+(1121, 'row 1121'),
+(1122, 'row 1122'),
+(1123, 'row 1123'),
+(1124, 'row 1124'),
+(1125, 'row 1125'),
+(1126, 'row 1126'),
+(1127, 'row 1127'),
+(1128, 'row 1128'),
+(1129, 'row 1129'),
+(1130, 'row 1130'),
+(1131, 'row 1131'),
+(1132, 'row 1132'),
+(1133, 'row 1133'),
+(1134, 'row 1134'),
+(1135, 'row 1135'),
+(1136, 'row 1136'),
+(1137, 'row 1137'),
+(1138, 'row 1138'),
+(1139, 'row 1139'),
+(1140, 'row 1140'),
+(1141, 'row 1141'),
+(1142, 'row 1142'),
+(1143, 'row 1143'),
+(1144, 'row 1144'),
+(1145, 'row 1145'),
+(1146, 'row 1146'),
+(1147, 'row 1147'),
+(1148, 'row 1148'),
+(1149, 'row 1149'),
+(1150, 'row 1150'),
+(1151, 'row 1151'),
+(1152, 'row 1152'),
+(1153, 'row 1153'),
+(1154, 'row 1154'),
+(1155, 'row 1155'),
+(1156, 'row 1156'),
+(1157, 'row 1157'),
+(1158, 'row 1158'),
+(1159, 'row 1159'),
+(1160, 'row 1160'),
+(1161, 'row 1161'),
+(1162, 'row 1162'),
+(1163, 'row 1163'),
+(1164, 'row 1164'),
+(1165, 'row 1165'),
+(1166, 'row 1166'),
+(1167, 'row 1167'),
+(1168, 'row 1168'),
+(1169, 'row 1169'),
+(1170, 'row 1170'),
+(1171, 'row 1171'),
+(1172, 'row 1172'),
+(1173, 'row 1173'),
+(1174, 'row 1174'),
+(1175, 'row 1175'),
+(1176, 'row 1176'),
+(1177, 'row 1177'),
+(1178, 'row 1178'),
+(1179, 'row 1179'),
+(1180, 'row 1180'),
+(1181, 'row 1181'),
+(1182, 'row 1182'),
+(1183, 'row 1183'),
+(1184, 'row 1184'),
+(1185, 'row 1185'),
+(1186, 'row 1186'),
+(1187, 'row 1187'),
+(1188, 'row 1188'),
+(1189, 'row 1189'),
+(1190, 'row 1190'),
+(1191, 'row 1191'),
+(1192, 'row 1192'),
+(1193, 'row 1193'),
+(1194, 'row 1194'),
+(1195, 'row 1195'),
+(1196, 'row 1196'),
+(1197, 'row 1197'),
+(1198, 'row 1198'),
+(1199, 'row 1199'),
+(1200, 'row 1200'),
+(1201, 'row 1201'),
+(1202, 'row 1202'),
+(1203, 'row 1203'),
+(1204, 'row 1204'),
+(1205, 'row 1205'),
+(1206, 'row 1206'),
+(1207, 'row 1207'),
+(1208, 'row 1208'),
+(1209, 'row 1209'),
+(1210, 'row 1210'),
+(1211, 'row 1211'),
+(1212, 'row 1212'),
+(1213, 'row 1213'),
+(1214, 'row 1214'),
+(1215, 'row 1215'),
+(1216, 'row 1216'),
+(1217, 'row 1217'),
+(1218, 'row 1218'),
+(1219, 'row 1219'),
+(1220, 'row 1220'),
+(1221, 'row 1221'),
+(1222, 'row 1222'),
+(1223, 'row 1223'),
+(1224, 'row 1224'),
+(1225, 'row 1225'),
+(1226, 'row 1226'),
+(1227, 'row 1227'),
+(1228, 'row 1228'),
+(1229, 'row 1229'),
+(1230, 'row 1230'),
+(1231, 'row 1231'),
+(1232, 'row 1232'),
+(1233, 'row 1233'),
+(1234, 'row 1234'),
+(1235, 'row 1235'),
+(1236, 'row 1236'),
+(1237, 'row 1237'),
+(1238, 'row 1238'),
+(1239, 'row 1239'),
+(1240, 'row 1240'),
+(1241, 'row 1241'),
+(1242, 'row 1242'),
+(1243, 'row 1243'),
+(1244, 'row 1244'),
+(1245, 'row 1245'),
+(1246, 'row 1246'),
+(1247, 'row 1247'),
+(1248, 'row 1248'),
+(1249, 'row 1249'),
+(1250, 'row 1250'),
+(1251, 'row 1251'),
+(1252, 'row 1252'),
+(1253, 'row 1253'),
+(1254, 'row 1254'),
+(1255, 'row 1255'),
+(1256, 'row 1256'),
+(1257, 'row 1257'),
+(1258, 'row 1258'),
+(1259, 'row 1259'),
+(1260, 'row 1260'),
+(1261, 'row 1261'),
+(1262, 'row 1262'),
+(1263, 'row 1263'),
+(1264, 'row 1264'),
+(1265, 'row 1265'),
+(1266, 'row 1266'),
+(1267, 'row 1267'),
+(1268, 'row 1268'),
+(1269, 'row 1269'),
+(1270, 'row 1270'),
+(1271, 'row 1271'),
+(1272, 'row 1272'),
+(1273, 'row 1273'),
+(1274, 'row 1274'),
+(1275, 'row 1275'),
+(1276, 'row 1276'),
+(1277, 'row 1277'),
+(1278, 'row 1278'),
+(1279, 'row 1279'),
+(1280, 'row 1280'),
+(1281, 'row 1281'),
+(1282, 'row 1282'),
+(1283, 'row 1283'),
+(1284, 'row 1284'),
+(1285, 'row 1285'),
+(1286, 'row 1286'),
+(1287, 'row 1287'),
+(1288, 'row 1288'),
+(1289, 'row 1289'),
+(1290, 'row 1290'),
+(1291, 'row 1291'),
+(1292, 'row 1292'),
+(1293, 'row 1293'),
+(1294, 'row 1294'),
+(1295, 'row 1295'),
+(1296, 'row 1296'),
+(1297, 'row 1297'),
+(1298, 'row 1298'),
+(1299, 'row 1299'),
+(1300, 'row 1300'),
+(1301, 'row 1301'),
+(1302, 'row 1302'),
+(1303, 'row 1303'),
+(1304, 'row 1304'),
+(1305, 'row 1305'),
+(1306, 'row 1306'),
+(1307, 'row 1307'),
+(1308, 'row 1308'),
+(1309, 'row 1309'),
+(1310, 'row 1310'),
+(1311, 'row 1311'),
+(1312, 'row 1312'),
+(1313, 'row 1313'),
+(1314, 'row 1314'),
+(1315, 'row 1315'),
+(1316, 'row 1316'),
+(1317, 'row 1317'),
+(1318, 'row 1318'),
+(1319, 'row 1319'),
+(1320, 'row 1320'),
+(1321, 'row 1321'),
+(1322, 'row 1322'),
+(1323, 'row 1323'),
+(1324, 'row 1324'),
+(1325, 'row 1325'),
+(1326, 'row 1326'),
+(1327, 'row 1327'),
+(1328, 'row 1328'),
+(1329, 'row 1329'),
+(1330, 'row 1330'),
+(1331, 'row 1331'),
+(1332, 'row 1332'),
+(1333, 'row 1333'),
+(1334, 'row 1334'),
+(1335, 'row 1335'),
+(1336, 'row 1336'),
+(1337, 'row 1337'),
+(1338, 'row 1338'),
+(1339, 'row 1339'),
+(1340, 'row 1340'),
+(1341, 'row 1341'),
+(1342, 'row 1342'),
+(1343, 'row 1343'),
+(1344, 'row 1344'),
+(1345, 'row 1345'),
+(1346, 'row 1346'),
+(1347, 'row 1347'),
+(1348, 'row 1348'),
+(1349, 'row 1349'),
+(1350, 'row 1350'),
+(1351, 'row 1351'),
+(1352, 'row 1352'),
+(1353, 'row 1353'),
+(1354, 'row 1354'),
+(1355, 'row 1355'),
+(1356, 'row 1356'),
+(1357, 'row 1357'),
+(1358, 'row 1358'),
+(1359, 'row 1359'),
+(1360, 'row 1360'),
+(1361, 'row 1361'),
+(1362, 'row 1362'),
+(1363, 'row 1363'),
+(1364, 'row 1364'),
+(1365, 'row 1365'),
+(1366, 'row 1366'),
+(1367, 'row 1367'),
+(1368, 'row 1368'),
+(1369, 'row 1369'),
+(1370, 'row 1370'),
+(1371, 'row 1371'),
+(1372, 'row 1372'),
+(1373, 'row 1373'),
+(1374, 'row 1374'),
+(1375, 'row 1375'),
+(1376, 'row 1376'),
+(1377, 'row 1377'),
+(1378, 'row 1378'),
+(1379, 'row 1379'),
+(1380, 'row 1380'),
+(1381, 'row 1381'),
+(1382, 'row 1382'),
+(1383, 'row 1383'),
+(1384, 'row 1384'),
+(1385, 'row 1385'),
+(1386, 'row 1386'),
+(1387, 'row 1387'),
+(1388, 'row 1388'),
+(1389, 'row 1389'),
+(1390, 'row 1390'),
+(1391, 'row 1391'),
+(1392, 'row 1392'),
+(1393, 'row 1393'),
+(1394, 'row 1394'),
+(1395, 'row 1395'),
+(1396, 'row 1396'),
+(1397, 'row 1397'),
+(1398, 'row 1398'),
+(1399, 'row 1399'),
+(1400, 'row 1400'),
+(1401, 'row 1401'),
+(1402, 'row 1402'),
+(1403, 'row 1403'),
+(1404, 'row 1404'),
+(1405, 'row 1405'),
+(1406, 'row 1406'),
+(1407, 'row 1407'),
+(1408, 'row 1408'),
+(1409, 'row 1409'),
+(1410, 'row 1410'),
+(1411, 'row 1411'),
+(1412, 'row 1412'),
+(1413, 'row 1413'),
+(1414, 'row 1414'),
+(1415, 'row 1415'),
+(1416, 'row 1416'),
+(1417, 'row 1417'),
+(1418, 'row 1418'),
+(1419, 'row 1419'),
+(1420, 'row 1420'),
+(1421, 'row 1421'),
+(1422, 'row 1422'),
+(1423, 'row 1423'),
+(1424, 'row 1424'),
+(1425, 'row 1425'),
+(1426, 'row 1426'),
+(1427, 'row 1427'),
+(1428, 'row 1428'),
+(1429, 'row 1429'),
+(1430, 'row 1430'),
+(1431, 'row 1431'),
+(1432, 'row 1432'),
+(1433, 'row 1433'),
+(1434, 'row 1434'),
+(1435, 'row 1435'),
+(1436, 'row 1436'),
+(1437, 'row 1437'),
+(1438, 'row 1438'),
+(1439, 'row 1439'),
+(1440, 'row 1440'),
+(1441, 'row 1441'),
+(1442, 'row 1442'),
+(1443, 'row 1443'),
+(1444, 'row 1444'),
+(1445, 'row 1445'),
+(1446, 'row 1446'),
+(1447, 'row 1447'),
+(1448, 'row 1448'),
+(1449, 'row 1449'),
+(1450, 'row 1450'),
+(1451, 'row 1451'),
+(1452, 'row 1452'),
+(1453, 'row 1453'),
+(1454, 'row 1454'),
+(1455, 'row 1455'),
+(1456, 'row 1456'),
+(1457, 'row 1457'),
+(1458, 'row 1458'),
+(1459, 'row 1459'),
+(1460, 'row 1460'),
+(1461, 'row 1461'),
+(1462, 'row 1462'),
+(1463, 'row 1463'),
+(1464, 'row 1464'),
+(1465, 'row 1465'),
+(1466, 'row 1466'),
+(1467, 'row 1467'),
+(1468, 'row 1468'),
+(1469, 'row 1469'),
+(1470, 'row 1470'),
+(1471, 'row 1471'),
+(1472, 'row 1472'),
+(1473, 'row 1473'),
+(1474, 'row 1474'),
+(1475, 'row 1475'),
+(1476, 'row 1476'),
+(1477, 'row 1477'),
+(1478, 'row 1478'),
+(1479, 'row 1479'),
+(1480, 'row 1480'),
+(1481, 'row 1481'),
+(1482, 'row 1482'),
+(1483, 'row 1483'),
+(1484, 'row 1484'),
+(1485, 'row 1485'),
+(1486, 'row 1486'),
+(1487, 'row 1487'),
+(1488, 'row 1488'),
+(1489, 'row 1489'),
+(1490, 'row 1490'),
+(1491, 'row 1491'),
+(1492, 'row 1492'),
+(1493, 'row 1493'),
+(1494, 'row 1494'),
+(1495, 'row 1495'),
+(1496, 'row 1496'),
+(1497, 'row 1497'),
+(1498, 'row 1498'),
+(1499, 'row 1499'),
+(1500, 'row 1500'),
+(1501, 'row 1501'),
+(1502, 'row 1502'),
+(1503, 'row 1503'),
+(1504, 'row 1504'),
+(1505, 'row 1505'),
+(1506, 'row 1506'),
+(1507, 'row 1507'),
+(1508, 'row 1508'),
+(1509, 'row 1509'),
+(1510, 'row 1510'),
+(1511, 'row 1511'),
+(1512, 'row 1512'),
+(1513, 'row 1513'),
+(1514, 'row 1514'),
+(1515, 'row 1515'),
+(1516, 'row 1516'),
+(1517, 'row 1517'),
+(1518, 'row 1518'),
+(1519, 'row 1519'),
+(1520, 'row 1520'),
+(1521, 'row 1521'),
+(1522, 'row 1522'),
+(1523, 'row 1523'),
+(1524, 'row 1524'),
+(1525, 'row 1525'),
+(1526, 'row 1526'),
+(1527, 'row 1527'),
+(1528, 'row 1528'),
+(1529, 'row 1529'),
+(1530, 'row 1530'),
+(1531, 'row 1531'),
+(1532, 'row 1532'),
+(1533, 'row 1533'),
+(1534, 'row 1534'),
+(1535, 'row 1535'),
+(1536, 'row 1536'),
+(1537, 'row 1537'),
+(1538, 'row 1538'),
+(1539, 'row 1539'),
+(1540, 'row 1540'),
+(1541, 'row 1541'),
+(1542, 'row 1542'),
+(1543, 'row 1543'),
+(1544, 'row 1544'),
+(1545, 'row 1545'),
+(1546, 'row 1546'),
+(1547, 'row 1547'),
+(1548, 'row 1548'),
+(1549, 'row 1549'),
+(1550, 'row 1550'),
+(1551, 'row 1551'),
+(1552, 'row 1552'),
+(1553, 'row 1553'),
+(1554, 'row 1554'),
+(1555, 'row 1555'),
+(1556, 'row 1556'),
+(1557, 'row 1557'),
+(1558, 'row 1558'),
+(1559, 'row 1559'),
+(1560, 'row 1560'),
+(1561, 'row 1561'),
+(1562, 'row 1562'),
+(1563, 'row 1563'),
+(1564, 'row 1564'),
+(1565, 'row 1565'),
+(1566, 'row 1566'),
+(1567, 'row 1567'),
+(1568, 'row 1568'),
+(1569, 'row 1569'),
+(1570, 'row 1570'),
+(1571, 'row 1571'),
+(1572, 'row 1572'),
+(1573, 'row 1573'),
+(1574, 'row 1574'),
+(1575, 'row 1575'),
+(1576, 'row 1576'),
+(1577, 'row 1577'),
+(1578, 'row 1578'),
+(1579, 'row 1579'),
+(1580, 'row 1580'),
+(1581, 'row 1581'),
+(1582, 'row 1582'),
+(1583, 'row 1583'),
+(1584, 'row 1584'),
+(1585, 'row 1585'),
+(1586, 'row 1586'),
+(1587, 'row 1587'),
+(1588, 'row 1588'),
+(1589, 'row 1589'),
+(1590, 'row 1590'),
+(1591, 'row 1591'),
+(1592, 'row 1592'),
+(1593, 'row 1593'),
+(1594, 'row 1594'),
+(1595, 'row 1595'),
+(1596, 'row 1596'),
+(1597, 'row 1597'),
+(1598, 'row 1598'),
+(1599, 'row 1599'),
+(1600, 'row 1600'),
+(1601, 'row 1601'),
+(1602, 'row 1602'),
+(1603, 'row 1603'),
+(1604, 'row 1604'),
+(1605, 'row 1605'),
+(1606, 'row 1606'),
+(1607, 'row 1607'),
+(1608, 'row 1608'),
+(1609, 'row 1609'),
+(1610, 'row 1610'),
+(1611, 'row 1611'),
+(1612, 'row 1612'),
+(1613, 'row 1613'),
+(1614, 'row 1614'),
+(1615, 'row 1615'),
+(1616, 'row 1616'),
+(1617, 'row 1617'),
+(1618, 'row 1618'),
+(1619, 'row 1619'),
+(1620, 'row 1620'),
+(1621, 'row 1621'),
+(1622, 'row 1622'),
+(1623, 'row 1623'),
+(1624, 'row 1624'),
+(1625, 'row 1625'),
+(1626, 'row 1626'),
+(1627, 'row 1627'),
+(1628, 'row 1628'),
+(1629, 'row 1629'),
+(1630, 'row 1630'),
+(1631, 'row 1631'),
+(1632, 'row 1632'),
+(1633, 'row 1633'),
+(1634, 'row 1634'),
+(1635, 'row 1635'),
+(1636, 'row 1636'),
+(1637, 'row 1637'),
+(1638, 'row 1638'),
+(1639, 'row 1639'),
+(1640, 'row 1640'),
+(1641, 'row 1641'),
+(1642, 'row 1642'),
+(1643, 'row 1643'),
+(1644, 'row 1644'),
+(1645, 'row 1645'),
+(1646, 'row 1646'),
+(1647, 'row 1647'),
+(1648, 'row 1648'),
+(1649, 'row 1649'),
+(1650, 'row 1650'),
+(1651, 'row 1651'),
+(1652, 'row 1652'),
+(1653, 'row 1653'),
+(1654, 'row 1654'),
+(1655, 'row 1655'),
+(1656, 'row 1656'),
+(1657, 'row 1657'),
+(1658, 'row 1658'),
+(1659, 'row 1659'),
+(1660, 'row 1660'),
+(1661, 'row 1661'),
+(1662, 'row 1662'),
+(1663, 'row 1663'),
+(1664, 'row 1664'),
+(1665, 'row 1665'),
+(1666, 'row 1666'),
+(1667, 'row 1667'),
+(1668, 'row 1668'),
+(1669, 'row 1669'),
+(1670, 'row 1670'),
+(1671, 'row 1671'),
+(1672, 'row 1672'),
+(1673, 'row 1673'),
+(1674, 'row 1674'),
+(1675, 'row 1675'),
+(1676, 'row 1676'),
+(1677, 'row 1677'),
+(1678, 'row 1678'),
+(1679, 'row 1679'),
+(1680, 'row 1680'),
+(1681, 'row 1681'),
+(1682, 'row 1682'),
+(1683, 'row 1683'),
+(1684, 'row 1684'),
+(1685, 'row 1685'),
+(1686, 'row 1686'),
+(1687, 'row 1687'),
+(1688, 'row 1688'),
+(1689, 'row 1689'),
+(1690, 'row 1690'),
+(1691, 'row 1691'),
+(1692, 'row 1692'),
+(1693, 'row 1693'),
+(1694, 'row 1694'),
+(1695, 'row 1695'),
+(1696, 'row 1696'),
+(1697, 'row 1697'),
+(1698, 'row 1698'),
+(1699, 'row 1699'),
+(1700, 'row 1700'),
+(1701, 'row 1701'),
+(1702, 'row 1702'),
+(1703, 'row 1703'),
+(1704, 'row 1704'),
+(1705, 'row 1705'),
+(1706, 'row 1706'),
+(1707, 'row 1707'),
+(1708, 'row 1708'),
+(1709, 'row 1709'),
+(1710, 'row 1710'),
+(1711, 'row 1711'),
+(1712, 'row 1712'),
+(1713, 'row 1713'),
+(1714, 'row 1714'),
+(1715, 'row 1715'),
+(1716, 'row 1716'),
+(1717, 'row 1717'),
+(1718, 'row 1718'),
+(1719, 'row 1719'),
+(1720, 'row 1720'),
+(1721, 'row 1721'),
+(1722, 'row 1722'),
+(1723, 'row 1723'),
+(1724, 'row 1724'),
+(1725, 'row 1725'),
+(1726, 'row 1726'),
+(1727, 'row 1727'),
+(1728, 'row 1728'),
+(1729, 'row 1729'),
+(1730, 'row 1730'),
+(1731, 'row 1731'),
+(1732, 'row 1732'),
+(1733, 'row 1733'),
+(1734, 'row 1734'),
+(1735, 'row 1735'),
+(1736, 'row 1736'),
+(1737, 'row 1737'),
+(1738, 'row 1738'),
+(1739, 'row 1739'),
+(1740, 'row 1740'),
+(1741, 'row 1741'),
+(1742, 'row 1742'),
+(1743, 'row 1743'),
+(1744, 'row 1744'),
+(1745, 'row 1745'),
+(1746, 'row 1746'),
+(1747, 'row 1747'),
+(1748, 'row 1748'),
+(1749, 'row 1749'),
+(1750, 'row 1750'),
+(1751, 'row 1751'),
+(1752, 'row 1752'),
+(1753, 'row 1753'),
+(1754, 'row 1754'),
+(1755, 'row 1755'),
+(1756, 'row 1756'),
+(1757, 'row 1757'),
+(1758, 'row 1758'),
+(1759, 'row 1759'),
+(1760, 'row 1760'),
+(1761, 'row 1761'),
+(1762, 'row 1762'),
+(1763, 'row 1763'),
+(1764, 'row 1764'),
+(1765, 'row 1765'),
+(1766, 'row 1766'),
+(1767, 'row 1767'),
+(1768, 'row 1768'),
+(1769, 'row 1769'),
+(1770, 'row 1770'),
+(1771, 'row 1771'),
+(1772, 'row 1772'),
+(1773, 'row 1773'),
+(1774, 'row 1774'),
+(1775, 'row 1775'),
+(1776, 'row 1776'),
+(1777, 'row 1777'),
+(1778, 'row 1778'),
+(1779, 'row 1779'),
+(1780, 'row 1780'),
+(1781, 'row 1781'),
+(1782, 'row 1782'),
+(1783, 'row 1783'),
+(1784, 'row 1784'),
+(1785, 'row 1785'),
+(1786, 'row 1786'),
+(1787, 'row 1787'),
+(1788, 'row 1788'),
+(1789, 'row 1789'),
+(1790, 'row 1790'),
+(1791, 'row 1791'),
+(1792, 'row 1792'),
+(1793, 'row 1793'),
+(1794, 'row 1794'),
+(1795, 'row 1795'),
+(1796, 'row 1796'),
+(1797, 'row 1797'),
+(1798, 'row 1798'),
+(1799, 'row 1799'),
+(1800, 'row 1800'),
+(1801, 'row 1801'),
+(1802, 'row 1802'),
+(1803, 'row 1803'),
+(1804, 'row 1804'),
+(1805, 'row 1805'),
+(1806, 'row 1806'),
+(1807, 'row 1807'),
+(1808, 'row 1808'),
+(1809, 'row 1809'),
+(1810, 'row 1810'),
+(1811, 'row 1811'),
+(1812, 'row 1812'),
+(1813, 'row 1813'),
+(1814, 'row 1814'),
+(1815, 'row 1815'),
+(1816, 'row 1816'),
+(1817, 'row 1817'),
+(1818, 'row 1818'),
+(1819, 'row 1819'),
+(1820, 'row 1820'),
+(1821, 'row 1821'),
+(1822, 'row 1822'),
+(1823, 'row 1823'),
+(1824, 'row 1824'),
+(1825, 'row 1825'),
+(1826, 'row 1826'),
+(1827, 'row 1827'),
+(1828, 'row 1828'),
+(1829, 'row 1829'),
+(1830, 'row 1830'),
+(1831, 'row 1831'),
+(1832, 'row 1832'),
+(1833, 'row 1833'),
+(1834, 'row 1834'),
+(1835, 'row 1835'),
+(1836, 'row 1836'),
+(1837, 'row 1837'),
+(1838, 'row 1838'),
+(1839, 'row 1839'),
+(1840, 'row 1840'),
+(1841, 'row 1841'),
+(1842, 'row 1842'),
+(1843, 'row 1843'),
+(1844, 'row 1844'),
+(1845, 'row 1845'),
+(1846, 'row 1846'),
+(1847, 'row 1847'),
+(1848, 'row 1848'),
+(1849, 'row 1849'),
+(1850, 'row 1850'),
+(1851, 'row 1851'),
+(1852, 'row 1852'),
+(1853, 'row 1853'),
+(1854, 'row 1854'),
+(1855, 'row 1855'),
+(1856, 'row 1856'),
+(1857, 'row 1857'),
+(1858, 'row 1858'),
+(1859, 'row 1859'),
+(1860, 'row 1860'),
+(1861, 'row 1861'),
+(1862, 'row 1862'),
+(1863, 'row 1863'),
+(1864, 'row 1864'),
+(1865, 'row 1865'),
+(1866, 'row 1866'),
+(1867, 'row 1867'),
+(1868, 'row 1868'),
+(1869, 'row 1869'),
+(1870, 'row 1870'),
+(1871, 'row 1871'),
+(1872, 'row 1872'),
+(1873, 'row 1873'),
+(1874, 'row 1874'),
+(1875, 'row 1875'),
+(1876, 'row 1876'),
+(1877, 'row 1877'),
+(1878, 'row 1878'),
+(1879, 'row 1879'),
+(1880, 'row 1880'),
+(1881, 'row 1881'),
+(1882, 'row 1882'),
+(1883, 'row 1883'),
+(1884, 'row 1884'),
+(1885, 'row 1885'),
+(1886, 'row 1886'),
+(1887, 'row 1887'),
+(1888, 'row 1888'),
+(1889, 'row 1889'),
+(1890, 'row 1890'),
+(1891, 'row 1891'),
+(1892, 'row 1892'),
+(1893, 'row 1893'),
+(1894, 'row 1894'),
+(1895, 'row 1895'),
+(1896, 'row 1896'),
+(1897, 'row 1897'),
+(1898, 'row 1898'),
+(1899, 'row 1899'),
+(1900, 'row 1900'),
+(1901, 'row 1901'),
+(1902, 'row 1902'),
+(1903, 'row 1903'),
+(1904, 'row 1904'),
+(1905, 'row 1905'),
+(1906, 'row 1906'),
+(1907, 'row 1907'),
+(1908, 'row 1908'),
+(1909, 'row 1909'),
+(1910, 'row 1910'),
+(1911, 'row 1911'),
+(1912, 'row 1912'),
+(1913, 'row 1913'),
+(1914, 'row 1914'),
+(1915, 'row 1915'),
+(1916, 'row 1916'),
+(1917, 'row 1917'),
+(1918, 'row 1918'),
+(1919, 'row 1919'),
+(1920, 'row 1920'),
+(1921, 'row 1921'),
+(1922, 'row 1922'),
+(1923, 'row 1923'),
+(1924, 'row 1924'),
+(1925, 'row 1925'),
+(1926, 'row 1926'),
+(1927, 'row 1927'),
+(1928, 'row 1928'),
+(1929, 'row 1929'),
+(1930, 'row 1930'),
+(1931, 'row 1931'),
+(1932, 'row 1932'),
+(1933, 'row 1933'),
+(1934, 'row 1934'),
+(1935, 'row 1935'),
+(1936, 'row 1936'),
+(1937, 'row 1937'),
+(1938, 'row 1938'),
+(1939, 'row 1939'),
+(1940, 'row 1940'),
+(1941, 'row 1941'),
+(1942, 'row 1942'),
+(1943, 'row 1943'),
+(1944, 'row 1944'),
+(1945, 'row 1945'),
+(1946, 'row 1946'),
+(1947, 'row 1947'),
+(1948, 'row 1948'),
+(1949, 'row 1949'),
+(1950, 'row 1950'),
+(1951, 'row 1951'),
+(1952, 'row 1952'),
+(1953, 'row 1953'),
+(1954, 'row 1954'),
+(1955, 'row 1955'),
+(1956, 'row 1956'),
+(1957, 'row 1957'),
+(1958, 'row 1958'),
+(1959, 'row 1959'),
+(1960, 'row 1960'),
+(1961, 'row 1961'),
+(1962, 'row 1962'),
+(1963, 'row 1963'),
+(1964, 'row 1964'),
+(1965, 'row 1965'),
+(1966, 'row 1966'),
+(1967, 'row 1967'),
+(1968, 'row 1968'),
+(1969, 'row 1969'),
+(1970, 'row 1970'),
+(1971, 'row 1971'),
+(1972, 'row 1972'),
+(1973, 'row 1973'),
+(1974, 'row 1974'),
+(1975, 'row 1975'),
+(1976, 'row 1976'),
+(1977, 'row 1977'),
+(1978, 'row 1978'),
+(1979, 'row 1979'),
+(1980, 'row 1980'),
+(1981, 'row 1981'),
+(1982, 'row 1982'),
+(1983, 'row 1983'),
+(1984, 'row 1984'),
+(1985, 'row 1985'),
+(1986, 'row 1986'),
+(1987, 'row 1987'),
+(1988, 'row 1988'),
+(1989, 'row 1989'),
+(1990, 'row 1990'),
+(1991, 'row 1991'),
+(1992, 'row 1992'),
+(1993, 'row 1993'),
+(1994, 'row 1994'),
+(1995, 'row 1995'),
+(1996, 'row 1996'),
+(1997, 'row 1997'),
+(1998, 'row 1998'),
+(1999, 'row 1999'),
+(2000, 'row 2000'),
+(2001, 'row 2001'),
+(2002, 'row 2002'),
+(2003, 'row 2003'),
+(2004, 'row 2004'),
+(2005, 'row 2005'),
+(2006, 'row 2006'),
+(2007, 'row 2007'),
+(2008, 'row 2008'),
+(2009, 'row 2009'),
+(2010, 'row 2010'),
+(2011, 'row 2011'),
+(2012, 'row 2012'),
+(2013, 'row 2013'),
+(2014, 'row 2014'),
+(2015, 'row 2015'),
+(2016, 'row 2016'),
+(2017, 'row 2017'),
+(2018, 'row 2018'),
+(2019, 'row 2019'),
+(2020, 'row 2020'),
+(2021, 'row 2021'),
+(2022, 'row 2022'),
+(2023, 'row 2023'),
+(2024, 'row 2024'),
+(2025, 'row 2025'),
+(2026, 'row 2026'),
+(2027, 'row 2027'),
+(2028, 'row 2028'),
+(2029, 'row 2029'),
+(2030, 'row 2030'),
+(2031, 'row 2031'),
+(2032, 'row 2032'),
+(2033, 'row 2033'),
+(2034, 'row 2034'),
+(2035, 'row 2035'),
+(2036, 'row 2036'),
+(2037, 'row 2037'),
+(2038, 'row 2038'),
+(2039, 'row 2039'),
+(2040, 'row 2040'),
+(2041, 'row 2041'),
+(2042, 'row 2042'),
+(2043, 'row 2043'),
+(2044, 'row 2044'),
+(2045, 'row 2045'),
+(2046, 'row 2046'),
+(2047, 'row 2047'),
+(2048, 'row 2048'),
+(2049, 'row 2049'),
+(2050, 'row 2050'),
+(2051, 'row 2051'),
+(2052, 'row 2052'),
+(2053, 'row 2053'),
+(2054, 'row 2054'),
+(2055, 'row 2055'),
+(2056, 'row 2056'),
+(2057, 'row 2057'),
+(2058, 'row 2058'),
+(2059, 'row 2059'),
+(2060, 'row 2060'),
+(2061, 'row 2061'),
+(2062, 'row 2062'),
+(2063, 'row 2063'),
+(2064, 'row 2064'),
+(2065, 'row 2065'),
+(2066, 'row 2066'),
+(2067, 'row 2067'),
+(2068, 'row 2068'),
+(2069, 'row 2069'),
+(2070, 'row 2070'),
+(2071, 'row 2071'),
+(2072, 'row 2072'),
+(2073, 'row 2073'),
+(2074, 'row 2074'),
+(2075, 'row 2075'),
+(2076, 'row 2076'),
+(2077, 'row 2077'),
+(2078, 'row 2078'),
+(2079, 'row 2079'),
+(2080, 'row 2080'),
+(2081, 'row 2081'),
+(2082, 'row 2082'),
+(2083, 'row 2083'),
+(2084, 'row 2084'),
+(2085, 'row 2085'),
+(2086, 'row 2086'),
+(2087, 'row 2087'),
+(2088, 'row 2088'),
+(2089, 'row 2089'),
+(2090, 'row 2090'),
+(2091, 'row 2091'),
+(2092, 'row 2092'),
+(2093, 'row 2093'),
+(2094, 'row 2094'),
+(2095, 'row 2095'),
+(2096, 'row 2096'),
+(2097, 'row 2097'),
+(2098, 'row 2098'),
+(2099, 'row 2099'),
+(2100, 'row 2100'),
+(2101, 'row 2101'),
+(2102, 'row 2102'),
+(2103, 'row 2103'),
+(2104, 'row 2104'),
+(2105, 'row 2105'),
+(2106, 'row 2106'),
+(2107, 'row 2107'),
+(2108, 'row 2108'),
+(2109, 'row 2109'),
+(2110, 'row 2110'),
+(2111, 'row 2111'),
+(2112, 'row 2112'),
+(2113, 'row 2113'),
+(2114, 'row 2114'),
+(2115, 'row 2115'),
+(2116, 'row 2116'),
+(2117, 'row 2117'),
+(2118, 'row 2118'),
+(2119, 'row 2119'),
+(2120, 'row 2120'),
+(2121, 'row 2121'),
+(2122, 'row 2122'),
+(2123, 'row 2123'),
+(2124, 'row 2124'),
+(2125, 'row 2125'),
+(2126, 'row 2126'),
+(2127, 'row 2127'),
+(2128, 'row 2128'),
+(2129, 'row 2129'),
+(2130, 'row 2130'),
+(2131, 'row 2131'),
+(2132, 'row 2132'),
+(2133, 'row 2133'),
+(2134, 'row 2134'),
+(2135, 'row 2135'),
+(2136, 'row 2136'),
+(2137, 'row 2137'),
+(2138, 'row 2138'),
+(2139, 'row 2139'),
+(2140, 'row 2140'),
+(2141, 'row 2141'),
+(2142, 'row 2142'),
+(2143, 'row 2143'),
+(2144, 'row 2144'),
+(2145, 'row 2145'),
+(2146, 'row 2146'),
+(2147, 'row 2147'),
+(2148, 'row 2148'),
+(2149, 'row 2149'),
+(2150, 'row 2150'),
+(2151, 'row 2151'),
+(2152, 'row 2152'),
+(2153, 'row 2153'),
+(2154, 'row 2154'),
+(2155, 'row 2155'),
+(2156, 'row 2156'),
+(2157, 'row 2157'),
+(2158, 'row 2158'),
+(2159, 'row 2159'),
+(2160, 'row 2160'),
+(2161, 'row 2161'),
+(2162, 'row 2162'),
+(2163, 'row 2163'),
+(2164, 'row 2164'),
+(2165, 'row 2165'),
+(2166, 'row 2166'),
+(2167, 'row 2167'),
+(2168, 'row 2168'),
+(2169, 'row 2169'),
+(2170, 'row 2170'),
+(2171, 'row 2171'),
+(2172, 'row 2172'),
+(2173, 'row 2173'),
+(2174, 'row 2174'),
+(2175, 'row 2175'),
+(2176, 'row 2176'),
+(2177, 'row 2177'),
+(2178, 'row 2178'),
+(2179, 'row 2179'),
+(2180, 'row 2180'),
+(2181, 'row 2181'),
+(2182, 'row 2182'),
+(2183, 'row 2183'),
+(2184, 'row 2184'),
+(2185, 'row 2185'),
+(2186, 'row 2186'),
+(2187, 'row 2187'),
+(2188, 'row 2188'),
+(2189, 'row 2189'),
+(2190, 'row 2190'),
+(2191, 'row 2191'),
+(2192, 'row 2192'),
+(2193, 'row 2193'),
+(2194, 'row 2194'),
+(2195, 'row 2195'),
+(2196, 'row 2196'),
+(2197, 'row 2197'),
+(2198, 'row 2198'),
+(2199, 'row 2199'),
+(2200, 'row 2200'),
+(2201, 'row 2201'),
+(2202, 'row 2202'),
+(2203, 'row 2203'),
+(2204, 'row 2204'),
+(2205, 'row 2205'),
+(2206, 'row 2206'),
+(2207, 'row 2207'),
+(2208, 'row 2208'),
+(2209, 'row 2209'),
+(2210, 'row 2210'),
+(2211, 'row 2211'),
+(2212, 'row 2212'),
+(2213, 'row 2213'),
+(2214, 'row 2214'),
+(2215, 'row 2215'),
+(2216, 'row 2216'),
+(2217, 'row 2217'),
+(2218, 'row 2218'),
+(2219, 'row 2219'),
+(2220, 'row 2220'),
+(2221, 'row 2221'),
+(2222, 'row 2222'),
+(2223, 'row 2223'),
+(2224, 'row 2224'),
+(2225, 'row 2225'),
+(2226, 'row 2226'),
+(2227, 'row 2227'),
+(2228, 'row 2228'),
+(2229, 'row 2229'),
+(2230, 'row 2230'),
+(2231, 'row 2231'),
+(2232, 'row 2232'),
+(2233, 'row 2233'),
+(2234, 'row 2234'),
+(2235, 'row 2235'),
+(2236, 'row 2236'),
+(2237, 'row 2237'),
+(2238, 'row 2238'),
+(2239, 'row 2239'),
+(2240, 'row 2240'),
+(2241, 'row 2241'),
+(2242, 'row 2242'),
+(2243, 'row 2243'),
+(2244, 'row 2244'),
+(2245, 'row 2245'),
+(2246, 'row 2246'),
+(2247, 'row 2247'),
+(2248, 'row 2248'),
+(2249, 'row 2249'),
+(2250, 'row 2250'),
+(2251, 'row 2251'),
+(2252, 'row 2252'),
+(2253, 'row 2253'),
+(2254, 'row 2254'),
+(2255, 'row 2255'),
+(2256, 'row 2256'),
+(2257, 'row 2257'),
+(2258, 'row 2258'),
+(2259, 'row 2259'),
+(2260, 'row 2260'),
+(2261, 'row 2261'),
+(2262, 'row 2262'),
+(2263, 'row 2263'),
+(2264, 'row 2264'),
+(2265, 'row 2265'),
+(2266, 'row 2266'),
+(2267, 'row 2267'),
+(2268, 'row 2268'),
+(2269, 'row 2269'),
+(2270, 'row 2270'),
+(2271, 'row 2271'),
+(2272, 'row 2272'),
+(2273, 'row 2273'),
+(2274, 'row 2274'),
+(2275, 'row 2275'),
+(2276, 'row 2276'),
+(2277, 'row 2277'),
+(2278, 'row 2278'),
+(2279, 'row 2279'),
+(2280, 'row 2280'),
+(2281, 'row 2281'),
+(2282, 'row 2282'),
+(2283, 'row 2283'),
+(2284, 'row 2284'),
+(2285, 'row 2285'),
+(2286, 'row 2286'),
+(2287, 'row 2287'),
+(2288, 'row 2288'),
+(2289, 'row 2289'),
+(2290, 'row 2290'),
+(2291, 'row 2291'),
+(2292, 'row 2292'),
+(2293, 'row 2293'),
+(2294, 'row 2294'),
+(2295, 'row 2295'),
+(2296, 'row 2296'),
+(2297, 'row 2297'),
+(2298, 'row 2298'),
+(2299, 'row 2299'),
+(2300, 'row 2300'),
+(2301, 'row 2301'),
+(2302, 'row 2302'),
+(2303, 'row 2303'),
+(2304, 'row 2304'),
+(2305, 'row 2305'),
+(2306, 'row 2306'),
+(2307, 'row 2307'),
+(2308, 'row 2308'),
+(2309, 'row 2309'),
+(2310, 'row 2310'),
+(2311, 'row 2311'),
+(2312, 'row 2312'),
+(2313, 'row 2313'),
+(2314, 'row 2314'),
+(2315, 'row 2315'),
+(2316, 'row 2316'),
+(2317, 'row 2317'),
+(2318, 'row 2318'),
+(2319, 'row 2319'),
+(2320, 'row 2320'),
+(2321, 'row 2321'),
+(2322, 'row 2322'),
+(2323, 'row 2323'),
+(2324, 'row 2324'),
+(2325, 'row 2325'),
+(2326, 'row 2326'),
+(2327, 'row 2327'),
+(2328, 'row 2328'),
+(2329, 'row 2329'),
+(2330, 'row 2330'),
+(2331, 'row 2331'),
+(2332, 'row 2332'),
+(2333, 'row 2333'),
+(2334, 'row 2334'),
+(2335, 'row 2335'),
+(2336, 'row 2336'),
+(2337, 'row 2337'),
+(2338, 'row 2338'),
+(2339, 'row 2339'),
+(2340, 'row 2340'),
+(2341, 'row 2341'),
+(2342, 'row 2342'),
+(2343, 'row 2343'),
+(2344, 'row 2344'),
+(2345, 'row 2345'),
+(2346, 'row 2346'),
+(2347, 'row 2347'),
+(2348, 'row 2348'),
+(2349, 'row 2349'),
+(2350, 'row 2350'),
+(2351, 'row 2351'),
+(2352, 'row 2352'),
+(2353, 'row 2353'),
+(2354, 'row 2354'),
+(2355, 'row 2355'),
+(2356, 'row 2356'),
+(2357, 'row 2357'),
+(2358, 'row 2358'),
+(2359, 'row 2359'),
+(2360, 'row 2360'),
+(2361, 'row 2361'),
+(2362, 'row 2362'),
+(2363, 'row 2363'),
+(2364, 'row 2364'),
+(2365, 'row 2365'),
+(2366, 'row 2366'),
+(2367, 'row 2367'),
+(2368, 'row 2368'),
+(2369, 'row 2369'),
+(2370, 'row 2370'),
+(2371, 'row 2371'),
+(2372, 'row 2372'),
+(2373, 'row 2373'),
+(2374, 'row 2374'),
+(2375, 'row 2375'),
+(2376, 'row 2376'),
+(2377, 'row 2377'),
+(2378, 'row 2378'),
+(2379, 'row 2379'),
+(2380, 'row 2380'),
+(2381, 'row 2381'),
+(2382, 'row 2382'),
+(2383, 'row 2383'),
+(2384, 'row 2384'),
+(2385, 'row 2385'),
+(2386, 'row 2386'),
+(2387, 'row 2387'),
+(2388, 'row 2388'),
+(2389, 'row 2389'),
+(2390, 'row 2390'),
+(2391, 'row 2391'),
+(2392, 'row 2392'),
+(2393, 'row 2393'),
+(2394, 'row 2394'),
+(2395, 'row 2395'),
+(2396, 'row 2396'),
+(2397, 'row 2397'),
+(2398, 'row 2398'),
+(2399, 'row 2399'),
+(2400, 'row 2400'),
+(2401, 'row 2401'),
+(2402, 'row 2402'),
+(2403, 'row 2403'),
+(2404, 'row 2404'),
+(2405, 'row 2405'),
+(2406, 'row 2406'),
+(2407, 'row 2407'),
+(2408, 'row 2408'),
+(2409, 'row 2409'),
+(2410, 'row 2410'),
+(2411, 'row 2411'),
+(2412, 'row 2412'),
+(2413, 'row 2413'),
+(2414, 'row 2414'),
+(2415, 'row 2415'),
+(2416, 'row 2416'),
+(2417, 'row 2417'),
+(2418, 'row 2418'),
+(2419, 'row 2419'),
+(2420, 'row 2420'),
+(2421, 'row 2421'),
+(2422, 'row 2422'),
+(2423, 'row 2423'),
+(2424, 'row 2424'),
+(2425, 'row 2425'),
+(2426, 'row 2426'),
+(2427, 'row 2427'),
+(2428, 'row 2428'),
+(2429, 'row 2429'),
+(2430, 'row 2430'),
+(2431, 'row 2431'),
+(2432, 'row 2432'),
+(2433, 'row 2433'),
+(2434, 'row 2434'),
+(2435, 'row 2435'),
+(2436, 'row 2436'),
+(2437, 'row 2437'),
+(2438, 'row 2438'),
+(2439, 'row 2439'),
+(2440, 'row 2440'),
+(2441, 'row 2441'),
+(2442, 'row 2442'),
+(2443, 'row 2443'),
+(2444, 'row 2444'),
+(2445, 'row 2445'),
+(2446, 'row 2446'),
+(2447, 'row 2447'),
+(2448, 'row 2448'),
+(2449, 'row 2449'),
+(2450, 'row 2450'),
+(2451, 'row 2451'),
+(2452, 'row 2452'),
+(2453, 'row 2453'),
+(2454, 'row 2454'),
+(2455, 'row 2455'),
+(2456, 'row 2456'),
+(2457, 'row 2457'),
+(2458, 'row 2458'),
+(2459, 'row 2459'),
+(2460, 'row 2460'),
+(2461, 'row 2461'),
+(2462, 'row 2462'),
+(2463, 'row 2463'),
+(2464, 'row 2464'),
+(2465, 'row 2465'),
+(2466, 'row 2466'),
+(2467, 'row 2467'),
+(2468, 'row 2468'),
+(2469, 'row 2469'),
+(2470, 'row 2470'),
+(2471, 'row 2471'),
+(2472, 'row 2472'),
+(2473, 'row 2473'),
+(2474, 'row 2474'),
+(2475, 'row 2475'),
+(2476, 'row 2476'),
+(2477, 'row 2477'),
+(2478, 'row 2478'),
+(2479, 'row 2479'),
+(2480, 'row 2480'),
+(2481, 'row 2481'),
+(2482, 'row 2482'),
+(2483, 'row 2483'),
+(2484, 'row 2484'),
+(2485, 'row 2485'),
+(2486, 'row 2486'),
+(2487, 'row 2487'),
+(2488, 'row 2488'),
+(2489, 'row 2489'),
+(2490, 'row 2490'),
+(2491, 'row 2491'),
+(2492, 'row 2492'),
+(2493, 'row 2493'),
+(2494, 'row 2494'),
+(2495, 'row 2495'),
+(2496, 'row 2496'),
+(2497, 'row 2497'),
+(2498, 'row 2498'),
+(2499, 'row 2499'),
+(2500, 'row 2500'),
+(2501, 'row 2501'),
+(2502, 'row 2502'),
+(2503, 'row 2503'),
+(2504, 'row 2504'),
+(2505, 'row 2505'),
+(2506, 'row 2506'),
+(2507, 'row 2507'),
+(2508, 'row 2508'),
+(2509, 'row 2509'),
+(2510, 'row 2510'),
+(2511, 'row 2511'),
+(2512, 'row 2512'),
+(2513, 'row 2513'),
+(2514, 'row 2514'),
+(2515, 'row 2515'),
+(2516, 'row 2516'),
+(2517, 'row 2517'),
+(2518, 'row 2518'),
+(2519, 'row 2519'),
+(2520, 'row 2520'),
+(2521, 'row 2521'),
+(2522, 'row 2522'),
+(2523, 'row 2523'),
+(2524, 'row 2524'),
+(2525, 'row 2525'),
+(2526, 'row 2526'),
+(2527, 'row 2527'),
+(2528, 'row 2528'),
+(2529, 'row 2529'),
+(2530, 'row 2530'),
+(2531, 'row 2531'),
+(2532, 'row 2532'),
+(2533, 'row 2533'),
+(2534, 'row 2534'),
+(2535, 'row 2535'),
+(2536, 'row 2536'),
+(2537, 'row 2537'),
+(2538, 'row 2538'),
+(2539, 'row 2539'),
+(2540, 'row 2540'),
+(2541, 'row 2541'),
+(2542, 'row 2542'),
+(2543, 'row 2543'),
+(2544, 'row 2544'),
+(2545, 'row 2545'),
+(2546, 'row 2546'),
+(2547, 'row 2547'),
+(2548, 'row 2548'),
+(2549, 'row 2549'),
+(2550, 'row 2550'),
+(2551, 'row 2551'),
+(2552, 'row 2552'),
+(2553, 'row 2553'),
+(2554, 'row 2554'),
+(2555, 'row 2555'),
+(2556, 'row 2556'),
+(2557, 'row 2557'),
+(2558, 'row 2558'),
+(2559, 'row 2559'),
+(2560, 'row 2560'),
+(2561, 'row 2561'),
+(2562, 'row 2562'),
+(2563, 'row 2563'),
+(2564, 'row 2564'),
+(2565, 'row 2565'),
+(2566, 'row 2566'),
+(2567, 'row 2567'),
+(2568, 'row 2568'),
+(2569, 'row 2569'),
+(2570, 'row 2570'),
+(2571, 'row 2571'),
+(2572, 'row 2572'),
+(2573, 'row 2573'),
+(2574, 'row 2574'),
+(2575, 'row 2575'),
+(2576, 'row 2576'),
+(2577, 'row 2577'),
+(2578, 'row 2578'),
+(2579, 'row 2579'),
+(2580, 'row 2580'),
+(2581, 'row 2581'),
+(2582, 'row 2582'),
+(2583, 'row 2583'),
+(2584, 'row 2584'),
+(2585, 'row 2585'),
+(2586, 'row 2586'),
+(2587, 'row 2587'),
+(2588, 'row 2588'),
+(2589, 'row 2589'),
+(2590, 'row 2590'),
+(2591, 'row 2591'),
+(2592, 'row 2592'),
+(2593, 'row 2593'),
+(2594, 'row 2594'),
+(2595, 'row 2595'),
+(2596, 'row 2596'),
+(2597, 'row 2597'),
+(2598, 'row 2598'),
+(2599, 'row 2599'),
+(2600, 'row 2600'),
+(2601, 'row 2601'),
+(2602, 'row 2602'),
+(2603, 'row 2603'),
+(2604, 'row 2604'),
+(2605, 'row 2605'),
+(2606, 'row 2606'),
+(2607, 'row 2607'),
+(2608, 'row 2608'),
+(2609, 'row 2609'),
+(2610, 'row 2610'),
+(2611, 'row 2611'),
+(2612, 'row 2612'),
+(2613, 'row 2613'),
+(2614, 'row 2614'),
+(2615, 'row 2615'),
+(2616, 'row 2616'),
+(2617, 'row 2617'),
+(2618, 'row 2618'),
+(2619, 'row 2619'),
+(2620, 'row 2620'),
+(2621, 'row 2621'),
+(2622, 'row 2622'),
+(2623, 'row 2623'),
+(2624, 'row 2624'),
+(2625, 'row 2625'),
+(2626, 'row 2626'),
+(2627, 'row 2627'),
+(2628, 'row 2628'),
+(2629, 'row 2629'),
+(2630, 'row 2630'),
+(2631, 'row 2631'),
+(2632, 'row 2632'),
+(2633, 'row 2633'),
+(2634, 'row 2634'),
+(2635, 'row 2635'),
+(2636, 'row 2636'),
+(2637, 'row 2637'),
+(2638, 'row 2638'),
+(2639, 'row 2639'),
+(2640, 'row 2640'),
+(2641, 'row 2641'),
+(2642, 'row 2642'),
+(2643, 'row 2643'),
+(2644, 'row 2644'),
+(2645, 'row 2645'),
+(2646, 'row 2646'),
+(2647, 'row 2647'),
+(2648, 'row 2648'),
+(2649, 'row 2649'),
+(2650, 'row 2650'),
+(2651, 'row 2651'),
+(2652, 'row 2652'),
+(2653, 'row 2653'),
+(2654, 'row 2654'),
+(2655, 'row 2655'),
+(2656, 'row 2656'),
+(2657, 'row 2657'),
+(2658, 'row 2658'),
+(2659, 'row 2659'),
+(2660, 'row 2660'),
+(2661, 'row 2661'),
+(2662, 'row 2662'),
+(2663, 'row 2663'),
+(2664, 'row 2664'),
+(2665, 'row 2665'),
+(2666, 'row 2666'),
+(2667, 'row 2667'),
+(2668, 'row 2668'),
+(2669, 'row 2669'),
+(2670, 'row 2670'),
+(2671, 'row 2671'),
+(2672, 'row 2672'),
+(2673, 'row 2673'),
+(2674, 'row 2674'),
+(2675, 'row 2675'),
+(2676, 'row 2676'),
+(2677, 'row 2677'),
+(2678, 'row 2678'),
+(2679, 'row 2679'),
+(2680, 'row 2680'),
+(2681, 'row 2681'),
+(2682, 'row 2682'),
+(2683, 'row 2683'),
+(2684, 'row 2684'),
+(2685, 'row 2685'),
+(2686, 'row 2686'),
+(2687, 'row 2687'),
+(2688, 'row 2688'),
+(2689, 'row 2689'),
+(2690, 'row 2690'),
+(2691, 'row 2691'),
+(2692, 'row 2692'),
+(2693, 'row 2693'),
+(2694, 'row 2694'),
+(2695, 'row 2695'),
+(2696, 'row 2696'),
+(2697, 'row 2697'),
+(2698, 'row 2698'),
+(2699, 'row 2699'),
+(2700, 'row 2700'),
+(2701, 'row 2701'),
+(2702, 'row 2702'),
+(2703, 'row 2703'),
+(2704, 'row 2704'),
+(2705, 'row 2705'),
+(2706, 'row 2706'),
+(2707, 'row 2707'),
+(2708, 'row 2708'),
+(2709, 'row 2709'),
+(2710, 'row 2710'),
+(2711, 'row 2711'),
+(2712, 'row 2712'),
+(2713, 'row 2713'),
+(2714, 'row 2714'),
+(2715, 'row 2715'),
+(2716, 'row 2716'),
+(2717, 'row 2717'),
+(2718, 'row 2718'),
+(2719, 'row 2719'),
+(2720, 'row 2720'),
+(2721, 'row 2721'),
+(2722, 'row 2722'),
+(2723, 'row 2723'),
+(2724, 'row 2724'),
+(2725, 'row 2725'),
+(2726, 'row 2726'),
+(2727, 'row 2727'),
+(2728, 'row 2728'),
+(2729, 'row 2729'),
+(2730, 'row 2730'),
+(2731, 'row 2731'),
+(2732, 'row 2732'),
+(2733, 'row 2733'),
+(2734, 'row 2734'),
+(2735, 'row 2735'),
+(2736, 'row 2736'),
+(2737, 'row 2737'),
+(2738, 'row 2738'),
+(2739, 'row 2739'),
+(2740, 'row 2740'),
+(2741, 'row 2741'),
+(2742, 'row 2742'),
+(2743, 'row 2743'),
+(2744, 'row 2744'),
+(2745, 'row 2745'),
+(2746, 'row 2746'),
+(2747, 'row 2747'),
+(2748, 'row 2748'),
+(2749, 'row 2749'),
+(2750, 'row 2750'),
+(2751, 'row 2751'),
+(2752, 'row 2752'),
+(2753, 'row 2753'),
+(2754, 'row 2754'),
+(2755, 'row 2755'),
+(2756, 'row 2756'),
+(2757, 'row 2757'),
+(2758, 'row 2758'),
+(2759, 'row 2759'),
+(2760, 'row 2760'),
+(2761, 'row 2761'),
+(2762, 'row 2762'),
+(2763, 'row 2763'),
+(2764, 'row 2764'),
+(2765, 'row 2765'),
+(2766, 'row 2766'),
+(2767, 'row 2767'),
+(2768, 'row 2768'),
+(2769, 'row 2769'),
+(2770, 'row 2770'),
+(2771, 'row 2771'),
+(2772, 'row 2772'),
+(2773, 'row 2773'),
+(2774, 'row 2774'),
+(2775, 'row 2775'),
+(2776, 'row 2776'),
+(2777, 'row 2777'),
+(2778, 'row 2778'),
+(2779, 'row 2779'),
+(2780, 'row 2780'),
+(2781, 'row 2781'),
+(2782, 'row 2782'),
+(2783, 'row 2783'),
+(2784, 'row 2784'),
+(2785, 'row 2785'),
+(2786, 'row 2786'),
+(2787, 'row 2787'),
+(2788, 'row 2788'),
+(2789, 'row 2789'),
+(2790, 'row 2790'),
+(2791, 'row 2791'),
+(2792, 'row 2792'),
+(2793, 'row 2793'),
+(2794, 'row 2794'),
+(2795, 'row 2795'),
+(2796, 'row 2796'),
+(2797, 'row 2797'),
+(2798, 'row 2798'),
+(2799, 'row 2799'),
+(2800, 'row 2800'),
+(2801, 'row 2801'),
+(2802, 'row 2802'),
+(2803, 'row 2803'),
+(2804, 'row 2804'),
+(2805, 'row 2805'),
+(2806, 'row 2806'),
+(2807, 'row 2807'),
+(2808, 'row 2808'),
+(2809, 'row 2809'),
+(2810, 'row 2810'),
+(2811, 'row 2811'),
+(2812, 'row 2812'),
+(2813, 'row 2813'),
+(2814, 'row 2814'),
+(2815, 'row 2815'),
+(2816, 'row 2816'),
+(2817, 'row 2817'),
+(2818, 'row 2818'),
+(2819, 'row 2819'),
+(2820, 'row 2820'),
+(2821, 'row 2821'),
+(2822, 'row 2822'),
+(2823, 'row 2823'),
+(2824, 'row 2824'),
+(2825, 'row 2825'),
+(2826, 'row 2826'),
+(2827, 'row 2827'),
+(2828, 'row 2828'),
+(2829, 'row 2829'),
+(2830, 'row 2830'),
+(2831, 'row 2831'),
+(2832, 'row 2832'),
+(2833, 'row 2833'),
+(2834, 'row 2834'),
+(2835, 'row 2835'),
+(2836, 'row 2836'),
+(2837, 'row 2837'),
+(2838, 'row 2838'),
+(2839, 'row 2839'),
+(2840, 'row 2840'),
+(2841, 'row 2841'),
+(2842, 'row 2842'),
+(2843, 'row 2843'),
+(2844, 'row 2844'),
+(2845, 'row 2845'),
+(2846, 'row 2846'),
+(2847, 'row 2847'),
+(2848, 'row 2848'),
+(2849, 'row 2849'),
+(2850, 'row 2850'),
+(2851, 'row 2851'),
+(2852, 'row 2852'),
+(2853, 'row 2853'),
+(2854, 'row 2854'),
+(2855, 'row 2855'),
+(2856, 'row 2856'),
+(2857, 'row 2857'),
+(2858, 'row 2858'),
+(2859, 'row 2859'),
+(2860, 'row 2860'),
+(2861, 'row 2861'),
+(2862, 'row 2862'),
+(2863, 'row 2863'),
+(2864, 'row 2864'),
+(2865, 'row 2865'),
+(2866, 'row 2866'),
+(2867, 'row 2867'),
+(2868, 'row 2868'),
+(2869, 'row 2869'),
+(2870, 'row 2870'),
+(2871, 'row 2871'),
+(2872, 'row 2872'),
+(2873, 'row 2873'),
+(2874, 'row 2874'),
+(2875, 'row 2875'),
+(2876, 'row 2876'),
+(2877, 'row 2877'),
+(2878, 'row 2878'),
+(2879, 'row 2879'),
+(2880, 'row 2880'),
+(2881, 'row 2881'),
+(2882, 'row 2882'),
+(2883, 'row 2883'),
+(2884, 'row 2884'),
+(2885, 'row 2885'),
+(2886, 'row 2886'),
+(2887, 'row 2887'),
+(2888, 'row 2888'),
+(2889, 'row 2889'),
+(2890, 'row 2890'),
+(2891, 'row 2891'),
+(2892, 'row 2892'),
+(2893, 'row 2893'),
+(2894, 'row 2894'),
+(2895, 'row 2895'),
+(2896, 'row 2896'),
+(2897, 'row 2897'),
+(2898, 'row 2898'),
+(2899, 'row 2899'),
+(2900, 'row 2900'),
+(2901, 'row 2901'),
+(2902, 'row 2902'),
+(2903, 'row 2903'),
+(2904, 'row 2904'),
+(2905, 'row 2905'),
+(2906, 'row 2906'),
+(2907, 'row 2907'),
+(2908, 'row 2908'),
+(2909, 'row 2909'),
+(2910, 'row 2910'),
+(2911, 'row 2911'),
+(2912, 'row 2912'),
+(2913, 'row 2913'),
+(2914, 'row 2914'),
+(2915, 'row 2915'),
+(2916, 'row 2916'),
+(2917, 'row 2917'),
+(2918, 'row 2918'),
+(2919, 'row 2919'),
+(2920, 'row 2920'),
+(2921, 'row 2921'),
+(2922, 'row 2922'),
+(2923, 'row 2923'),
+(2924, 'row 2924'),
+(2925, 'row 2925'),
+(2926, 'row 2926'),
+(2927, 'row 2927'),
+(2928, 'row 2928'),
+(2929, 'row 2929'),
+(2930, 'row 2930'),
+(2931, 'row 2931'),
+(2932, 'row 2932'),
+(2933, 'row 2933'),
+(2934, 'row 2934'),
+(2935, 'row 2935'),
+(2936, 'row 2936'),
+(2937, 'row 2937'),
+(2938, 'row 2938'),
+(2939, 'row 2939'),
+(2940, 'row 2940'),
+(2941, 'row 2941'),
+(2942, 'row 2942'),
+(2943, 'row 2943'),
+(2944, 'row 2944'),
+(2945, 'row 2945'),
+(2946, 'row 2946'),
+(2947, 'row 2947'),
+(2948, 'row 2948'),
+(2949, 'row 2949'),
+(2950, 'row 2950'),
+(2951, 'row 2951'),
+(2952, 'row 2952'),
+(2953, 'row 2953'),
+(2954, 'row 2954'),
+(2955, 'row 2955'),
+(2956, 'row 2956'),
+(2957, 'row 2957'),
+(2958, 'row 2958'),
+(2959, 'row 2959'),
+(2960, 'row 2960'),
+(2961, 'row 2961'),
+(2962, 'row 2962'),
+(2963, 'row 2963'),
+(2964, 'row 2964'),
+(2965, 'row 2965'),
+(2966, 'row 2966'),
+(2967, 'row 2967'),
+(2968, 'row 2968'),
+(2969, 'row 2969'),
+(2970, 'row 2970'),
+(2971, 'row 2971'),
+(2972, 'row 2972'),
+(2973, 'row 2973'),
+(2974, 'row 2974'),
+(2975, 'row 2975'),
+(2976, 'row 2976'),
+(2977, 'row 2977'),
+(2978, 'row 2978'),
+(2979, 'row 2979'),
+(2980, 'row 2980'),
+(2981, 'row 2981'),
+(2982, 'row 2982'),
+(2983, 'row 2983'),
+(2984, 'row 2984'),
+(2985, 'row 2985'),
+(2986, 'row 2986'),
+(2987, 'row 2987'),
+(2988, 'row 2988'),
+(2989, 'row 2989'),
+(2990, 'row 2990'),
+(2991, 'row 2991'),
+(2992, 'row 2992'),
+(2993, 'row 2993'),
+(2994, 'row 2994'),
+(2995, 'row 2995'),
+(2996, 'row 2996'),
+(2997, 'row 2997'),
+(2998, 'row 2998'),
+(2999, 'row 2999'),
+(3000, 'row 3000'),
+(3001, 'row 3001'),
+(3002, 'row 3002'),
+(3003, 'row 3003'),
+(3004, 'row 3004'),
+(3005, 'row 3005'),
+(3006, 'row 3006'),
+(3007, 'row 3007'),
+(3008, 'row 3008'),
+(3009, 'row 3009'),
+(3010, 'row 3010'),
+(3011, 'row 3011'),
+(3012, 'row 3012'),
+(3013, 'row 3013'),
+(3014, 'row 3014'),
+(3015, 'row 3015'),
+(3016, 'row 3016'),
+(3017, 'row 3017'),
+(3018, 'row 3018'),
+(3019, 'row 3019'),
+(3020, 'row 3020'),
+(3021, 'row 3021'),
+(3022, 'row 3022'),
+(3023, 'row 3023'),
+(3024, 'row 3024'),
+(3025, 'row 3025'),
+(3026, 'row 3026'),
+(3027, 'row 3027'),
+(3028, 'row 3028'),
+(3029, 'row 3029'),
+(3030, 'row 3030'),
+(3031, 'row 3031'),
+(3032, 'row 3032'),
+(3033, 'row 3033'),
+(3034, 'row 3034'),
+(3035, 'row 3035'),
+(3036, 'row 3036'),
+(3037, 'row 3037'),
+(3038, 'row 3038'),
+(3039, 'row 3039'),
+(3040, 'row 3040'),
+(3041, 'row 3041'),
+(3042, 'row 3042'),
+(3043, 'row 3043'),
+(3044, 'row 3044'),
+(3045, 'row 3045'),
+(3046, 'row 3046'),
+(3047, 'row 3047'),
+(3048, 'row 3048'),
+(3049, 'row 3049'),
+(3050, 'row 3050'),
+(3051, 'row 3051'),
+(3052, 'row 3052'),
+(3053, 'row 3053'),
+(3054, 'row 3054'),
+(3055, 'row 3055'),
+(3056, 'row 3056'),
+(3057, 'row 3057'),
+(3058, 'row 3058'),
+(3059, 'row 3059'),
+(3060, 'row 3060'),
+(3061, 'row 3061'),
+(3062, 'row 3062'),
+(3063, 'row 3063'),
+(3064, 'row 3064'),
+(3065, 'row 3065'),
+(3066, 'row 3066'),
+(3067, 'row 3067'),
+(3068, 'row 3068'),
+(3069, 'row 3069'),
+(3070, 'row 3070'),
+(3071, 'row 3071'),
+(3072, 'row 3072'),
+(3073, 'row 3073'),
+(3074, 'row 3074'),
+(3075, 'row 3075'),
+(3076, 'row 3076'),
+(3077, 'row 3077'),
+(3078, 'row 3078'),
+(3079, 'row 3079'),
+(3080, 'row 3080'),
+(3081, 'row 3081'),
+(3082, 'row 3082'),
+(3083, 'row 3083'),
+(3084, 'row 3084'),
+(3085, 'row 3085'),
+(3086, 'row 3086'),
+(3087, 'row 3087'),
+(3088, 'row 3088'),
+(3089, 'row 3089'),
+(3090, 'row 3090'),
+(3091, 'row 3091'),
+(3092, 'row 3092'),
+(3093, 'row 3093'),
+(3094, 'row 3094'),
+(3095, 'row 3095'),
+(3096, 'row 3096'),
+(3097, 'row 3097'),
+(3098, 'row 3098'),
+(3099, 'row 3099'),
+(3100, 'row 3100'),
+(3101, 'row 3101'),
+(3102, 'row 3102'),
+(3103, 'row 3103'),
+(3104, 'row 3104'),
+(3105, 'row 3105'),
+(3106, 'row 3106'),
+(3107, 'row 3107'),
+(3108, 'row 3108'),
+(3109, 'row 3109'),
+(3110, 'row 3110'),
+(3111, 'row 3111'),
+(3112, 'row 3112'),
+(3113, 'row 3113'),
+(3114, 'row 3114'),
+(3115, 'row 3115'),
+(3116, 'row 3116'),
+(3117, 'row 3117'),
+(3118, 'row 3118'),
+(3119, 'row 3119'),
+(3120, 'row 3120'),
+(3121, 'row 3121'),
+(3122, 'row 3122'),
+(3123, 'row 3123'),
+(3124, 'row 3124'),
+(3125, 'row 3125'),
+(3126, 'row 3126'),
+(3127, 'row 3127'),
+(3128, 'row 3128'),
+(3129, 'row 3129'),
+(3130, 'row 3130'),
+(3131, 'row 3131'),
+(3132, 'row 3132'),
+(3133, 'row 3133'),
+(3134, 'row 3134'),
+(3135, 'row 3135'),
+(3136, 'row 3136'),
+(3137, 'row 3137'),
+(3138, 'row 3138'),
+(3139, 'row 3139'),
+(3140, 'row 3140'),
+(3141, 'row 3141'),
+(3142, 'row 3142'),
+(3143, 'row 3143'),
+(3144, 'row 3144'),
+(3145, 'row 3145'),
+(3146, 'row 3146'),
+(3147, 'row 3147'),
+(3148, 'row 3148'),
+(3149, 'row 3149'),
+(3150, 'row 3150'),
+(3151, 'row 3151'),
+(3152, 'row 3152'),
+(3153, 'row 3153'),
+(3154, 'row 3154'),
+(3155, 'row 3155'),
+(3156, 'row 3156'),
+(3157, 'row 3157'),
+(3158, 'row 3158'),
+(3159, 'row 3159'),
+(3160, 'row 3160'),
+(3161, 'row 3161'),
+(3162, 'row 3162'),
+(3163, 'row 3163'),
+(3164, 'row 3164'),
+(3165, 'row 3165'),
+(3166, 'row 3166'),
+(3167, 'row 3167'),
+(3168, 'row 3168'),
+(3169, 'row 3169'),
+(3170, 'row 3170'),
+(3171, 'row 3171'),
+(3172, 'row 3172'),
+(3173, 'row 3173'),
+(3174, 'row 3174'),
+(3175, 'row 3175'),
+(3176, 'row 3176'),
+(3177, 'row 3177'),
+(3178, 'row 3178'),
+(3179, 'row 3179'),
+(3180, 'row 3180'),
+(3181, 'row 3181'),
+(3182, 'row 3182'),
+(3183, 'row 3183'),
+(3184, 'row 3184'),
+(3185, 'row 3185'),
+(3186, 'row 3186'),
+(3187, 'row 3187'),
+(3188, 'row 3188'),
+(3189, 'row 3189'),
+(3190, 'row 3190'),
+(3191, 'row 3191'),
+(3192, 'row 3192'),
+(3193, 'row 3193'),
+(3194, 'row 3194'),
+(3195, 'row 3195'),
+(3196, 'row 3196'),
+(3197, 'row 3197'),
+(3198, 'row 3198'),
+(3199, 'row 3199'),
+(3200, 'row 3200'),
+(3201, 'row 3201'),
+(3202, 'row 3202'),
+(3203, 'row 3203'),
+(3204, 'row 3204'),
+(3205, 'row 3205'),
+(3206, 'row 3206'),
+(3207, 'row 3207'),
+(3208, 'row 3208'),
+(3209, 'row 3209'),
+(3210, 'row 3210'),
+(3211, 'row 3211'),
+(3212, 'row 3212'),
+(3213, 'row 3213'),
+(3214, 'row 3214'),
+(3215, 'row 3215'),
+(3216, 'row 3216'),
+(3217, 'row 3217'),
+(3218, 'row 3218'),
+(3219, 'row 3219'),
+(3220, 'row 3220'),
+(3221, 'row 3221'),
+(3222, 'row 3222'),
+(3223, 'row 3223'),
+(3224, 'row 3224'),
+(3225, 'row 3225'),
+(3226, 'row 3226'),
+(3227, 'row 3227'),
+(3228, 'row 3228'),
+(3229, 'row 3229'),
+(3230, 'row 3230'),
+(3231, 'row 3231'),
+(3232, 'row 3232'),
+(3233, 'row 3233'),
+(3234, 'row 3234'),
+(3235, 'row 3235'),
+(3236, 'row 3236'),
+(3237, 'row 3237'),
+(3238, 'row 3238'),
+(3239, 'row 3239'),
+(3240, 'row 3240'),
+(3241, 'row 3241'),
+(3242, 'row 3242'),
+(3243, 'row 3243'),
+(3244, 'row 3244'),
+(3245, 'row 3245'),
+(3246, 'row 3246'),
+(3247, 'row 3247'),
+(3248, 'row 3248'),
+(3249, 'row 3249'),
+(3250, 'row 3250'),
+(3251, 'row 3251'),
+(3252, 'row 3252'),
+(3253, 'row 3253'),
+(3254, 'row 3254'),
+(3255, 'row 3255'),
+(3256, 'row 3256'),
+(3257, 'row 3257'),
+(3258, 'row 3258'),
+(3259, 'row 3259'),
+(3260, 'row 3260'),
+(3261, 'row 3261'),
+(3262, 'row 3262'),
+(3263, 'row 3263'),
+(3264, 'row 3264'),
+(3265, 'row 3265'),
+(3266, 'row 3266'),
+(3267, 'row 3267'),
+(3268, 'row 3268'),
+(3269, 'row 3269'),
+(3270, 'row 3270'),
+(3271, 'row 3271'),
+(3272, 'row 3272'),
+(3273, 'row 3273'),
+(3274, 'row 3274'),
+(3275, 'row 3275'),
+(3276, 'row 3276'),
+(3277, 'row 3277'),
+(3278, 'row 3278'),
+(3279, 'row 3279'),
+(3280, 'row 3280'),
+(3281, 'row 3281'),
+(3282, 'row 3282'),
+(3283, 'row 3283'),
+(3284, 'row 3284'),
+(3285, 'row 3285'),
+(3286, 'row 3286'),
+(3287, 'row 3287'),
+(3288, 'row 3288'),
+(3289, 'row 3289'),
+(3290, 'row 3290'),
+(3291, 'row 3291'),
+(3292, 'row 3292'),
+(3293, 'row 3293'),
+(3294, 'row 3294'),
+(3295, 'row 3295'),
+(3296, 'row 3296'),
+(3297, 'row 3297'),
+(3298, 'row 3298'),
+(3299, 'row 3299'),
+(3300, 'row 3300'),
+(3301, 'row 3301'),
+(3302, 'row 3302'),
+(3303, 'row 3303'),
+(3304, 'row 3304'),
+(3305, 'row 3305'),
+(3306, 'row 3306'),
+(3307, 'row 3307'),
+(3308, 'row 3308'),
+(3309, 'row 3309'),
+(3310, 'row 3310'),
+(3311, 'row 3311'),
+(3312, 'row 3312'),
+(3313, 'row 3313'),
+(3314, 'row 3314'),
+(3315, 'row 3315'),
+(3316, 'row 3316'),
+(3317, 'row 3317'),
+(3318, 'row 3318'),
+(3319, 'row 3319'),
+(3320, 'row 3320'),
+(3321, 'row 3321'),
+(3322, 'row 3322'),
+(3323, 'row 3323'),
+(3324, 'row 3324'),
+(3325, 'row 3325'),
+(3326, 'row 3326'),
+(3327, 'row 3327'),
+(3328, 'row 3328'),
+(3329, 'row 3329'),
+(3330, 'row 3330'),
+(3331, 'row 3331'),
+(3332, 'row 3332'),
+(3333, 'row 3333'),
+(3334, 'row 3334'),
+(3335, 'row 3335'),
+(3336, 'row 3336'),
+(3337, 'row 3337'),
+(3338, 'row 3338'),
+(3339, 'row 3339'),
+(3340, 'row 3340'),
+(3341, 'row 3341'),
+(3342, 'row 3342'),
+(3343, 'row 3343'),
+(3344, 'row 3344'),
+(3345, 'row 3345'),
+(3346, 'row 3346'),
+(3347, 'row 3347'),
+(3348, 'row 3348'),
+(3349, 'row 3349'),
+(3350, 'row 3350'),
+(3351, 'row 3351'),
+(3352, 'row 3352'),
+(3353, 'row 3353'),
+(3354, 'row 3354'),
+(3355, 'row 3355'),
+(3356, 'row 3356'),
+(3357, 'row 3357'),
+(3358, 'row 3358'),
+(3359, 'row 3359'),
+(3360, 'row 3360'),
+(3361, 'row 3361'),
+(3362, 'row 3362'),
+(3363, 'row 3363'),
+(3364, 'row 3364'),
+(3365, 'row 3365'),
+(3366, 'row 3366'),
+(3367, 'row 3367'),
+(3368, 'row 3368'),
+(3369, 'row 3369'),
+(3370, 'row 3370'),
+(3371, 'row 3371'),
+(3372, 'row 3372'),
+(3373, 'row 3373'),
+(3374, 'row 3374'),
+(3375, 'row 3375'),
+(3376, 'row 3376'),
+(3377, 'row 3377'),
+(3378, 'row 3378'),
+(3379, 'row 3379'),
+(3380, 'row 3380'),
+(3381, 'row 3381'),
+(3382, 'row 3382'),
+(3383, 'row 3383'),
+(3384, 'row 3384'),
+(3385, 'row 3385'),
+(3386, 'row 3386'),
+(3387, 'row 3387'),
+(3388, 'row 3388'),
+(3389, 'row 3389'),
+(3390, 'row 3390'),
+(3391, 'row 3391'),
+(3392, 'row 3392'),
+(3393, 'row 3393'),
+(3394, 'row 3394'),
+(3395, 'row 3395'),
+(3396, 'row 3396'),
+(3397, 'row 3397'),
+(3398, 'row 3398'),
+(3399, 'row 3399'),
+(3400, 'row 3400'),
+(3401, 'row 3401'),
+(3402, 'row 3402'),
+(3403, 'row 3403'),
+(3404, 'row 3404'),
+(3405, 'row 3405'),
+(3406, 'row 3406'),
+(3407, 'row 3407'),
+(3408, 'row 3408'),
+(3409, 'row 3409'),
+(3410, 'row 3410'),
+(3411, 'row 3411'),
+(3412, 'row 3412'),
+(3413, 'row 3413'),
+(3414, 'row 3414'),
+(3415, 'row 3415'),
+(3416, 'row 3416'),
+(3417, 'row 3417'),
+(3418, 'row 3418'),
+(3419, 'row 3419'),
+(3420, 'row 3420'),
+(3421, 'row 3421'),
+(3422, 'row 3422'),
+(3423, 'row 3423'),
+(3424, 'row 3424'),
+(3425, 'row 3425'),
+(3426, 'row 3426'),
+(3427, 'row 3427'),
+(3428, 'row 3428'),
+(3429, 'row 3429'),
+(3430, 'row 3430'),
+(3431, 'row 3431'),
+(3432, 'row 3432'),
+(3433, 'row 3433'),
+(3434, 'row 3434'),
+(3435, 'row 3435'),
+(3436, 'row 3436'),
+(3437, 'row 3437'),
+(3438, 'row 3438'),
+(3439, 'row 3439'),
+(3440, 'row 3440'),
+(3441, 'row 3441'),
+(3442, 'row 3442'),
+(3443, 'row 3443'),
+(3444, 'row 3444'),
+(3445, 'row 3445'),
+(3446, 'row 3446'),
+(3447, 'row 3447'),
+(3448, 'row 3448'),
+(3449, 'row 3449'),
+(3450, 'row 3450'),
+(3451, 'row 3451'),
+(3452, 'row 3452'),
+(3453, 'row 3453'),
+(3454, 'row 3454'),
+(3455, 'row 3455'),
+(3456, 'row 3456'),
+(3457, 'row 3457'),
+(3458, 'row 3458'),
+(3459, 'row 3459'),
+(3460, 'row 3460'),
+(3461, 'row 3461'),
+(3462, 'row 3462'),
+(3463, 'row 3463'),
+(3464, 'row 3464'),
+(3465, 'row 3465'),
+(3466, 'row 3466'),
+(3467, 'row 3467'),
+(3468, 'row 3468'),
+(3469, 'row 3469'),
+(3470, 'row 3470'),
+(3471, 'row 3471'),
+(3472, 'row 3472'),
+(3473, 'row 3473'),
+(3474, 'row 3474'),
+(3475, 'row 3475'),
+(3476, 'row 3476'),
+(3477, 'row 3477'),
+(3478, 'row 3478'),
+(3479, 'row 3479'),
+(3480, 'row 3480'),
+(3481, 'row 3481'),
+(3482, 'row 3482'),
+(3483, 'row 3483'),
+(3484, 'row 3484'),
+(3485, 'row 3485'),
+(3486, 'row 3486'),
+(3487, 'row 3487'),
+(3488, 'row 3488'),
+(3489, 'row 3489'),
+(3490, 'row 3490'),
+(3491, 'row 3491'),
+(3492, 'row 3492'),
+(3493, 'row 3493'),
+(3494, 'row 3494'),
+(3495, 'row 3495'),
+(3496, 'row 3496'),
+(3497, 'row 3497'),
+(3498, 'row 3498'),
+(3499, 'row 3499'),
+(3500, 'row 3500'),
+(3501, 'row 3501'),
+(3502, 'row 3502'),
+(3503, 'row 3503'),
+(3504, 'row 3504'),
+(3505, 'row 3505'),
+(3506, 'row 3506'),
+(3507, 'row 3507'),
+(3508, 'row 3508'),
+(3509, 'row 3509'),
+(3510, 'row 3510'),
+(3511, 'row 3511'),
+(3512, 'row 3512'),
+(3513, 'row 3513'),
+(3514, 'row 3514'),
+(3515, 'row 3515'),
+(3516, 'row 3516'),
+(3517, 'row 3517'),
+(3518, 'row 3518'),
+(3519, 'row 3519'),
+(3520, 'row 3520'),
+(3521, 'row 3521'),
+(3522, 'row 3522'),
+(3523, 'row 3523'),
+(3524, 'row 3524'),
+(3525, 'row 3525'),
+(3526, 'row 3526'),
+(3527, 'row 3527'),
+(3528, 'row 3528'),
+(3529, 'row 3529'),
+(3530, 'row 3530'),
+(3531, 'row 3531'),
+(3532, 'row 3532'),
+(3533, 'row 3533'),
+(3534, 'row 3534'),
+(3535, 'row 3535'),
+(3536, 'row 3536'),
+(3537, 'row 3537'),
+(3538, 'row 3538'),
+(3539, 'row 3539'),
+(3540, 'row 3540'),
+(3541, 'row 3541'),
+(3542, 'row 3542'),
+(3543, 'row 3543'),
+(3544, 'row 3544'),
+(3545, 'row 3545'),
+(3546, 'row 3546'),
+(3547, 'row 3547'),
+(3548, 'row 3548'),
+(3549, 'row 3549'),
+(3550, 'row 3550'),
+(3551, 'row 3551'),
+(3552, 'row 3552'),
+(3553, 'row 3553'),
+(3554, 'row 3554'),
+(3555, 'row 3555'),
+(3556, 'row 3556'),
+(3557, 'row 3557'),
+(3558, 'row 3558'),
+(3559, 'row 3559'),
+(3560, 'row 3560'),
+(3561, 'row 3561'),
+(3562, 'row 3562'),
+(3563, 'row 3563'),
+(3564, 'row 3564'),
+(3565, 'row 3565'),
+(3566, 'row 3566'),
+(3567, 'row 3567'),
+(3568, 'row 3568'),
+(3569, 'row 3569'),
+(3570, 'row 3570'),
+(3571, 'row 3571'),
+(3572, 'row 3572'),
+(3573, 'row 3573'),
+(3574, 'row 3574'),
+(3575, 'row 3575'),
+(3576, 'row 3576'),
+(3577, 'row 3577'),
+(3578, 'row 3578'),
+(3579, 'row 3579'),
+(3580, 'row 3580'),
+(3581, 'row 3581'),
+(3582, 'row 3582'),
+(3583, 'row 3583'),
+(3584, 'row 3584'),
+(3585, 'row 3585'),
+(3586, 'row 3586'),
+(3587, 'row 3587'),
+(3588, 'row 3588'),
+(3589, 'row 3589'),
+(3590, 'row 3590'),
+(3591, 'row 3591'),
+(3592, 'row 3592'),
+(3593, 'row 3593'),
+(3594, 'row 3594'),
+(3595, 'row 3595'),
+(3596, 'row 3596'),
+(3597, 'row 3597'),
+(3598, 'row 3598'),
+(3599, 'row 3599'),
+(3600, 'row 3600'),
+(3601, 'row 3601'),
+(3602, 'row 3602'),
+(3603, 'row 3603'),
+(3604, 'row 3604'),
+(3605, 'row 3605'),
+(3606, 'row 3606'),
+(3607, 'row 3607'),
+(3608, 'row 3608'),
+(3609, 'row 3609'),
+(3610, 'row 3610'),
+(3611, 'row 3611'),
+(3612, 'row 3612'),
+(3613, 'row 3613'),
+(3614, 'row 3614'),
+(3615, 'row 3615'),
+(3616, 'row 3616'),
+(3617, 'row 3617'),
+(3618, 'row 3618'),
+(3619, 'row 3619'),
+(3620, 'row 3620'),
+(3621, 'row 3621'),
+(3622, 'row 3622'),
+(3623, 'row 3623'),
+(3624, 'row 3624'),
+(3625, 'row 3625'),
+(3626, 'row 3626'),
+(3627, 'row 3627'),
+(3628, 'row 3628'),
+(3629, 'row 3629'),
+(3630, 'row 3630'),
+(3631, 'row 3631'),
+(3632, 'row 3632'),
+(3633, 'row 3633'),
+(3634, 'row 3634'),
+(3635, 'row 3635'),
+(3636, 'row 3636'),
+(3637, 'row 3637'),
+(3638, 'row 3638'),
+(3639, 'row 3639'),
+(3640, 'row 3640'),
+(3641, 'row 3641'),
+(3642, 'row 3642'),
+(3643, 'row 3643'),
+(3644, 'row 3644'),
+(3645, 'row 3645'),
+(3646, 'row 3646'),
+(3647, 'row 3647'),
+(3648, 'row 3648'),
+(3649, 'row 3649'),
+(3650, 'row 3650'),
+(3651, 'row 3651'),
+(3652, 'row 3652'),
+(3653, 'row 3653'),
+(3654, 'row 3654'),
+(3655, 'row 3655'),
+(3656, 'row 3656'),
+(3657, 'row 3657'),
+(3658, 'row 3658'),
+(3659, 'row 3659'),
+(3660, 'row 3660'),
+(3661, 'row 3661'),
+(3662, 'row 3662'),
+(3663, 'row 3663'),
+(3664, 'row 3664'),
+(3665, 'row 3665'),
+(3666, 'row 3666'),
+(3667, 'row 3667'),
+(3668, 'row 3668'),
+(3669, 'row 3669'),
+(3670, 'row 3670'),
+(3671, 'row 3671'),
+(3672, 'row 3672'),
+(3673, 'row 3673'),
+(3674, 'row 3674'),
+(3675, 'row 3675'),
+(3676, 'row 3676'),
+(3677, 'row 3677'),
+(3678, 'row 3678'),
+(3679, 'row 3679'),
+(3680, 'row 3680'),
+(3681, 'row 3681'),
+(3682, 'row 3682'),
+(3683, 'row 3683'),
+(3684, 'row 3684'),
+(3685, 'row 3685'),
+(3686, 'row 3686'),
+(3687, 'row 3687'),
+(3688, 'row 3688'),
+(3689, 'row 3689'),
+(3690, 'row 3690'),
+(3691, 'row 3691'),
+(3692, 'row 3692'),
+(3693, 'row 3693'),
+(3694, 'row 3694'),
+(3695, 'row 3695'),
+(3696, 'row 3696'),
+(3697, 'row 3697'),
+(3698, 'row 3698'),
+(3699, 'row 3699'),
+(3700, 'row 3700'),
+(3701, 'row 3701'),
+(3702, 'row 3702'),
+(3703, 'row 3703'),
+(3704, 'row 3704'),
+(3705, 'row 3705'),
+(3706, 'row 3706'),
+(3707, 'row 3707'),
+(3708, 'row 3708'),
+(3709, 'row 3709'),
+(3710, 'row 3710'),
+(3711, 'row 3711'),
+(3712, 'row 3712'),
+(3713, 'row 3713'),
+(3714, 'row 3714'),
+(3715, 'row 3715'),
+(3716, 'row 3716'),
+(3717, 'row 3717'),
+(3718, 'row 3718'),
+(3719, 'row 3719'),
+(3720, 'row 3720'),
+(3721, 'row 3721'),
+(3722, 'row 3722'),
+(3723, 'row 3723'),
+(3724, 'row 3724'),
+(3725, 'row 3725'),
+(3726, 'row 3726'),
+(3727, 'row 3727'),
+(3728, 'row 3728'),
+(3729, 'row 3729'),
+(3730, 'row 3730'),
+(3731, 'row 3731'),
+(3732, 'row 3732'),
+(3733, 'row 3733'),
+(3734, 'row 3734'),
+(3735, 'row 3735'),
+(3736, 'row 3736'),
+(3737, 'row 3737'),
+(3738, 'row 3738'),
+(3739, 'row 3739'),
+(3740, 'row 3740'),
+(3741, 'row 3741'),
+(3742, 'row 3742'),
+(3743, 'row 3743'),
+(3744, 'row 3744'),
+(3745, 'row 3745'),
+(3746, 'row 3746'),
+(3747, 'row 3747'),
+(3748, 'row 3748'),
+(3749, 'row 3749'),
+(3750, 'row 3750'),
+(3751, 'row 3751'),
+(3752, 'row 3752'),
+(3753, 'row 3753'),
+(3754, 'row 3754'),
+(3755, 'row 3755'),
+(3756, 'row 3756'),
+(3757, 'row 3757'),
+(3758, 'row 3758'),
+(3759, 'row 3759'),
+(3760, 'row 3760'),
+(3761, 'row 3761'),
+(3762, 'row 3762'),
+(3763, 'row 3763'),
+(3764, 'row 3764'),
+(3765, 'row 3765'),
+(3766, 'row 3766'),
+(3767, 'row 3767'),
+(3768, 'row 3768'),
+(3769, 'row 3769'),
+(3770, 'row 3770'),
+(3771, 'row 3771'),
+(3772, 'row 3772'),
+(3773, 'row 3773'),
+(3774, 'row 3774'),
+(3775, 'row 3775'),
+(3776, 'row 3776'),
+(3777, 'row 3777'),
+(3778, 'row 3778'),
+(3779, 'row 3779'),
+(3780, 'row 3780'),
+(3781, 'row 3781'),
+(3782, 'row 3782'),
+(3783, 'row 3783'),
+(3784, 'row 3784'),
+(3785, 'row 3785'),
+(3786, 'row 3786'),
+(3787, 'row 3787'),
+(3788, 'row 3788'),
+(3789, 'row 3789'),
+(3790, 'row 3790'),
+(3791, 'row 3791'),
+(3792, 'row 3792'),
+(3793, 'row 3793'),
+(3794, 'row 3794'),
+(3795, 'row 3795'),
+(3796, 'row 3796'),
+(3797, 'row 3797'),
+(3798, 'row 3798'),
+(3799, 'row 3799'),
+(3800, 'row 3800'),
+(3801, 'row 3801'),
+(3802, 'row 3802'),
+(3803, 'row 3803'),
+(3804, 'row 3804'),
+(3805, 'row 3805'),
+(3806, 'row 3806'),
+(3807, 'row 3807'),
+(3808, 'row 3808'),
+(3809, 'row 3809'),
+(3810, 'row 3810'),
+(3811, 'row 3811'),
+(3812, 'row 3812'),
+(3813, 'row 3813'),
+(3814, 'row 3814'),
+(3815, 'row 3815'),
+(3816, 'row 3816'),
+(3817, 'row 3817'),
+(3818, 'row 3818'),
+(3819, 'row 3819'),
+(3820, 'row 3820'),
+(3821, 'row 3821'),
+(3822, 'row 3822'),
+(3823, 'row 3823'),
+(3824, 'row 3824'),
+(3825, 'row 3825'),
+(3826, 'row 3826'),
+(3827, 'row 3827'),
+(3828, 'row 3828'),
+(3829, 'row 3829'),
+(3830, 'row 3830'),
+(3831, 'row 3831'),
+(3832, 'row 3832'),
+(3833, 'row 3833'),
+(3834, 'row 3834'),
+(3835, 'row 3835'),
+(3836, 'row 3836'),
+(3837, 'row 3837'),
+(3838, 'row 3838'),
+(3839, 'row 3839'),
+(3840, 'row 3840'),
+(3841, 'row 3841'),
+(3842, 'row 3842'),
+(3843, 'row 3843'),
+(3844, 'row 3844'),
+(3845, 'row 3845'),
+(3846, 'row 3846'),
+(3847, 'row 3847'),
+(3848, 'row 3848'),
+(3849, 'row 3849'),
+(3850, 'row 3850'),
+(3851, 'row 3851'),
+(3852, 'row 3852'),
+(3853, 'row 3853'),
+(3854, 'row 3854'),
+(3855, 'row 3855'),
+(3856, 'row 3856'),
+(3857, 'row 3857'),
+(3858, 'row 3858'),
+(3859, 'row 3859'),
+(3860, 'row 3860'),
+(3861, 'row 3861'),
+(3862, 'row 3862'),
+(3863, 'row 3863'),
+(3864, 'row 3864'),
+(3865, 'row 3865'),
+(3866, 'row 3866'),
+(3867, 'row 3867'),
+(3868, 'row 3868'),
+(3869, 'row 3869'),
+(3870, 'row 3870'),
+(3871, 'row 3871'),
+(3872, 'row 3872'),
+(3873, 'row 3873'),
+(3874, 'row 3874'),
+(3875, 'row 3875'),
+(3876, 'row 3876'),
+(3877, 'row 3877'),
+(3878, 'row 3878'),
+(3879, 'row 3879'),
+(3880, 'row 3880'),
+(3881, 'row 3881'),
+(3882, 'row 3882'),
+(3883, 'row 3883'),
+(3884, 'row 3884'),
+(3885, 'row 3885'),
+(3886, 'row 3886'),
+(3887, 'row 3887'),
+(3888, 'row 3888'),
+(3889, 'row 3889'),
+(3890, 'row 3890'),
+(3891, 'row 3891'),
+(3892, 'row 3892'),
+(3893, 'row 3893'),
+(3894, 'row 3894'),
+(3895, 'row 3895'),
+(3896, 'row 3896'),
+(3897, 'row 3897'),
+(3898, 'row 3898'),
+(3899, 'row 3899'),
+(3900, 'row 3900'),
+(3901, 'row 3901'),
+(3902, 'row 3902'),
+(3903, 'row 3903'),
+(3904, 'row 3904'),
+(3905, 'row 3905'),
+(3906, 'row 3906'),
+(3907, 'row 3907'),
+(3908, 'row 3908'),
+(3909, 'row 3909'),
+(3910, 'row 3910'),
+(3911, 'row 3911'),
+(3912, 'row 3912'),
+(3913, 'row 3913'),
+(3914, 'row 3914'),
+(3915, 'row 3915'),
+(3916, 'row 3916'),
+(3917, 'row 3917'),
+(3918, 'row 3918'),
+(3919, 'row 3919'),
+(3920, 'row 3920'),
+(3921, 'row 3921'),
+(3922, 'row 3922'),
+(3923, 'row 3923'),
+(3924, 'row 3924'),
+(3925, 'row 3925'),
+(3926, 'row 3926'),
+(3927, 'row 3927'),
+(3928, 'row 3928'),
+(3929, 'row 3929'),
+(3930, 'row 3930'),
+(3931, 'row 3931'),
+(3932, 'row 3932'),
+(3933, 'row 3933'),
+(3934, 'row 3934'),
+(3935, 'row 3935'),
+(3936, 'row 3936'),
+(3937, 'row 3937'),
+(3938, 'row 3938'),
+(3939, 'row 3939'),
+(3940, 'row 3940'),
+(3941, 'row 3941'),
+(3942, 'row 3942'),
+(3943, 'row 3943'),
+(3944, 'row 3944'),
+(3945, 'row 3945'),
+(3946, 'row 3946'),
+(3947, 'row 3947'),
+(3948, 'row 3948'),
+(3949, 'row 3949'),
+(3950, 'row 3950'),
+(3951, 'row 3951'),
+(3952, 'row 3952'),
+(3953, 'row 3953'),
+(3954, 'row 3954'),
+(3955, 'row 3955'),
+(3956, 'row 3956'),
+(3957, 'row 3957'),
+(3958, 'row 3958'),
+(3959, 'row 3959'),
+(3960, 'row 3960'),
+(3961, 'row 3961'),
+(3962, 'row 3962'),
+(3963, 'row 3963'),
+(3964, 'row 3964'),
+(3965, 'row 3965'),
+(3966, 'row 3966'),
+(3967, 'row 3967'),
+(3968, 'row 3968'),
+(3969, 'row 3969'),
+(3970, 'row 3970'),
+(3971, 'row 3971'),
+(3972, 'row 3972'),
+(3973, 'row 3973'),
+(3974, 'row 3974'),
+(3975, 'row 3975'),
+(3976, 'row 3976'),
+(3977, 'row 3977'),
+(3978, 'row 3978'),
+(3979, 'row 3979'),
+(3980, 'row 3980'),
+(3981, 'row 3981'),
+(3982, 'row 3982'),
+(3983, 'row 3983'),
+(3984, 'row 3984'),
+(3985, 'row 3985'),
+(3986, 'row 3986'),
+(3987, 'row 3987'),
+(3988, 'row 3988'),
+(3989, 'row 3989'),
+(3990, 'row 3990'),
+(3991, 'row 3991'),
+(3992, 'row 3992'),
+(3993, 'row 3993'),
+(3994, 'row 3994'),
+(3995, 'row 3995'),
+(3996, 'row 3996'),
+(3997, 'row 3997'),
+(3998, 'row 3998'),
+(3999, 'row 3999');
+SAVEPOINT caf;
@@ -0,0 +1,2 @@
+INSERT INTO t1 VALUES ('it\'s');
+SAVEPOINT s1;
\ No newline at end of file